@@ -0,0 +1,92 @@
+//! Times researching recipes and applying their steps separately, for wide, deep and diamond shaped tomes.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use summon::{circle, Materials, Tome, TransmutationBuilder};
+
+/// One of many distinct types, told apart by `N`.
+#[derive(Clone)]
+struct Node<const N: usize>;
+type Base = Node<0>;
+struct Wide;
+struct Left;
+struct Right;
+struct Diamond;
+
+macro_rules! wide {
+    ($($n:literal)*) => {{
+        let mut tome = Tome::new();
+        $(tome.ether(Node::<$n>);)*
+        tome.inscribe(
+            TransmutationBuilder::new::<Wide>()
+                $(.require::<Node<$n>>())*
+                .build(|_| Wide),
+        );
+        tome
+    }};
+}
+
+/// A tome with 50 ethers, all consumed by a single circle.
+fn wide() -> Tome {
+    wide!(
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37
+        38 39 40 41 42 43 44 45 46 47 48 49
+    )
+}
+
+macro_rules! deep {
+    ($($from:literal => $to:literal)*) => {{
+        let mut tome = Tome::new();
+        tome.ether(Node::<0>);
+        $(tome.inscribe(
+            TransmutationBuilder::new::<Node<$to>>()
+                .require::<Node<$from>>()
+                .build(|_| Node::<$to>),
+        );)*
+        tome
+    }};
+}
+
+/// A tome with a linear chain of 20 circles.
+fn deep() -> Tome {
+    deep!(
+        0 => 1 1 => 2 2 => 3 3 => 4 4 => 5 5 => 6 6 => 7 7 => 8 8 => 9 9 => 10 10 => 11 11 => 12 12 => 13
+        13 => 14 14 => 15 15 => 16 16 => 17 17 => 18 18 => 19 19 => 20
+    )
+}
+
+/// A tome with two branches from the same base which converge again.
+fn diamond() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Node::<0>);
+    tome.inscribe(circle!(Base => Left));
+    tome.inscribe(circle!(Base => Right));
+    tome.inscribe(circle!(Left, Right => Diamond));
+    tome
+}
+
+fn bench<T: 'static>(c: &mut Criterion, name: &str, tome: &Tome) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("research", |b| {
+        b.iter(|| black_box(tome).research::<T>().unwrap())
+    });
+    let recipe = tome.research::<T>().unwrap();
+    group.bench_function("apply", |b| {
+        b.iter(|| {
+            let mut materials = Materials::default();
+            for &step in black_box(&recipe).steps() {
+                materials.apply(step);
+            }
+            black_box(materials)
+        })
+    });
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench::<Wide>(c, "wide", &wide());
+    bench::<Node<20>>(c, "deep", &deep());
+    bench::<Diamond>(c, "diamond", &diamond());
+}
+
+criterion_group!(core, benches);
+criterion_main!(core);
@@ -0,0 +1,56 @@
+//! Compares sequential and parallel summoning of a wide diamond, where every side shares the same base.
+
+use std::thread;
+use std::time::{Duration, Instant};
+use summon::{circle, SharedTome};
+
+#[derive(Clone)]
+struct Base;
+struct Side0;
+struct Side1;
+struct Side2;
+struct Side3;
+struct Side4;
+struct Side5;
+struct Side6;
+struct Side7;
+struct Top;
+
+fn work() {
+    thread::sleep(Duration::from_millis(20));
+}
+
+fn tome() -> SharedTome {
+    let mut tome = SharedTome::new();
+    tome.ether(Base);
+    macro_rules! side {
+        ($($side:ident),*) => {
+            $(tome.inscribe_parallel::<$side, _>(circle!(|_b: &Base| -> $side {
+                work();
+                $side
+            }));)*
+        };
+    }
+    side!(Side0, Side1, Side2, Side3, Side4, Side5, Side6, Side7);
+    tome.inscribe_parallel::<Top, _>(circle!(
+        Side0, Side1, Side2, Side3, Side4, Side5, Side6, Side7 => Top
+    ));
+    tome
+}
+
+fn time(name: &str, summon: impl Fn()) {
+    const RUNS: u32 = 5;
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        summon();
+    }
+    println!("{}: {:?} per summon", name, start.elapsed() / RUNS);
+}
+
+fn main() {
+    let tome = tome();
+    time("sequential", || assert!(tome.summon::<Top>().is_ok()));
+    time("parallel", || {
+        assert!(tome.summon_parallel::<Top>().is_some())
+    });
+}
@@ -0,0 +1,97 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Every ether value inscribed for the ingredient of an accumulator.
+pub(crate) type AccumulatedEthers = Rc<RefCell<Vec<Box<dyn Any>>>>;
+
+/// Performs a circle on every ether value of its ingredient and collects the results.
+struct AccumulatorTransmutation<T, Item, C> {
+    item_circle: C,
+    ethers: AccumulatedEthers,
+    _phantom: PhantomData<fn() -> (T, Item)>,
+}
+
+impl<T: Default + Extend<Item> + 'static, Item: 'static, C: Transmutation> Transmutation
+    for AccumulatorTransmutation<T, Item, C>
+{
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        let mut collection = T::default();
+        collection.extend(self.ethers.borrow().iter().map(|ether| {
+            *self
+                .item_circle
+                .transmute(&[&**ether])
+                .downcast::<Item>()
+                .expect("accumulator circle produced an incorrect type")
+        }));
+        Box::new(collection)
+    }
+    fn expired(&self) -> bool {
+        self.item_circle.expired()
+    }
+}
+
+impl Tome {
+    /// Summon `T` by performing `item_circle` on every ether value of its single ingredient and collecting
+    /// the resulting items.
+    ///
+    /// Ether inscribed both before and after the accumulator is included.
+    pub fn inscribe_accumulator<T, Item, C>(&mut self, item_circle: C)
+    where
+        T: Default + Extend<Item> + 'static,
+        Item: Clone + 'static,
+        C: Transmutation + 'static,
+    {
+        assert_eq!(
+            TypeId::of::<Item>(),
+            item_circle.product(),
+            "accumulator circle must produce the item type"
+        );
+        let ingredient = match item_circle.ingredients() {
+            &[ingredient] => ingredient,
+            ingredients => panic!(
+                "accumulator circle must have exactly one ingredient (found: {})",
+                ingredients.len()
+            ),
+        };
+        let existing = self
+            .circles
+            .get(&ingredient)
+            .into_iter()
+            .flatten()
+            .map(|(_, circle)| circle)
+            .filter(|circle| circle.ingredients().is_empty() && !circle.expired())
+            .map(|circle| circle.transmute(&[]))
+            .collect();
+        let ethers: AccumulatedEthers = Rc::new(RefCell::new(existing));
+        self.accumulators
+            .entry(ingredient)
+            .or_default()
+            .push(ethers.clone());
+        self.inscribe(AccumulatorTransmutation::<T, Item, C> {
+            item_circle,
+            ethers,
+            _phantom: PhantomData,
+        });
+    }
+
+    /// Add a newly inscribed ether value to every accumulator which consumes it.
+    pub(crate) fn accumulate<T: Clone + 'static>(&self, item: &T) {
+        for ethers in self
+            .accumulators
+            .get(&TypeId::of::<T>())
+            .into_iter()
+            .flatten()
+        {
+            ethers.borrow_mut().push(Box::new(item.clone()));
+        }
+    }
+}
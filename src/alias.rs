@@ -0,0 +1,34 @@
+use crate::{Conversion, Tome};
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+impl Tome {
+    /// Treat `F` as interchangeable with `T`, so that `T` can be summoned whenever `F` can.
+    ///
+    /// Unlike an ordinary circle, the coercion is reported as such by `explain` and `coerced_from`.
+    pub fn register_alias<F: Clone + 'static, T: From<F> + 'static>(&mut self) {
+        self.register_coerce::<F, T>(T::from);
+    }
+
+    /// Coerce `F` into `T` with `f` whenever `T` is needed, like `register_alias` for types which do not
+    /// convert with `From`.
+    pub fn register_coerce<F: Clone + 'static, T: 'static>(
+        &mut self,
+        f: impl Fn(F) -> T + 'static,
+    ) {
+        self.inscribe(Conversion(move |from: &F| f(from.clone()), PhantomData));
+        self.coercions.insert(TypeId::of::<T>(), TypeId::of::<F>());
+    }
+
+    /// The type `T` is coerced from, if it was registered with `register_alias` or `register_coerce`.
+    pub fn coerced_from<T: 'static>(&self) -> Option<TypeId> {
+        self.coercions.get(&TypeId::of::<T>()).copied()
+    }
+
+    /// Check whether a circle producing `product` from `ingredients` is a registered coercion.
+    pub(crate) fn is_coercion(&self, product: TypeId, ingredients: &[TypeId]) -> bool {
+        self.coercions
+            .get(&product)
+            .is_some_and(|from| ingredients == [*from])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Tome;
+use std::any::TypeId;
+
+impl Tome {
+    /// Summon `T` once with every circle producing it whose recipe can be performed, in the order they would be
+    /// tried by `summon`.
+    ///
+    /// Each circle's recipe is performed separately, so shared ingredients are made once per circle.
+    pub fn summon_all<T: 'static>(&self) -> Vec<T> {
+        self.summon_iter().collect()
+    }
+
+    /// Lazily summon `T` with every circle producing it, like `summon_all`.
+    ///
+    /// Each circle's recipe is only researched and performed when the iterator is advanced, so stopping early
+    /// avoids performing the remaining circles.
+    pub fn summon_iter<T: 'static>(&self) -> impl Iterator<Item = T> + '_ {
+        let id = TypeId::of::<T>();
+        self.candidates(id)
+            .filter_map(move |circle| self.research_circle(id, circle))
+            .filter_map(move |recipe| self.perform(recipe).ok())
+            .map(move |materials| self.extract(materials))
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// An ingredient which was produced with a different type than the one its circle expects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeMismatch {
+    /// The product of the circle which consumes the ingredient.
+    pub product: TypeId,
+    /// The ingredient the circle expects.
+    pub ingredient: TypeId,
+    /// Describes the expected type of the ingredient.
+    pub expected_type_name: String,
+}
+
+/// An ingredient which no circle in the tome can produce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The ingredient which cannot be produced.
+    pub missing: TypeId,
+    /// The products whose circles led to the ingredient, starting with a product nothing else consumes and ending
+    /// with the product of the circle which consumes the missing ingredient directly.
+    pub required_by: Vec<TypeId>,
+}
+
+impl Tome {
+    /// Find every product which cannot currently be summoned, along with the ingredients of its circles which
+    /// cannot be summoned either.
+    pub fn find_all_missing_types(&self) -> HashMap<TypeId, Vec<TypeId>> {
+        self.inscribed_products()
+            .into_iter()
+            .filter(|&product| self.research_id(product).is_none())
+            .map(|product| {
+                let mut missing = vec![];
+                for circle in self.candidates(product) {
+                    for &ingredient in circle.ingredients() {
+                        if !missing.contains(&ingredient) && self.research_id(ingredient).is_none()
+                        {
+                            missing.push(ingredient);
+                        }
+                    }
+                }
+                (product, missing)
+            })
+            .collect()
+    }
+
+    /// Check that every product in the tome can currently be summoned.
+    pub fn is_complete(&self) -> bool {
+        self.find_all_missing_types().is_empty()
+    }
+
+    /// Describe every product in the tome and the circles which produce it.
+    pub fn summarize(&self) -> String {
+        let products = self.inscribed_products();
+        let mut summary = format!("tome with {} products:", products.len());
+        for product in products {
+            let circles: Vec<String> = self
+                .candidates(product)
+                .map(|circle| {
+                    let ingredients = circle.ingredients();
+                    if self.is_coercion(product, ingredients) {
+                        format!("{:?} (coercion)", ingredients)
+                    } else {
+                        format!("{:?}", ingredients)
+                    }
+                })
+                .collect();
+            let summonable = if self.research_id(product).is_some() {
+                "summonable"
+            } else {
+                "not summonable"
+            };
+            summary += &format!(
+                "\n  {:?} ({}) from {}",
+                product,
+                summonable,
+                circles.join(" or ")
+            );
+        }
+        summary
+    }
+
+    /// Check that everything made out of the ether has the type its consumers expect.
+    ///
+    /// Only circles holding a value given to the ether are checked, by inspecting the value they hold, so no
+    /// transmutation is performed and lazy ethers and generators are left untouched.
+    pub fn validate_ingredient_types(&self) -> Result<(), Vec<TypeMismatch>> {
+        let mut mismatches = vec![];
+        for product in self.inscribed_products() {
+            for circle in self.candidates(product) {
+                for &ingredient in circle.ingredients() {
+                    let malformed = self
+                        .candidates(ingredient)
+                        .filter_map(|source| source.ether_value())
+                        .any(|value| (*value).type_id() != ingredient);
+                    if malformed {
+                        mismatches.push(TypeMismatch {
+                            product,
+                            ingredient,
+                            expected_type_name: self.type_registry.describe(ingredient),
+                        });
+                    }
+                }
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
+
+    /// Find every ingredient of an inscribed circle which no circle produces, as `(product, ingredient)` pairs.
+    ///
+    /// Only the ingredients of each circle are checked, not theirs in turn, so an ingredient which is produced
+    /// but cannot itself be summoned is not reported. Pairs are sorted and reported once each.
+    pub fn verify_completeness(&self) -> Vec<(TypeId, TypeId)> {
+        let mut missing: Vec<(TypeId, TypeId)> = self
+            .inscribed_products()
+            .into_iter()
+            .flat_map(|product| {
+                self.candidates(product)
+                    .flat_map(|circle| circle.ingredients().iter().copied())
+                    .filter(|&ingredient| self.candidates(ingredient).next().is_none())
+                    .map(move |ingredient| (product, ingredient))
+            })
+            .collect();
+        missing.sort();
+        missing.dedup();
+        missing
+    }
+
+    /// Check that every ingredient of every inscribed circle can be produced by some circle, recursively.
+    ///
+    /// Unlike `find_all_missing_types`, this only considers which circles are inscribed, so it performs no
+    /// transmutations and ignores whether circles have expired. Each missing ingredient is reported once for every
+    /// product consuming it directly.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let products = self.inscribed_products();
+        let consumed: HashSet<TypeId> = products
+            .iter()
+            .flat_map(|&product| self.candidates(product))
+            .flat_map(|circle| circle.ingredients().iter().copied())
+            .collect();
+        // Start from the products nothing consumes so chains are as long as possible, then pick up any cycles.
+        let (roots, rest): (Vec<TypeId>, Vec<TypeId>) = products
+            .into_iter()
+            .partition(|product| !consumed.contains(product));
+        let mut errors = vec![];
+        let mut visited = HashSet::new();
+        for product in roots.into_iter().chain(rest) {
+            self.validate_from(product, &mut vec![], &mut visited, &mut errors);
+        }
+        errors
+    }
+
+    fn validate_from(
+        &self,
+        product: TypeId,
+        chain: &mut Vec<TypeId>,
+        visited: &mut HashSet<TypeId>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        if !visited.insert(product) {
+            return;
+        }
+        chain.push(product);
+        let mut missing = vec![];
+        for circle in self.candidates(product) {
+            for &ingredient in circle.ingredients() {
+                if self.candidates(ingredient).next().is_none() {
+                    if !missing.contains(&ingredient) {
+                        missing.push(ingredient);
+                    }
+                } else {
+                    self.validate_from(ingredient, chain, visited, errors);
+                }
+            }
+        }
+        errors.extend(missing.into_iter().map(|missing| ValidationError {
+            missing,
+            required_by: chain.clone(),
+        }));
+        chain.pop();
+    }
+}
+
+/// Describes a circle by its name and how many ingredients it has.
+struct CircleSummary<'a>(&'a dyn Transmutation);
+
+impl fmt::Display for CircleSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.0.name() {
+            write!(f, "{:?}: ", name)?;
+        }
+        match self.0.ingredients().len() {
+            1 => write!(f, "1 ingredient"),
+            count => write!(f, "{} ingredients", count),
+        }
+    }
+}
+
+impl fmt::Debug for CircleSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Circle")
+            .field("name", &self.0.name())
+            .field("ingredients", &self.0.ingredients().len())
+            .finish()
+    }
+}
+
+/// Lists every product with its circles, which are described by their names and numbers of ingredients.
+impl fmt::Debug for Tome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let products = self.inscribed_products();
+        let circles = products.iter().map(|&product| {
+            let circles: Vec<CircleSummary<'_>> =
+                self.candidates(product).map(CircleSummary).collect();
+            (self.type_registry.describe(product), circles)
+        });
+        f.debug_struct("Tome")
+            .field("products", &products.len())
+            .field("circles", &DebugMap(circles.collect()))
+            .finish()
+    }
+}
+
+/// Formats pairs as a map, keeping their order.
+struct DebugMap<K, V>(Vec<(K, V)>);
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for DebugMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+/// Summarizes every product on one line, such as `Tome { 2 products: [Speed(0 ingredients), Distance(2
+/// ingredients)] }`, with the circles of a product separated by `|`.
+impl fmt::Display for Tome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let products = self.inscribed_products();
+        write!(f, "Tome {{ {} products: [", products.len())?;
+        for (index, &product) in products.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}(", self.type_registry.describe(product))?;
+            for (index, circle) in self.candidates(product).enumerate() {
+                if index > 0 {
+                    write!(f, " | ")?;
+                }
+                write!(f, "{}", CircleSummary(circle))?;
+            }
+            write!(f, ")")?;
+        }
+        write!(f, "] }}")
+    }
+}
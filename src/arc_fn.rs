@@ -0,0 +1,47 @@
+/// Create a circle from a shared function, listing its ingredients and product since they cannot be read from
+/// a closure's parameters.
+///
+/// The function is an `Arc<dyn Fn(&A, &B) -> C + Send + Sync>`, so the same function can be inscribed into many
+/// tomes, including a `SharedTome`, without cloning whatever it captures.
+///
+/// ```
+/// use std::sync::Arc;
+/// use summon::{Tome, circle_arc};
+/// #[derive(Clone)]
+/// struct A(u32);
+/// #[derive(Clone)]
+/// struct B(u32);
+/// struct C(u32);
+/// let add: Arc<dyn Fn(&A, &B) -> C + Send + Sync> = Arc::new(|a, b| C(a.0 + b.0));
+/// let mut tome = Tome::new();
+/// tome.ether(A(1));
+/// tome.ether(B(2));
+/// tome.inscribe(circle_arc!([A, B] => C, add.clone()));
+/// assert_eq!(3, tome.summon::<C>().unwrap().0);
+/// ```
+#[macro_export]
+macro_rules! circle_arc {
+    ([$($arg_ty:tt),*] => $return_ty:tt, $function:expr) => {{
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        struct Temporary(std::sync::Arc<dyn Fn($(&$arg_ty),*) -> $return_ty + Send + Sync>);
+        impl $crate::Transmutation for Temporary {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                if inputs.len() == self.ingredients().len() {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut inputs = inputs.iter();
+                    Box::new((self.0)($(inputs.next().unwrap().downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type")),*)) as Box<dyn Any>
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
+                }
+            }
+        }
+        Temporary($function)
+    }};
+}
@@ -0,0 +1,174 @@
+use crate::{prescribe, Materials, Recipe, Research, SummonError, Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A transmutation whose product is produced asynchronously, such as by fetching it over the network.
+///
+/// Async circles are only performed by `summon_async`, and are invisible to `summon`.
+pub trait AsyncTransmutation {
+    /// The types consumed by the transmutation, in the order they are passed to `transmute_async`.
+    fn ingredients(&self) -> &'static [TypeId];
+    /// The type produced by the transmutation.
+    fn product(&self) -> TypeId;
+    /// Produce the product from the ingredients.
+    fn transmute_async<'a>(
+        &'a self,
+        inputs: &'a [&'a dyn Any],
+    ) -> Pin<Box<dyn Future<Output = Box<dyn Any>> + 'a>>;
+}
+
+/// An async circle as seen by research, which only knows its ingredients and product.
+#[derive(Clone)]
+pub(crate) struct AsyncCircle(Arc<dyn AsyncTransmutation>);
+
+impl Transmutation for AsyncCircle {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.0.product()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        panic!("async circles can only be performed by summon_async");
+    }
+}
+
+/// Create an async circle from an async closure, like the closure form of `circle!`.
+///
+/// ```
+/// use summon::{Tome, circle_async};
+/// #[derive(Clone)]
+/// struct Url(&'static str);
+/// struct Page(String);
+/// let mut tome = Tome::new();
+/// tome.ether(Url("example.com"));
+/// tome.inscribe_async(circle_async!(async |url: &Url| -> Page { Page(format!("<{}>", url.0)) }));
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// assert_eq!("<example.com>", runtime.block_on(tome.summon_async::<Page>()).unwrap().0);
+/// ```
+#[macro_export]
+macro_rules! circle_async {
+    (async |$($arg_name:ident: &$arg_ty:tt),*| -> $return_ty:tt $body:tt) => {{
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        use std::future::Future;
+        use std::pin::Pin;
+        struct Temporary<F>(F);
+        impl<F: AsyncFn($(&$arg_ty),*) -> $return_ty> $crate::AsyncTransmutation for Temporary<F> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn transmute_async<'a>(
+                &'a self,
+                inputs: &'a [&'a dyn Any],
+            ) -> Pin<Box<dyn Future<Output = Box<dyn Any>> + 'a>> {
+                if let [$($arg_name),*] = inputs {
+                    $(let $arg_name = $arg_name.downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type");)*
+                    Box::pin(async move { Box::new((self.0)($($arg_name),*).await) as Box<dyn Any> })
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
+                }
+            }
+        }
+        Temporary(async |$($arg_name: &$arg_ty),*| -> $return_ty $body)
+    }};
+}
+
+impl Tome {
+    /// Inscribe a circle which produces its product asynchronously.
+    ///
+    /// Async circles are tried after every normal circle for their product.
+    pub fn inscribe_async<C: AsyncTransmutation + 'static>(&mut self, circle: C) {
+        self.assert_unpinned(circle.product());
+        self.version += 1;
+        self.forget_cached_id(circle.product());
+        let circles = self.async_circles.entry(circle.product()).or_default();
+        circles.push(AsyncCircle(Arc::new(circle)));
+        circles.sort_by_key(|circle| circle.ingredients().len());
+    }
+
+    /// Find a recipe for `T` which may use async circles.
+    pub(crate) fn research_async<T: 'static>(&self) -> Option<Recipe<'_>> {
+        self.research_in(
+            TypeId::of::<T>(),
+            &mut Research {
+                asynchronous: true,
+                ..Research::default()
+            },
+        )
+    }
+
+    /// The async circles producing `id`, if they may be used.
+    pub(crate) fn async_candidates(
+        &self,
+        id: TypeId,
+        asynchronous: bool,
+    ) -> impl Iterator<Item = &dyn Transmutation> + '_ {
+        self.async_circles
+            .get(&id)
+            .filter(|_| asynchronous)
+            .into_iter()
+            .flatten()
+            .map(|circle| circle as &dyn Transmutation)
+    }
+
+    /// Perform a recipe which may contain async circles, yielding to other tasks between each batch of steps,
+    /// and stopping at the first transmutation which fails.
+    ///
+    /// Every step whose ingredients are already available is performed in the same batch, with the async ones
+    /// awaited concurrently.
+    pub(crate) async fn perform_async(&self, recipe: Recipe<'_>) -> Result<Materials, SummonError> {
+        let mut materials = self.materials();
+        let mut pending = recipe.steps;
+        while !pending.is_empty() {
+            let (ready, rest): (Vec<&dyn Transmutation>, _) =
+                pending.into_iter().partition(|step| {
+                    step.ingredients()
+                        .iter()
+                        .all(|&ingredient| materials.get_id(ingredient).is_some())
+                });
+            let mut waiting = vec![];
+            for step in ready {
+                match self.async_circle(step) {
+                    Some(circle) => waiting.push((step, circle)),
+                    None => materials.try_apply(step)?,
+                }
+            }
+            let products = futures::future::join_all(waiting.into_iter().map(|(step, circle)| {
+                let inputs: Vec<&dyn Any> = circle
+                    .ingredients()
+                    .iter()
+                    .map(|&ingredient| materials.get_id(ingredient).unwrap())
+                    .collect();
+                async move { (step, circle.transmute_async(&inputs).await) }
+            }))
+            .await;
+            for (step, product) in products {
+                if !materials.accepted(step.product(), &*product) {
+                    return Err(SummonError::TransmutationFailed {
+                        at: step.product(),
+                        source: Box::new(prescribe::Rejected),
+                    });
+                }
+                materials.insert(step, product);
+            }
+            tokio::task::yield_now().await;
+            pending = rest;
+        }
+        Ok(materials)
+    }
+
+    /// The async circle which a step of a recipe refers to, if it is one.
+    fn async_circle(&self, step: &dyn Transmutation) -> Option<&dyn AsyncTransmutation> {
+        self.async_circles
+            .get(&step.product())?
+            .iter()
+            .find(|&circle| std::ptr::addr_eq(circle, step))
+            .map(|circle| &*circle.0)
+    }
+}
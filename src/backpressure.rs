@@ -0,0 +1,77 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::sync::Arc;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+impl Tome {
+    /// Inscribe a circle producing `T` which at most `max_queue` async summons may be performing at once.
+    ///
+    /// Async summons whose recipe uses the circle wait for a permit before performing any of the recipe, and
+    /// hold it until they finish.
+    pub fn inscribe_with_backpressure<T: 'static, C: Transmutation + 'static>(
+        &mut self,
+        circle: C,
+        max_queue: usize,
+    ) {
+        let product = TypeId::of::<T>();
+        assert_eq!(
+            product,
+            circle.product(),
+            "backpressured circle must produce the given type"
+        );
+        self.backpressure
+            .insert(product, Arc::new(Semaphore::new(max_queue)));
+        self.inscribe(circle);
+    }
+
+    /// Summon `T`, waiting for permits from every backpressured circle in its recipe and yielding to other tasks
+    /// between steps.
+    ///
+    /// Async circles may be used as well as normal ones, and async steps which do not depend on each other are
+    /// awaited concurrently.
+    pub async fn summon_async<T: 'static>(&self) -> Option<T> {
+        let recipe = self.research_async::<T>()?;
+        let mut permits = vec![];
+        for semaphore in self.recipe_semaphores(&recipe.steps) {
+            permits.push(
+                semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed"),
+            );
+        }
+        let materials = self.perform_async(recipe).await.ok()?;
+        drop(permits);
+        Some(self.extract(materials))
+    }
+
+    /// Summon `T` like `summon_async`, but return `None` straight away if any backpressured circle in its recipe
+    /// has no permits left.
+    pub async fn try_summon_async_nb<T: 'static>(&self) -> Option<T> {
+        let recipe = self.research_async::<T>()?;
+        let permits: Vec<SemaphorePermit<'_>> = self
+            .recipe_semaphores(&recipe.steps)
+            .into_iter()
+            .map(|semaphore| semaphore.try_acquire().ok())
+            .collect::<Option<_>>()?;
+        let materials = self.perform_async(recipe).await.ok()?;
+        drop(permits);
+        Some(self.extract(materials))
+    }
+
+    /// The semaphores of the backpressured circles in a recipe, in a consistent order so that summons never
+    /// deadlock while acquiring them.
+    fn recipe_semaphores(&self, steps: &[&dyn Transmutation]) -> Vec<&Semaphore> {
+        let mut products: Vec<TypeId> = steps
+            .iter()
+            .map(|step| step.product())
+            .filter(|product| self.backpressure.contains_key(product))
+            .collect();
+        products.sort();
+        products.dedup();
+        products
+            .iter()
+            .map(|product| &*self.backpressure[product])
+            .collect()
+    }
+}
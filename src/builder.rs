@@ -0,0 +1,120 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock};
+
+/// A transmutation made from a function operating on type-erased ingredients.
+struct FnTransmutation<F> {
+    ingredients: &'static [TypeId],
+    product: TypeId,
+    transmute: F,
+}
+
+impl<F: Fn(&[&dyn Any]) -> Box<dyn Any>> Transmutation for FnTransmutation<F> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.ingredients
+    }
+    fn product(&self) -> TypeId {
+        self.product
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        (self.transmute)(inputs)
+    }
+}
+
+/// Builds a transmutation producing `O` without the `circle!` macro.
+///
+/// ```
+/// use summon::{Tome, TransmutationBuilder};
+/// #[derive(Clone)]
+/// struct Width(u32);
+/// #[derive(Clone)]
+/// struct Height(u32);
+/// struct Area(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(Width(3));
+/// tome.ether(Height(4));
+/// tome.inscribe(
+///     TransmutationBuilder::new::<Area>()
+///         .require::<Width>()
+///         .require::<Height>()
+///         .build(|inputs| {
+///             let w = inputs[0].downcast_ref::<Width>().unwrap();
+///             let h = inputs[1].downcast_ref::<Height>().unwrap();
+///             Area(w.0 * h.0)
+///         }),
+/// );
+/// assert_eq!(12, tome.summon::<Area>().unwrap().0);
+/// ```
+pub struct TransmutationBuilder<O = ()> {
+    ingredients: Vec<TypeId>,
+    _product: PhantomData<fn() -> O>,
+}
+
+impl TransmutationBuilder {
+    /// Start building a transmutation producing `O`, which requires no ingredients until some are added.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<O: 'static>() -> TransmutationBuilder<O> {
+        TransmutationBuilder {
+            ingredients: vec![],
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<O: 'static> TransmutationBuilder<O> {
+    /// Add an ingredient, which is passed to the function after the ingredients added before it.
+    pub fn require<I: 'static>(mut self) -> Self {
+        self.ingredients.push(TypeId::of::<I>());
+        self
+    }
+
+    /// Finish the transmutation with a function which is passed the ingredients in the order they were required.
+    pub fn build(
+        self,
+        transmute: impl Fn(&[&dyn Any]) -> O + 'static,
+    ) -> impl Transmutation + 'static {
+        FnTransmutation {
+            ingredients: intern(self.ingredients),
+            product: TypeId::of::<O>(),
+            transmute: move |inputs: &[&dyn Any]| Box::new(transmute(inputs)) as Box<dyn Any>,
+        }
+    }
+}
+
+impl Tome {
+    /// Inscribe a function operating on type-erased ingredients, without the `circle!` macro.
+    ///
+    /// The function is passed the ingredients in the order they are listed and must return a boxed `product`.
+    /// Nothing checks this, so prefer `TransmutationBuilder` when the types are known.
+    pub fn inscribe_fn(
+        &mut self,
+        ingredients: &'static [TypeId],
+        product: TypeId,
+        f: impl Fn(&[&dyn Any]) -> Box<dyn Any> + 'static,
+    ) {
+        self.inscribe(FnTransmutation {
+            ingredients,
+            product,
+            transmute: f,
+        });
+    }
+}
+
+/// A `'static` slice with the same ingredients, shared by every transmutation built with them.
+///
+/// Each distinct list of ingredients is leaked the first time it is built and never freed, so building many
+/// transmutations with the same ingredients does not keep allocating.
+fn intern(ingredients: Vec<TypeId>) -> &'static [TypeId] {
+    static INTERNED: OnceLock<Mutex<HashSet<&'static [TypeId]>>> = OnceLock::new();
+    let mut interned = INTERNED.get_or_init(Default::default).lock().unwrap();
+    match interned.get(&*ingredients) {
+        Some(&ingredients) => ingredients,
+        None => {
+            let ingredients: &'static [TypeId] = Box::leak(ingredients.into_boxed_slice());
+            interned.insert(ingredients);
+            ingredients
+        }
+    }
+}
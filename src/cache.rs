@@ -0,0 +1,130 @@
+use crate::{Ether, SummonError, Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::rc::Rc;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Performs a transmutation once and then behaves like ether holding the result.
+struct OnceCacheTransmutation<T, C> {
+    circle: C,
+    cached: Arc<RwLock<Option<T>>>,
+}
+
+impl<T: Clone + 'static, C: Transmutation> Transmutation for OnceCacheTransmutation<T, C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        if self.cached.read().unwrap().is_some() {
+            &[]
+        } else {
+            self.circle.ingredients()
+        }
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.try_transmute(inputs)
+            .unwrap_or_else(|error| panic!("transmutation failed: {}", error))
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        if let Some(cached) = &*self.cached.read().unwrap() {
+            return Ok(Box::new(cached.clone()));
+        }
+        let mut cached = self.cached.write().unwrap();
+        if cached.is_none() {
+            // A failure is not cached, so the circle is performed again next time.
+            *cached = Some(
+                *self
+                    .circle
+                    .try_transmute(inputs)?
+                    .downcast::<T>()
+                    .expect("circle produced an incorrect type"),
+            );
+        }
+        Ok(Box::new(cached.clone().unwrap()))
+    }
+    fn name(&self) -> Option<&'static str> {
+        self.circle.name()
+    }
+    fn cost(&self) -> u64 {
+        self.circle.cost()
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        self.circle.ether_value()
+    }
+}
+
+/// Computes a value the first time it is needed and then behaves like ether holding it.
+struct LazyTransmutation<T, F> {
+    cell: Arc<RwLock<OnceLock<T>>>,
+    init: F,
+}
+
+impl<T: Clone + 'static, F: Fn() -> T> Transmutation for LazyTransmutation<T, F> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(self.cell.read().unwrap().get_or_init(&self.init).clone())
+    }
+}
+
+impl Tome {
+    /// Inscribe a circle which is only performed on the first summon, with its product reused afterwards.
+    pub fn inscribe_once_then_cache<T: Clone + 'static, C: Transmutation + 'static>(
+        &mut self,
+        circle: C,
+    ) {
+        assert_eq!(
+            TypeId::of::<T>(),
+            circle.product(),
+            "circle does not produce the cached type"
+        );
+        self.inscribe(OnceCacheTransmutation {
+            circle,
+            cached: Arc::new(RwLock::new(None::<T>)),
+        });
+    }
+
+    /// Create a note about something which can be made out of the ether, but only once it is first needed.
+    ///
+    /// The value is computed at most once and cloned on every summon after that.
+    pub fn inscribe_lazy_computed<T: Clone + 'static, F: Fn() -> T + 'static>(&mut self, f: F) {
+        let cell = Arc::new(RwLock::new(OnceLock::new()));
+        let reset_cell = cell.clone();
+        self.lazies.insert(
+            TypeId::of::<T>(),
+            Rc::new(move || drop(reset_cell.write().unwrap().take())),
+        );
+        self.inscribe(LazyTransmutation { cell, init: f });
+    }
+
+    /// Create a note about how to create something out of the ether, calling `factory` only the first time it
+    /// is needed.
+    ///
+    /// This is the same as `inscribe_lazy_computed`, so the value can be reset with `reset_lazy`.
+    pub fn ether_lazy<T: Clone + 'static, F: Fn() -> T + 'static>(&mut self, factory: F) {
+        self.inscribe_lazy_computed(factory);
+    }
+
+    /// Summon `T` and inscribe the result as ether, tried before the other inscribed circles for `T`, so later
+    /// summons reuse it instead of performing the recipe again.
+    pub fn clone_ether<T: Clone + 'static>(&mut self) -> Result<(), SummonError> {
+        let item = self.summon::<T>()?;
+        self.accumulate(&item);
+        self.inscribe_with_priority(Ether(item), i32::MAX);
+        Ok(())
+    }
+
+    /// Forget the lazily computed value of `T` so that it is computed again when it is next needed.
+    ///
+    /// Returns `false` if `T` was not inscribed as lazily computed.
+    pub fn reset_lazy<T: 'static>(&self) -> bool {
+        self.lazies
+            .get(&TypeId::of::<T>())
+            .map(|reset| reset())
+            .is_some()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{SharedTome, SummonError, Tome};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Borrows a tome and keeps everything summoned through it, leaving the tome itself unchanged.
+///
+/// Nothing is invalidated automatically, so call `invalidate` if the tome changes.
+pub struct CachingTome<'a> {
+    tome: &'a Tome,
+    cache: RefCell<HashMap<TypeId, Box<dyn Any>>>,
+}
+
+impl<'a> CachingTome<'a> {
+    /// Cache the products summoned from `tome`.
+    pub fn new(tome: &'a Tome) -> Self {
+        Self {
+            tome,
+            cache: RefCell::default(),
+        }
+    }
+
+    /// Give me what I want, summoning it from the tome only the first time it is asked for.
+    pub fn summon<T: Clone + 'static>(&self) -> Result<T, SummonError> {
+        let id = TypeId::of::<T>();
+        if let Some(cached) = self.cache.borrow().get(&id) {
+            return Ok(cached.downcast_ref::<T>().unwrap().clone());
+        }
+        let summoned = self.tome.summon::<T>()?;
+        self.cache
+            .borrow_mut()
+            .insert(id, Box::new(summoned.clone()));
+        Ok(summoned)
+    }
+
+    /// Forget every cached product.
+    pub fn invalidate(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+/// Borrows a shared tome and keeps everything summoned through it, like `CachingTome` but usable from many
+/// threads at once.
+pub struct ThreadSafeCachingTome<'a> {
+    tome: &'a SharedTome,
+    cache: RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+}
+
+impl<'a> ThreadSafeCachingTome<'a> {
+    /// Cache the products summoned from `tome`.
+    pub fn new(tome: &'a SharedTome) -> Self {
+        Self {
+            tome,
+            cache: RwLock::default(),
+        }
+    }
+
+    /// Give me what I want, summoning it from the tome only if it has not been cached yet.
+    ///
+    /// Threads which miss the cache at the same time may each summon the product, but only the first is kept.
+    pub fn summon<T: Clone + Send + Sync + 'static>(&self) -> Result<T, SummonError> {
+        let id = TypeId::of::<T>();
+        if let Some(cached) = self.cache.read().unwrap().get(&id) {
+            return Ok(cached.downcast_ref::<T>().unwrap().clone());
+        }
+        let summoned = self.tome.summon::<T>()?;
+        let mut cache = self.cache.write().unwrap();
+        let cached = cache.entry(id).or_insert_with(|| Box::new(summoned));
+        Ok(cached.downcast_ref::<T>().unwrap().clone())
+    }
+
+    /// Forget every cached product.
+    pub fn invalidate(&self) {
+        self.cache.write().unwrap().clear();
+    }
+}
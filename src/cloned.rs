@@ -0,0 +1,42 @@
+/// Create a circle from a closure which takes a clone of each ingredient by value, so ingredients can be bound
+/// with `mut` and modified.
+///
+/// Every ingredient is cloned before the closure is called, so each must be `Clone`.
+///
+/// ```
+/// use summon::{Tome, circle_clone};
+/// #[derive(Clone)]
+/// struct Count(u32);
+/// struct Total(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(Count(2));
+/// tome.inscribe(circle_clone!(|mut c: Count| -> Total { c.0 += 1; Total(c.0) }));
+/// assert_eq!(3, tome.summon::<Total>().unwrap().0);
+/// assert_eq!(2, tome.summon::<Count>().unwrap().0);
+/// ```
+#[macro_export]
+macro_rules! circle_clone {
+    (|$($($arg_bind:ident)+: $arg_ty:tt),*| -> $return_ty:tt $body:tt) => {{
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        struct Temporary<F>(F);
+        impl<F: Fn($($arg_ty),*) -> $return_ty> $crate::Transmutation for Temporary<F> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                if inputs.len() == self.ingredients().len() {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut inputs = inputs.iter();
+                    Box::new((self.0)($(inputs.next().unwrap().downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type").clone()),*)) as Box<dyn Any>
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
+                }
+            }
+        }
+        Temporary(|$($($arg_bind)+: $arg_ty),*| -> $return_ty $body)
+    }};
+}
@@ -0,0 +1,73 @@
+use crate::{Conversion, Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+/// Ether holding a collection which can be added to and removed from after it is inscribed.
+struct VecEther<T>(Arc<RwLock<Vec<T>>>);
+
+impl<T: Clone + 'static> Transmutation for VecEther<T> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<Vec<T>>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(self.0.read().unwrap().clone())
+    }
+}
+
+impl Tome {
+    /// Get the collection ether for `Vec<T>`, inscribing an empty one if there is none yet.
+    fn vec_ether<T: Clone + 'static>(&mut self) -> Arc<RwLock<Vec<T>>> {
+        let product = TypeId::of::<Vec<T>>();
+        if let Some(items) = self.vec_ethers.get(&product) {
+            return items
+                .downcast_ref::<Arc<RwLock<Vec<T>>>>()
+                .expect("collection ether has an incorrect type")
+                .clone();
+        }
+        let items = Arc::new(RwLock::new(Vec::<T>::new()));
+        self.vec_ethers.insert(product, Rc::new(items.clone()));
+        self.inscribe(VecEther(items.clone()));
+        items
+    }
+
+    /// Add all of the items to the collection ether which produces `Vec<T>`.
+    pub fn inscribe_from_iter_as_vec<T: Clone + 'static>(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+    ) {
+        self.vec_ether::<T>().write().unwrap().extend(items);
+    }
+
+    /// Add an item to the end of the collection ether which produces `Vec<T>`.
+    pub fn inscribe_push_ether<T: Clone + 'static>(&mut self, item: T) {
+        self.vec_ether::<T>().write().unwrap().push(item);
+    }
+
+    /// Create a note about how to create `T` out of the ether, and collect every ether value of `T` into a
+    /// summonable `Vec<T>`.
+    ///
+    /// The collection is an accumulator, so it includes values inscribed with `ether` before and after it, in
+    /// the order they were inscribed. It is only added if nothing produces `Vec<T>` yet.
+    pub fn ether_push<T: Clone + 'static>(&mut self, item: T) {
+        self.ether(item);
+        if self.candidates(TypeId::of::<Vec<T>>()).next().is_none() {
+            self.inscribe_accumulator::<Vec<T>, T, _>(Conversion(T::clone, PhantomData));
+        }
+    }
+
+    /// Remove the item at the end of the collection ether which produces `Vec<T>`.
+    pub fn inscribe_pop_ether<T: 'static>(&mut self) -> Option<T> {
+        self.vec_ethers
+            .get(&TypeId::of::<Vec<T>>())?
+            .downcast_ref::<Arc<RwLock<Vec<T>>>>()
+            .expect("collection ether has an incorrect type")
+            .write()
+            .unwrap()
+            .pop()
+    }
+}
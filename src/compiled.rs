@@ -0,0 +1,49 @@
+use crate::{Recipe, SummonError, Tome};
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// Borrows a tome along with a recipe for every product which could be summoned when it was compiled.
+///
+/// Summoning only looks up and performs a recipe. Nothing can be inscribed into the tome while it is borrowed,
+/// and recipes are not researched again, so circles which decline or expire are not worked around.
+pub struct CompiledTome<'a> {
+    tome: &'a Tome,
+    recipes: HashMap<TypeId, Recipe<'a>>,
+}
+
+impl CompiledTome<'_> {
+    /// Give me what I want using the compiled recipe, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        let id = TypeId::of::<T>();
+        let recipe = self
+            .recipes
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| self.tome.diagnose(id))?;
+        recipe.execute(self.tome)
+    }
+
+    /// Give me what I want using the compiled recipe, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+
+    /// Whether a recipe for `T` was compiled.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.recipes.contains_key(&TypeId::of::<T>())
+    }
+}
+
+impl Tome {
+    /// Research a recipe for every product in the tome once, so that summoning from the result does no research.
+    pub fn compile(&self) -> CompiledTome<'_> {
+        CompiledTome {
+            tome: self,
+            recipes: self
+                .inscribed_products()
+                .into_iter()
+                .filter_map(|id| self.research_id(id).map(|recipe| (id, recipe)))
+                .collect(),
+        }
+    }
+}
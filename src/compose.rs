@@ -0,0 +1,97 @@
+use crate::{Conversion, Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::marker::PhantomData;
+
+/// Combines two ingredients into a product with a function.
+struct Combination<A, B, C, F>(F, PhantomData<fn(&A, &B) -> C>);
+
+impl<A: 'static, B: 'static, C, F> Combination<A, B, C, F> {
+    const INGREDIENTS: &'static [TypeId] = &[TypeId::of::<A>(), TypeId::of::<B>()];
+}
+
+impl<A: 'static, B: 'static, C: 'static, F: Fn(&A, &B) -> C> Transmutation
+    for Combination<A, B, C, F>
+{
+    fn ingredients(&self) -> &'static [TypeId] {
+        Self::INGREDIENTS
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<C>()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new((self.0)(
+            inputs[0]
+                .downcast_ref::<A>()
+                .expect("transmute passed an incorrect type"),
+            inputs[1]
+                .downcast_ref::<B>()
+                .expect("transmute passed an incorrect type"),
+        ))
+    }
+}
+
+/// Performs one circle and then another on its product, as a single circle.
+struct Composed<F, G>(F, G);
+
+impl<F: Transmutation, G: Transmutation> Transmutation for Composed<F, G> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.1.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.1.transmute(&[&*self.0.transmute(inputs)])
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.1.try_transmute(&[&*self.0.try_transmute(inputs)?])
+    }
+    fn expired(&self) -> bool {
+        self.0.expired() || self.1.expired()
+    }
+    fn cost(&self) -> u64 {
+        self.0.cost().saturating_add(self.1.cost())
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        // Only the second circle produces the composed product.
+        self.1.ether_value()
+    }
+}
+
+impl Tome {
+    /// Combine a circle with one consuming only its product into a single circle, so the intermediate product is
+    /// never inscribed.
+    ///
+    /// Panics if `bc` requires anything other than the product of `ab`.
+    pub fn compose(
+        ab: impl Transmutation + 'static,
+        bc: impl Transmutation + 'static,
+    ) -> impl Transmutation + 'static {
+        assert_eq!(
+            &[ab.product()],
+            bc.ingredients(),
+            "the second circle must consume only the product of the first"
+        );
+        Composed(ab, bc)
+    }
+
+    /// Inscribe `g ∘ f` as a circle producing `C` from `A` and `B` followed by a circle producing `D` from `C`.
+    ///
+    /// Both `C` and `D` can be summoned afterwards.
+    pub fn inscribe_composed<A: 'static, B: 'static, C: 'static, D: 'static>(
+        &mut self,
+        f: impl Fn(&A, &B) -> C + 'static,
+        g: impl Fn(&C) -> D + 'static,
+    ) {
+        self.inscribe(Combination(f, PhantomData));
+        self.inscribe(Conversion(g, PhantomData));
+        self.compositions
+            .insert(TypeId::of::<D>(), TypeId::of::<C>());
+    }
+
+    /// The intermediate product `D` was composed through, if it was inscribed with `inscribe_composed`.
+    pub fn composed_through<D: 'static>(&self) -> Option<TypeId> {
+        self.compositions.get(&TypeId::of::<D>()).copied()
+    }
+}
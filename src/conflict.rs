@@ -0,0 +1,142 @@
+use crate::{Recipe, Tome, Transmutation};
+use std::any::TypeId;
+
+/// A circle which could produce a product.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircleInfo {
+    /// The ingredients the circle consumes.
+    pub ingredients: Vec<TypeId>,
+    /// The number of steps in the recipe ending with this circle.
+    pub recipe_steps: usize,
+}
+
+/// Why one circle was selected over the others.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionReason {
+    /// It was inscribed with this priority, which is higher than that of every competing circle.
+    ByPriority(i32),
+    /// Preferences given with `prefer` put it ahead of this many competing circles.
+    ByPreference(usize),
+    /// It consumes the fewest ingredients, namely this many.
+    ByIngredientCount(usize),
+    /// Its recipe is the cheapest, with this total cost.
+    ByCost(u64),
+    /// It was inscribed before the other circles.
+    ByRegistrationOrder,
+}
+
+/// A product which more than one circle could currently produce, made with `Tome::report_ambiguities`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmbiguityReport {
+    /// The product.
+    pub product: TypeId,
+    /// The competing circles, in the order they are tried, each described by its name or otherwise by its
+    /// index among every circle producing the product.
+    pub competing_circles: Vec<String>,
+}
+
+/// Describes how the circle used to summon a product was chosen between several valid circles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConflictReport {
+    /// Every circle which could currently produce the product, in the order they are tried.
+    pub competing_circles: Vec<CircleInfo>,
+    /// The index of the circle in `competing_circles` which is used.
+    pub selected: usize,
+    /// Why that circle is used.
+    pub reason: SelectionReason,
+}
+
+impl Tome {
+    /// Explain how a circle is chosen to summon `T` when more than one circle could produce it.
+    ///
+    /// Returns `None` if at most one circle can currently produce `T`.
+    pub fn explain_resolution_conflict<T: 'static>(&self) -> Option<ConflictReport> {
+        let id = TypeId::of::<T>();
+        let competing: Vec<(&dyn Transmutation, Recipe<'_>)> = self
+            .candidates(id)
+            .filter_map(|circle| {
+                self.research_circle(id, circle)
+                    .map(|recipe| (circle, recipe))
+            })
+            .collect();
+        if competing.len() < 2 {
+            return None;
+        }
+
+        // Choose the circle the same way researching does, which is the first of the cheapest recipes unless the
+        // circles are ordered explicitly.
+        let explicit = self.ordered_explicitly(id);
+        let mut selected = 0;
+        if !explicit {
+            for (index, (_, recipe)) in competing.iter().enumerate() {
+                if recipe.cost() < competing[selected].1.cost() {
+                    selected = index;
+                }
+            }
+        }
+        let (circle, recipe) = &competing[selected];
+        let others = || {
+            competing
+                .iter()
+                .enumerate()
+                .filter(move |&(index, _)| index != selected)
+                .map(|(_, other)| other)
+        };
+        let ingredient_count = circle.ingredients().len();
+        let preferred_over = others()
+            .filter(|&&(other, _)| self.is_preferred(id, *circle, other))
+            .count();
+        let priority = self.priority_of(id, *circle);
+        let reason = if others().all(|&(other, _)| self.priority_of(id, other) < priority) {
+            SelectionReason::ByPriority(priority)
+        } else if preferred_over > 0 {
+            SelectionReason::ByPreference(preferred_over)
+        } else if others().all(|(other, _)| other.ingredients().len() > ingredient_count) {
+            SelectionReason::ByIngredientCount(ingredient_count)
+        } else if !explicit && others().all(|(_, other)| other.cost() > recipe.cost()) {
+            SelectionReason::ByCost(recipe.cost())
+        } else {
+            SelectionReason::ByRegistrationOrder
+        };
+
+        Some(ConflictReport {
+            competing_circles: competing
+                .iter()
+                .map(|(circle, recipe)| CircleInfo {
+                    ingredients: circle.ingredients().to_vec(),
+                    recipe_steps: recipe.steps().len(),
+                })
+                .collect(),
+            selected,
+            reason,
+        })
+    }
+
+    /// Find every product which more than one circle could currently produce, sorted by product.
+    ///
+    /// Which of the competing circles is used depends on their costs and the order they are tried in, which
+    /// `explain_resolution_conflict` describes in more detail.
+    pub fn report_ambiguities(&self) -> Vec<AmbiguityReport> {
+        self.inscribed_products()
+            .into_iter()
+            .filter_map(|product| {
+                let competing_circles: Vec<String> = self
+                    .candidates(product)
+                    .enumerate()
+                    .filter(|&(_, circle)| self.research_circle(product, circle).is_some())
+                    .map(|(index, circle)| match circle.name() {
+                        Some(name) => name.to_owned(),
+                        None => format!("#{}", index),
+                    })
+                    .collect();
+                if competing_circles.len() < 2 {
+                    return None;
+                }
+                Some(AmbiguityReport {
+                    product,
+                    competing_circles,
+                })
+            })
+            .collect()
+    }
+}
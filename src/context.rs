@@ -0,0 +1,244 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::error::Error;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Modifies a tome and instruments the circles inscribed into it.
+pub trait Context: 'static {
+    /// Modify the tome when the context is added to it.
+    fn apply(&self, tome: &mut Tome);
+
+    /// Instrument a circle as it is inscribed.
+    fn wrap(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        circle
+    }
+
+    /// Combine this context with another, which is applied second and wraps circles outermost.
+    fn and_then<C: Context>(self, next: C) -> AndThen<Self, C>
+    where
+        Self: Sized,
+    {
+        AndThen(self, next)
+    }
+}
+
+/// Two contexts applied one after the other.
+pub struct AndThen<A, B>(A, B);
+
+impl<A: Context, B: Context> Context for AndThen<A, B> {
+    fn apply(&self, tome: &mut Tome) {
+        self.0.apply(tome);
+        self.1.apply(tome);
+    }
+
+    fn wrap(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        self.1.wrap(self.0.wrap(circle))
+    }
+}
+
+/// The outcome of performing a circle.
+type Transmuted = Result<Box<dyn Any>, Box<dyn Error>>;
+
+/// A circle which runs a hook around each transmutation.
+struct Instrumented<F> {
+    circle: Box<dyn Transmutation>,
+    hook: F,
+}
+
+impl<F: Fn(&dyn Transmutation, &dyn Fn() -> Transmuted) -> Transmuted> Transmutation
+    for Instrumented<F>
+{
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.circle.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.circle.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.try_transmute(inputs)
+            .unwrap_or_else(|error| panic!("transmutation failed: {}", error))
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Transmuted {
+        (self.hook)(&*self.circle, &|| self.circle.try_transmute(inputs))
+    }
+    fn expired(&self) -> bool {
+        self.circle.expired()
+    }
+    fn name(&self) -> Option<&'static str> {
+        self.circle.name()
+    }
+    fn cost(&self) -> u64 {
+        self.circle.cost()
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        self.circle.ether_value()
+    }
+}
+
+/// Logs a line every time a circle is performed.
+#[derive(Clone)]
+pub struct LoggingContext {
+    logger: Arc<dyn Fn(&str)>,
+}
+
+impl LoggingContext {
+    /// Log each transmutation by calling `logger`.
+    pub fn new(logger: impl Fn(&str) + 'static) -> Self {
+        Self {
+            logger: Arc::new(logger),
+        }
+    }
+}
+
+impl Context for LoggingContext {
+    fn apply(&self, _: &mut Tome) {}
+
+    fn wrap(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        let logger = self.logger.clone();
+        Box::new(Instrumented {
+            circle,
+            hook: move |circle: &dyn Transmutation, transmute: &dyn Fn() -> Transmuted| {
+                logger(&format!(
+                    "transmuting {:?} from {} ingredients",
+                    circle.product(),
+                    circle.ingredients().len()
+                ));
+                transmute()
+            },
+        })
+    }
+}
+
+/// Counts how many times each product is transmuted.
+#[derive(Clone, Default)]
+pub struct MetricsContext {
+    counts: Arc<Mutex<HashMap<TypeId, u64>>>,
+}
+
+impl MetricsContext {
+    /// Create a context with no counts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of times each product has been transmuted.
+    pub fn counts(&self) -> HashMap<TypeId, u64> {
+        self.counts.lock().unwrap().clone()
+    }
+}
+
+impl Context for MetricsContext {
+    fn apply(&self, _: &mut Tome) {}
+
+    fn wrap(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        let counts = self.counts.clone();
+        Box::new(Instrumented {
+            circle,
+            hook: move |circle: &dyn Transmutation, transmute: &dyn Fn() -> Transmuted| {
+                *counts.lock().unwrap().entry(circle.product()).or_default() += 1;
+                transmute()
+            },
+        })
+    }
+}
+
+/// A single transmutation recorded by a `TracingContext`.
+#[derive(Copy, Clone, Debug)]
+pub struct TracingSpan {
+    /// The product of the transmutation.
+    pub product: TypeId,
+    /// When the transmutation began.
+    pub start: Instant,
+    /// How long the transmutation took.
+    pub duration: Duration,
+}
+
+/// Records the timing of every transmutation.
+#[derive(Clone, Default)]
+pub struct TracingContext {
+    spans: Arc<Mutex<Vec<TracingSpan>>>,
+}
+
+impl TracingContext {
+    /// Create a context with no spans.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every transmutation recorded so far, in the order they finished.
+    pub fn spans(&self) -> Vec<TracingSpan> {
+        self.spans.lock().unwrap().clone()
+    }
+}
+
+impl Context for TracingContext {
+    fn apply(&self, _: &mut Tome) {}
+
+    fn wrap(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        let spans = self.spans.clone();
+        Box::new(Instrumented {
+            circle,
+            hook: move |circle: &dyn Transmutation, transmute: &dyn Fn() -> Transmuted| {
+                let start = Instant::now();
+                let product = transmute();
+                spans.lock().unwrap().push(TracingSpan {
+                    product: circle.product(),
+                    start,
+                    duration: start.elapsed(),
+                });
+                product
+            },
+        })
+    }
+}
+
+impl Tome {
+    /// Add a context to the tome, instrumenting every circle inscribed from now on.
+    pub fn with_context<C: Context>(&mut self, context: C) {
+        context.apply(self);
+        self.contexts.push(Rc::new(context));
+    }
+
+    /// Add a context to the tome, instrumenting every circle already inscribed as well as those inscribed later.
+    ///
+    /// This includes overrides along with their history, fallback chains, versioned, conditional and weighted
+    /// circles. Async circles are never instrumented by contexts.
+    pub fn with_context_retroactive<C: Context>(&mut self, context: C) {
+        context.apply(self);
+        self.version += 1;
+        self.invalidate_cache();
+        let wrap = |circle: &Arc<dyn Transmutation>| -> Arc<dyn Transmutation> {
+            Arc::from(context.wrap(Box::new(circle.clone())))
+        };
+        for (_, circle) in self.circles.values_mut().flatten() {
+            *circle = wrap(circle);
+        }
+        for circle in self.overrides.values_mut().flatten() {
+            *circle = wrap(circle);
+        }
+        for chain in self.fallback_chains.values_mut().flatten() {
+            chain.instrument(wrap);
+        }
+        for (_, circle) in self.schema_versions.values_mut().flatten() {
+            *circle = wrap(circle);
+        }
+        for conditional in self.conditionals.values_mut().flatten() {
+            conditional.instrument(wrap);
+        }
+        #[cfg(feature = "rand")]
+        for (_, circle) in self.weighted.values_mut().flatten() {
+            *circle = wrap(circle);
+        }
+        self.contexts.push(Rc::new(context));
+    }
+
+    /// Instrument a circle with every context in the order they were added.
+    pub(crate) fn contextualize(&self, circle: Box<dyn Transmutation>) -> Box<dyn Transmutation> {
+        self.contexts
+            .iter()
+            .fold(circle, |circle, context| context.wrap(circle))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Transmutation;
+use std::any::{Any, TypeId};
+use std::error::Error;
+
+/// A transmutation annotated with how expensive it is to perform, which is used to choose between recipes.
+pub struct Costed<C>(pub C, pub u64);
+
+impl<C: Transmutation> Costed<C> {
+    /// Annotate `circle` with `cost`.
+    pub fn new(circle: C, cost: u64) -> Self {
+        Self(circle, cost)
+    }
+}
+
+impl<C: Transmutation> Transmutation for Costed<C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.0.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.0.transmute(inputs)
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.0.try_transmute(inputs)
+    }
+    fn expired(&self) -> bool {
+        self.0.expired()
+    }
+    fn name(&self) -> Option<&'static str> {
+        self.0.name()
+    }
+    fn cost(&self) -> u64 {
+        self.1
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        self.0.ether_value()
+    }
+}
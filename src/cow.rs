@@ -0,0 +1,58 @@
+use crate::{observe, SummonError, Tome};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+impl Tome {
+    /// Create a note about a large read-only value, which `summon_cow` borrows from the tome instead of cloning.
+    ///
+    /// The value is kept behind an `Arc`, so circles consume `&Arc<T>` and summoning `Arc<T>` only clones the
+    /// pointer.
+    pub fn ether_cow<T: 'static>(&mut self, item: T) {
+        self.ether(Arc::new(item));
+    }
+
+    /// Summon `T`, borrowing it from the tome when it comes straight out of the ether.
+    ///
+    /// Values given to `ether_cow` or `ether` are returned as `Cow::Borrowed` without being cloned, after passing
+    /// their prescriptions and telling their observers and subscribers just like `summon` would. Anything else,
+    /// including a precomputed `T` or anything summoned while the cache is enabled, is summoned as usual and
+    /// returned as `Cow::Owned`.
+    pub fn summon_cow<T: Clone + 'static>(&self) -> Result<Cow<'_, T>, SummonError> {
+        if self.memo.enabled || self.precomputed.contains_key(&TypeId::of::<T>()) {
+            return self.summon::<T>().map(Cow::Owned);
+        }
+        let borrowed = match self.borrow_ether::<Arc<T>>() {
+            Some(value) => Some(&**value),
+            None => self.borrow_ether::<T>(),
+        };
+        match borrowed {
+            Some(value) => {
+                #[cfg(feature = "subscriptions")]
+                self.notify_subscribers(value);
+                Ok(Cow::Borrowed(value))
+            }
+            None => self.summon::<T>().map(Cow::Owned),
+        }
+    }
+
+    /// Borrow the value held by the circle used to summon `T`, if it is a single ether whose value passes its
+    /// prescriptions, counting the step and telling its observers as if it were performed.
+    fn borrow_ether<T: 'static>(&self) -> Option<&T> {
+        let id = TypeId::of::<T>();
+        if self.precomputed.contains_key(&id) {
+            return None;
+        }
+        let [step] = *self.research_id(id)?.steps() else {
+            return None;
+        };
+        let value = step.ether_value()?.downcast_ref::<T>()?;
+        let mut materials = self.materials();
+        if !materials.accepted(id, value) {
+            return None;
+        }
+        materials.record(step);
+        observe::notify(&self.observers, id, value);
+        Some(value)
+    }
+}
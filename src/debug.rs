@@ -0,0 +1,30 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+
+impl Tome {
+    /// Inscribe a circle producing `T` only in debug builds.
+    ///
+    /// In release builds, or with the `simulate-release` feature, the circle is dropped without being inscribed.
+    #[allow(unused_variables)]
+    pub fn inscribe_debug_only<T: 'static, C: Transmutation + 'static>(&mut self, circle: C) {
+        assert_eq!(
+            TypeId::of::<T>(),
+            circle.product(),
+            "debug circle must produce the given type"
+        );
+        #[cfg(all(debug_assertions, not(feature = "simulate-release")))]
+        self.inscribe(circle);
+    }
+
+    /// Inscribe a circle producing `T` only when summon is built for tests, meaning with the `testing` feature.
+    #[allow(unused_variables)]
+    pub fn inscribe_test_only<T: 'static, C: Transmutation + 'static>(&mut self, circle: C) {
+        assert_eq!(
+            TypeId::of::<T>(),
+            circle.product(),
+            "test circle must produce the given type"
+        );
+        #[cfg(any(test, feature = "testing"))]
+        self.inscribe(circle);
+    }
+}
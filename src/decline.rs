@@ -0,0 +1,103 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::fmt;
+
+/// A transmutation which can decline to produce its product, such as one which depends on a runtime flag.
+///
+/// When a circle declines, `summon` researches the product again without it and tries the next alternative.
+pub trait TryTransmutation {
+    fn ingredients(&self) -> &'static [TypeId];
+    fn product(&self) -> TypeId;
+    /// Perform the transmutation, or return `None` to decline.
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Option<Box<dyn Any>>;
+}
+
+/// The failure reported by a circle which declined to produce its product.
+#[derive(Debug)]
+pub(crate) struct Declined;
+
+impl fmt::Display for Declined {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "circle declined to produce its product")
+    }
+}
+
+impl Error for Declined {}
+
+/// Adapts a transmutation which can decline so it can be inscribed, letting `summon` fall back to other circles.
+pub struct Declinable<C>(pub C);
+
+impl<C: TryTransmutation> Transmutation for Declinable<C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.0.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.0
+            .try_transmute(inputs)
+            .unwrap_or_else(|| panic!("transmutation failed: {}", Declined))
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.0.try_transmute(inputs).ok_or_else(|| Declined.into())
+    }
+}
+
+/// Create a circle from a closure returning an `Option`, which declines to produce its product with `None`.
+///
+/// ```
+/// use summon::{Tome, circle, circle_try};
+/// #[derive(Clone)]
+/// struct Reading(i32);
+/// #[derive(Debug, PartialEq)]
+/// struct Level(&'static str);
+/// let mut tome = Tome::new();
+/// tome.ether(Reading(-3));
+/// tome.inscribe(circle_try!(|r: &Reading| -> Option<Level> { if r.0 > 0 { Some(Level("high")) } else { None } }));
+/// tome.inscribe(circle!(Reading => Level { Level("low") }));
+/// assert_eq!(Level("low"), tome.summon::<Level>().unwrap());
+/// ```
+///
+/// Like `circle!`, a circle consuming its own product fails to compile.
+///
+/// ```compile_fail
+/// use summon::circle_try;
+/// #[derive(Clone)]
+/// struct Level(i32);
+/// circle_try!(|l: &Level| -> Option<Level> { Some(Level(l.0 + 1)) });
+/// ```
+#[macro_export]
+macro_rules! circle_try {
+    (|$($arg_name:tt: &$arg_ty:tt),*| -> Option<$return_ty:tt> $body:tt) => {{
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        struct Temporary<F>(F);
+        impl<F: Fn($(&$arg_ty),*) -> Option<$return_ty>> $crate::TryTransmutation for Temporary<F> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn try_transmute(&self, inputs: &[&dyn Any]) -> Option<Box<dyn Any>> {
+                if inputs.len() == self.ingredients().len() {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut inputs = inputs.iter();
+                    (self.0)($(inputs.next().unwrap().downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type")),*).map(|product| Box::new(product) as Box<dyn Any>)
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
+                }
+            }
+        }
+        $crate::Declinable(Temporary(|$($arg_name: &$arg_ty),*| -> Option<$return_ty> $body))
+    }};
+}
+
+impl Tome {
+    /// Inscribe a note about a transmutation which can decline to produce its product into the tome.
+    pub fn inscribe_try<C: TryTransmutation + 'static>(&mut self, circle: C) {
+        self.inscribe(Declinable(circle));
+    }
+}
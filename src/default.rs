@@ -0,0 +1,62 @@
+use crate::group::ConditionalTransmutation;
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Produces the default value of a type out of the ether.
+struct DefaultEther<T>(PhantomData<fn() -> T>);
+
+impl<T: Default + 'static> Transmutation for DefaultEther<T> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(T::default())
+    }
+}
+
+impl Tome {
+    /// Create a note about making `T::default()` out of the ether.
+    ///
+    /// The default is made every time `T` is summoned rather than now, so nothing is constructed until it is needed.
+    pub fn inscribe_default<T: Default + 'static>(&mut self) {
+        self.inscribe(DefaultEther::<T>(PhantomData));
+    }
+
+    /// Create a note about making `T::default()` out of the ether only the first time it is needed, cloning it
+    /// on every summon after that.
+    ///
+    /// This is the same as `ether_lazy` with `T::default`, so the value can be reset with `reset_lazy`.
+    pub fn inscribe_lazy_default<T: Default + Clone + 'static>(&mut self) {
+        self.ether_lazy(T::default);
+    }
+
+    /// Summon `T::default()` when `T` cannot be summoned any other way and not every one of `dependencies`
+    /// can be summoned.
+    ///
+    /// Once all of the dependencies are available the default is bypassed in favor of the circles which consume
+    /// them.
+    pub fn inscribe_derived_default<T: Default + 'static>(&mut self, dependencies: &[TypeId]) {
+        self.assert_unpinned(TypeId::of::<T>());
+        self.version += 1;
+        self.forget_cached_id(TypeId::of::<T>());
+        let dependencies = dependencies.to_vec();
+        let circle = Arc::from(self.contextualize(Box::new(DefaultEther::<T>(PhantomData))));
+        self.conditionals
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(ConditionalTransmutation::new(
+                Rc::new(move |tome: &Tome| {
+                    !dependencies
+                        .iter()
+                        .all(|&dependency| tome.research_id(dependency).is_some())
+                }),
+                circle,
+            ));
+    }
+}
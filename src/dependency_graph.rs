@@ -0,0 +1,60 @@
+use crate::Tome;
+use serde::Serialize;
+
+/// The circles of a tome without any of their values, made with `Tome::to_dependency_graph`.
+///
+/// Types are described by their name from `register_type`, or otherwise their `TypeId`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DependencyGraph {
+    /// Every product with a circle, sorted by `TypeId`.
+    pub products: Vec<ProductNode>,
+}
+
+/// A product and the circles which could produce it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ProductNode {
+    /// The product's description.
+    pub product: String,
+    /// How many circles could produce the product.
+    pub circle_count: usize,
+    /// The circles producing this product, in the order they are tried.
+    pub circles: Vec<CircleNode>,
+}
+
+/// A single circle producing a product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CircleNode {
+    /// The circle's name, if it was given one with `Named`.
+    pub name: Option<String>,
+    /// The descriptions of the circle's ingredients, in order.
+    pub ingredients: Vec<String>,
+}
+
+impl Tome {
+    /// Describe every circle in the tome by its product and ingredients, in a form which can be serialized.
+    pub fn to_dependency_graph(&self) -> DependencyGraph {
+        let products = self
+            .inscribed_products()
+            .into_iter()
+            .map(|product| {
+                let circles: Vec<CircleNode> = self
+                    .candidates(product)
+                    .map(|circle| CircleNode {
+                        name: circle.name().map(str::to_owned),
+                        ingredients: circle
+                            .ingredients()
+                            .iter()
+                            .map(|&ingredient| self.type_registry.describe(ingredient))
+                            .collect(),
+                    })
+                    .collect();
+                ProductNode {
+                    product: self.type_registry.describe(product),
+                    circle_count: circles.len(),
+                    circles,
+                }
+            })
+            .collect();
+        DependencyGraph { products }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::Tome;
+use std::any::TypeId;
+
+/// The differences between the circles inscribed in two tomes, produced by [`Tome::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TomeDiff {
+    /// Products which only the second tome can produce.
+    pub added: Vec<TypeId>,
+    /// Products which only the first tome can produce.
+    pub removed: Vec<TypeId>,
+    /// Products which both tomes can produce, but with a different number of circles.
+    pub changed: Vec<TypeId>,
+}
+
+impl TomeDiff {
+    /// Check if the two tomes have the same products with the same number of circles.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Tome {
+    /// Compare the circles inscribed in `a` against those inscribed in `b`.
+    ///
+    /// Circles cannot be compared for equality, so a product is only considered changed
+    /// when the number of circles producing it differs.
+    pub fn diff(a: &Tome, b: &Tome) -> TomeDiff {
+        let before = a.inscribed_products();
+        let after = b.inscribed_products();
+        let mut diff = TomeDiff::default();
+        for &id in &after {
+            if before.binary_search(&id).is_err() {
+                diff.added.push(id);
+            } else if a.candidates(id).count() != b.candidates(id).count() {
+                diff.changed.push(id);
+            }
+        }
+        diff.removed = before
+            .into_iter()
+            .filter(|id| after.binary_search(id).is_err())
+            .collect();
+        diff
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Tome;
+use std::any::TypeId;
+use std::collections::BTreeSet;
+
+impl Tome {
+    /// Describe the dependency graph between types as a Graphviz DOT graph, with an edge from each ingredient to
+    /// the product of every circle consuming it.
+    ///
+    /// Types are labelled with their name from `register_type`, the name of a named circle producing them, or
+    /// otherwise their `TypeId`.
+    pub fn to_dot(&self) -> String {
+        let mut nodes: Vec<TypeId> = vec![];
+        let mut edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+        let mut node = |id: TypeId| match nodes.iter().position(|&node| node == id) {
+            Some(index) => index,
+            None => {
+                nodes.push(id);
+                nodes.len() - 1
+            }
+        };
+        for product in self.inscribed_products() {
+            let to = node(product);
+            for circle in self.candidates(product) {
+                for &ingredient in circle.ingredients() {
+                    edges.insert((node(ingredient), to));
+                }
+            }
+        }
+        let mut dot = String::from("digraph tome {\n");
+        for (index, &id) in nodes.iter().enumerate() {
+            let label = self
+                .type_registry
+                .name_of(id)
+                .or_else(|| self.candidates(id).find_map(|circle| circle.name()))
+                .map(|name| name.replace('"', "\\\""))
+                .unwrap_or_else(|| format!("{:?}", id));
+            dot += &format!("    n{} [label=\"{}\"];\n", index, label);
+        }
+        for (from, to) in edges {
+            dot += &format!("    n{} -> n{};\n", from, to);
+        }
+        dot += "}\n";
+        dot
+    }
+}
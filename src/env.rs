@@ -0,0 +1,115 @@
+use crate::Tome;
+use std::env::{self, VarError};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The reasons an environment variable could not be inscribed as ether.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InscribeEnvError {
+    /// The variable is not set.
+    Missing { key: String },
+    /// The variable is set, but could not be parsed.
+    ParseError { key: String, value: String },
+}
+
+impl fmt::Display for InscribeEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { key } => write!(f, "environment variable {} is not set", key),
+            Self::ParseError { key, value } => write!(
+                f,
+                "environment variable {} could not be parsed from {:?}",
+                key, value
+            ),
+        }
+    }
+}
+
+impl Error for InscribeEnvError {}
+
+fn parse_env<T: FromStr>(key: &str) -> Result<T, InscribeEnvError> {
+    let value = env::var(key).map_err(|e| match e {
+        VarError::NotPresent => InscribeEnvError::Missing {
+            key: key.to_owned(),
+        },
+        VarError::NotUnicode(value) => InscribeEnvError::ParseError {
+            key: key.to_owned(),
+            value: value.to_string_lossy().into_owned(),
+        },
+    })?;
+    value.parse().map_err(|_| InscribeEnvError::ParseError {
+        key: key.to_owned(),
+        value,
+    })
+}
+
+/// Parses the environment variable with the given key and inscribes it as ether, such as
+/// `Tome::inscribe_from_env::<T>`.
+pub type EnvLoader = fn(&mut Tome, &str) -> Result<(), InscribeEnvError>;
+
+impl Tome {
+    /// Parse an environment variable now and create a note about it as ether.
+    pub fn inscribe_from_env<T: FromStr + Clone + 'static>(
+        &mut self,
+        key: &str,
+    ) -> Result<(), InscribeEnvError> {
+        self.ether(parse_env::<T>(key)?);
+        Ok(())
+    }
+
+    /// Parse the environment variable `key` as a `T` and create a note about it as ether.
+    ///
+    /// This is the same as `inscribe_from_env`.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_env<T: FromStr + Clone + 'static>(
+        &mut self,
+        key: &str,
+    ) -> Result<(), InscribeEnvError> {
+        self.inscribe_from_env::<T>(key)
+    }
+
+    /// Parse an environment variable now and create a note about it as ether, using the default if it is not set.
+    ///
+    /// A variable which is set but cannot be parsed is still an error.
+    pub fn inscribe_from_env_or_default<T: FromStr + Default + Clone + 'static>(
+        &mut self,
+        key: &str,
+    ) -> Result<(), InscribeEnvError> {
+        let item = match parse_env::<T>(key) {
+            Err(InscribeEnvError::Missing { .. }) => T::default(),
+            result => result?,
+        };
+        self.ether(item);
+        Ok(())
+    }
+
+    /// Inscribe several environment variables as ether, each with its own loader, stopping at the first error.
+    ///
+    /// ```
+    /// use summon::Tome;
+    /// #[derive(Clone)]
+    /// struct Retries(u32);
+    /// impl std::str::FromStr for Retries {
+    ///     type Err = std::num::ParseIntError;
+    ///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+    ///         s.parse().map(Retries)
+    ///     }
+    /// }
+    /// std::env::set_var("SUMMON_DOC_RETRIES", "3");
+    /// let mut tome = Tome::new();
+    /// tome.load_env(&[
+    ///     ("SUMMON_DOC_RETRIES", Tome::inscribe_from_env::<Retries>),
+    ///     ("SUMMON_DOC_TIMEOUT", Tome::inscribe_from_env_or_default::<u64>),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(3, tome.summon::<Retries>().unwrap().0);
+    /// assert_eq!(0, tome.summon::<u64>().unwrap());
+    /// ```
+    pub fn load_env(&mut self, loaders: &[(&str, EnvLoader)]) -> Result<(), InscribeEnvError> {
+        for &(key, loader) in loaders {
+            loader(self, key)?;
+        }
+        Ok(())
+    }
+}
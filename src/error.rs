@@ -0,0 +1,146 @@
+use crate::{Research, Tome};
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt;
+
+/// Why a product could not be summoned.
+#[derive(Debug)]
+pub enum SummonError {
+    /// No recipe for the target can currently be researched.
+    NoPossibleRecipe {
+        /// The product which was being summoned.
+        target: TypeId,
+    },
+    /// Every recipe for the target depends on itself through these types.
+    CycleDetected {
+        /// The types which depend on each other, each consumed by the circle producing the one before it.
+        participants: Vec<TypeId>,
+    },
+    /// A type needed to summon the target has no circles which produce it.
+    MissingIngredient {
+        /// The product of the circle which needs the missing ingredient.
+        required_by: TypeId,
+        /// The ingredient which cannot be produced.
+        missing: TypeId,
+    },
+    /// Researching the target needed more nested ingredients than the tome's maximum depth allows.
+    DepthExceeded {
+        /// The product which was being summoned.
+        target: TypeId,
+        /// The maximum depth of the tome.
+        limit: usize,
+    },
+    /// A fallible transmutation in the recipe failed.
+    TransmutationFailed {
+        /// The product of the circle which failed.
+        at: TypeId,
+        /// The error the circle failed with.
+        source: Box<dyn Error>,
+    },
+}
+
+impl SummonError {
+    /// Describe the error, using `name` to describe each type.
+    pub(crate) fn write_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        name: impl Fn(TypeId) -> String,
+    ) -> fmt::Result {
+        match self {
+            Self::NoPossibleRecipe { target } => {
+                write!(f, "no recipe can currently produce {}", name(*target))
+            }
+            Self::CycleDetected { participants } => write!(
+                f,
+                "recipes depend on themselves through {}",
+                participants
+                    .iter()
+                    .map(|&ty| name(ty))
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            ),
+            Self::MissingIngredient {
+                required_by,
+                missing,
+            } => write!(
+                f,
+                "{} requires {}, which nothing produces",
+                name(*required_by),
+                name(*missing)
+            ),
+            Self::DepthExceeded { target, limit } => write!(
+                f,
+                "researching {} needs ingredients nested more than {} deep",
+                name(*target),
+                limit
+            ),
+            Self::TransmutationFailed { at, source } => {
+                write!(
+                    f,
+                    "transmutation producing {} failed: {}",
+                    name(*at),
+                    source
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for SummonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with(f, |id| format!("{:?}", id))
+    }
+}
+
+impl Error for SummonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::TransmutationFailed { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
+}
+
+impl Tome {
+    /// Explain why no recipe could be researched for `target`.
+    pub(crate) fn diagnose(&self, target: TypeId) -> SummonError {
+        let mut research = Research::default();
+        if self.research_in(target, &mut research).is_none() && research.depth_exceeded {
+            return SummonError::DepthExceeded {
+                target,
+                limit: self.max_depth(),
+            };
+        }
+        self.diagnose_path(target, &mut vec![])
+            .unwrap_or(SummonError::NoPossibleRecipe { target })
+    }
+
+    /// Follow the first unsummonable ingredient of each circle down from `id`, looking for a missing type or a
+    /// cycle back onto `path`.
+    fn diagnose_path(&self, id: TypeId, path: &mut Vec<TypeId>) -> Option<SummonError> {
+        if let Some(position) = path.iter().position(|&ty| ty == id) {
+            return Some(SummonError::CycleDetected {
+                participants: path[position..].to_vec(),
+            });
+        }
+        path.push(id);
+        let diagnosis = self
+            .candidates(id)
+            .filter(|circle| !circle.expired())
+            .find_map(|circle| {
+                let &ingredient = circle.ingredients().iter().find(|&&ingredient| {
+                    ingredient == id || self.research_id(ingredient).is_none()
+                })?;
+                if ingredient != id && self.candidates(ingredient).next().is_none() {
+                    Some(SummonError::MissingIngredient {
+                        required_by: id,
+                        missing: ingredient,
+                    })
+                } else {
+                    self.diagnose_path(ingredient, path)
+                }
+            });
+        path.pop();
+        diagnosis
+    }
+}
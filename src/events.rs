@@ -0,0 +1,37 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::rc::Rc;
+
+/// Called with the product of a circle and the circle itself.
+type CircleListener = Rc<dyn Fn(TypeId, &dyn Transmutation)>;
+
+/// The callbacks told about circles as they are inscribed and removed, in the order they were registered.
+#[derive(Clone, Default)]
+pub(crate) struct CircleListeners {
+    inscribed: Vec<CircleListener>,
+    removed: Vec<CircleListener>,
+}
+
+impl Tome {
+    /// Call `callback` with every circle inscribed from now on, along with its product.
+    pub fn on_inscribe(&mut self, callback: impl Fn(TypeId, &dyn Transmutation) + 'static) {
+        self.circle_listeners.inscribed.push(Rc::new(callback));
+    }
+
+    /// Call `callback` with every circle removed from now on, along with its product.
+    pub fn on_remove(&mut self, callback: impl Fn(TypeId, &dyn Transmutation) + 'static) {
+        self.circle_listeners.removed.push(Rc::new(callback));
+    }
+
+    pub(crate) fn notify_inscribed(&self, circle: &dyn Transmutation) {
+        for listener in &self.circle_listeners.inscribed {
+            listener(circle.product(), circle);
+        }
+    }
+
+    pub(crate) fn notify_removed(&self, circle: &dyn Transmutation) {
+        for listener in &self.circle_listeners.removed {
+            listener(circle.product(), circle);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+/// A transmutation which expires at a point in time.
+struct TimeLimited<C> {
+    circle: C,
+    valid_until: Instant,
+}
+
+impl<C: Transmutation> Transmutation for TimeLimited<C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.circle.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.circle.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.circle.transmute(inputs)
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.circle.try_transmute(inputs)
+    }
+    fn expired(&self) -> bool {
+        Instant::now() >= self.valid_until || self.circle.expired()
+    }
+    fn name(&self) -> Option<&'static str> {
+        self.circle.name()
+    }
+    fn cost(&self) -> u64 {
+        self.circle.cost()
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        self.circle.ether_value()
+    }
+}
+
+impl Tome {
+    /// Inscribe a circle which is ignored once `valid_until` has passed.
+    pub fn inscribe_time_limited<T: Transmutation + 'static>(
+        &mut self,
+        circle: T,
+        valid_until: Instant,
+    ) {
+        self.inscribe(TimeLimited {
+            circle,
+            valid_until,
+        });
+    }
+
+    /// Inscribe a circle which is ignored once `ttl` has elapsed from now.
+    pub fn inscribe_ttl<T: Transmutation + 'static>(&mut self, circle: T, ttl: Duration) {
+        self.inscribe_time_limited(circle, Instant::now() + ttl);
+    }
+
+    /// Remove every circle which has expired, returning how many were removed.
+    pub fn expire_stale(&mut self) -> usize {
+        let mut expired = vec![];
+        for circles in self.circles.values_mut() {
+            let (stale, fresh): (Vec<_>, Vec<_>) =
+                circles.drain(..).partition(|(_, circle)| circle.expired());
+            *circles = fresh;
+            expired.extend(stale);
+        }
+        if !expired.is_empty() {
+            self.version += 1;
+        }
+        for (_, circle) in &expired {
+            self.forget_cached_id(circle.product());
+            self.notify_removed(&**circle);
+        }
+        expired.len()
+    }
+}
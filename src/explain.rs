@@ -0,0 +1,114 @@
+use crate::Tome;
+use std::any::TypeId;
+use std::fmt;
+use std::time::Instant;
+
+/// One step of an explained recipe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExplainedStep {
+    /// The product of the step's circle.
+    pub product: TypeId,
+    /// The ingredients the step's circle consumes.
+    pub ingredients: Vec<TypeId>,
+    /// The name of the step's circle, if it has one.
+    pub name: Option<&'static str>,
+    /// The name registered for the product with `register_type`, if it has one.
+    pub type_name: Option<String>,
+    /// Whether the step coerces its ingredient with a circle from `register_alias` or `register_coerce`.
+    pub coercion: bool,
+    /// The name of the pair given to `inscribe_with_fallback` which the step's circle belongs to, if any.
+    pub fallback_pair: Option<String>,
+}
+
+/// A description of the recipe which would be used to summon a type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecipeExplanation {
+    /// The steps of the recipe, in the order they would be performed, ending with the summoned type.
+    pub steps: Vec<ExplainedStep>,
+}
+
+/// A transmutation which was performed while summoning with `summon_explain`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepRecord {
+    /// The product of the step's circle.
+    pub product: TypeId,
+    /// The ingredients which were fed into the step's circle.
+    pub ingredients: Vec<TypeId>,
+    /// The name of the step's circle, if it has one.
+    pub name: Option<&'static str>,
+    /// When the step's transmutation finished.
+    pub performed_at: Instant,
+}
+
+/// The transmutations performed while summoning, in the order they were performed.
+pub type ExecutionTrace = Vec<StepRecord>;
+
+impl RecipeExplanation {
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, product: TypeId, depth: usize) -> fmt::Result {
+        let step = self
+            .steps
+            .iter()
+            .find(|step| step.product == product)
+            .expect("explained ingredient has no step");
+        match &step.type_name {
+            Some(type_name) => write!(f, "{:indent$}{}", "", type_name, indent = depth * 2)?,
+            None => write!(f, "{:indent$}{:?}", "", step.product, indent = depth * 2)?,
+        }
+        match step.name {
+            Some(name) => writeln!(f, " ({})", name)?,
+            None => writeln!(f)?,
+        }
+        for &ingredient in &step.ingredients {
+            self.write_tree(f, ingredient, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the recipe as a dependency tree, with each step followed by its ingredients indented below it.
+impl fmt::Display for RecipeExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.steps.last() {
+            Some(root) => self.write_tree(f, root.product, 0),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Tome {
+    /// Describe the recipe which would be used to summon `T`, without performing any of it.
+    pub fn explain<T: 'static>(&self) -> Option<RecipeExplanation> {
+        let recipe = self.research::<T>()?;
+        Some(RecipeExplanation {
+            steps: recipe
+                .steps()
+                .iter()
+                .map(|step| ExplainedStep {
+                    product: step.product(),
+                    ingredients: step.ingredients().to_vec(),
+                    name: step.name(),
+                    type_name: self
+                        .type_registry
+                        .name_of(step.product())
+                        .map(str::to_owned),
+                    coercion: self.is_coercion(step.product(), step.ingredients()),
+                    fallback_pair: self.fallback_pair_name(*step).map(str::to_owned),
+                })
+                .collect(),
+        })
+    }
+
+    /// Summon `T` while recording every transmutation performed, to find out which recipe produced it.
+    ///
+    /// Like `summon`, a recipe whose circle declines or whose product is rejected is replaced with another, and
+    /// only the transmutations of the recipe which produced `T` are recorded.
+    pub fn summon_explain<T: 'static>(&self) -> Option<(T, ExecutionTrace)> {
+        let mut materials = self
+            .perform_retrying(TypeId::of::<T>(), |recipe| {
+                Self::perform_on(self.materials().traced(), recipe)
+            })
+            .ok()?;
+        let trace = materials.trace.take().unwrap_or_default();
+        Some((self.extract(materials), trace))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Tome;
+use std::any::TypeId;
+use std::sync::Arc;
+
+impl Tome {
+    /// Inscribe every circle of `other` into this tome, as if each had been inscribed here.
+    ///
+    /// Any contexts of this tome wrap the merged circles in addition to the contexts of `other`.
+    pub fn extend(&mut self, other: Tome) {
+        self.extend_ref(&other);
+    }
+
+    /// Inscribe every circle of `other` into this tome without consuming it, sharing the circles between both.
+    pub fn extend_ref(&mut self, other: &Tome) {
+        let mut products: Vec<TypeId> = other.circles.keys().copied().collect();
+        // Keep the merged circles in a predictable order for products inscribed in both tomes.
+        products.sort();
+        for product in products {
+            for (priority, circle) in &other.circles[&product] {
+                self.inscribe_box_with_priority(Box::new(Arc::clone(circle)), *priority);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Materials, Named, Tome, Transmutation};
+use std::any::TypeId;
+use std::sync::Arc;
+
+/// Which circle of a fallback chain is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FallbackBranch {
+    /// The primary circle, whose ingredients are available.
+    Primary,
+    /// The fallback circle, since the primary circle's ingredients are not available.
+    Fallback,
+}
+
+/// A primary circle and a fallback circle which is only tried when the primary cannot be.
+#[derive(Clone)]
+pub struct FallbackTransmutation<C1, C2> {
+    primary: C1,
+    fallback: C2,
+    /// The name of the pair, if it was given to `inscribe_with_fallback`.
+    name: Option<String>,
+}
+
+/// A fallback chain after it has been instrumented by the tome's contexts.
+pub(crate) type SharedFallback =
+    FallbackTransmutation<Arc<dyn Transmutation>, Arc<dyn Transmutation>>;
+
+impl<C1: Transmutation, C2: Transmutation> FallbackTransmutation<C1, C2> {
+    /// The branch which would be performed with `materials`, or `None` if neither has its ingredients.
+    pub fn active_branch(&self, materials: &Materials) -> Option<FallbackBranch> {
+        let available = |circle: &dyn Transmutation| {
+            !circle.expired()
+                && circle
+                    .ingredients()
+                    .iter()
+                    .all(|&ingredient| materials.get_id(ingredient).is_some())
+        };
+        if available(&self.primary) {
+            Some(FallbackBranch::Primary)
+        } else if available(&self.fallback) {
+            Some(FallbackBranch::Fallback)
+        } else {
+            None
+        }
+    }
+}
+
+impl SharedFallback {
+    /// The primary and fallback circles.
+    pub(crate) fn circles(&self) -> [Arc<dyn Transmutation>; 2] {
+        [self.primary.clone(), self.fallback.clone()]
+    }
+
+    /// Replace both circles with what `wrap` makes of them.
+    pub(crate) fn instrument(
+        &mut self,
+        wrap: impl Fn(&Arc<dyn Transmutation>) -> Arc<dyn Transmutation>,
+    ) {
+        self.primary = wrap(&self.primary);
+        self.fallback = wrap(&self.fallback);
+    }
+}
+
+impl Tome {
+    /// Inscribe a circle producing `T` which is always tried before `fallback`, regardless of how many ingredients
+    /// either consumes.
+    ///
+    /// The chain is tried before any other circle producing `T` and `fallback` is only used when the ingredients of
+    /// `primary` cannot be summoned.
+    pub fn inscribe_fallback_chain<T: 'static, C1, C2>(&mut self, primary: C1, fallback: C2)
+    where
+        C1: Transmutation + 'static,
+        C2: Transmutation + 'static,
+    {
+        let product = TypeId::of::<T>();
+        assert!(
+            primary.product() == product && fallback.product() == product,
+            "fallback chain circles must produce the given type"
+        );
+        self.push_fallback_chain(Box::new(primary), Box::new(fallback), None);
+    }
+
+    /// Inscribe `primary` and `fallback` as a pair, where `primary` is always tried first and `fallback` is only
+    /// used when the ingredients of `primary` cannot be summoned.
+    ///
+    /// Unnamed circles are named `"primary"` and `"fallback"`, and the pair is named after both of them, such as
+    /// `"primary or fallback"`, so `explain` shows which of the pair was chosen.
+    pub fn inscribe_with_fallback<C1, C2>(&mut self, primary: C1, fallback: C2)
+    where
+        C1: Transmutation + 'static,
+        C2: Transmutation + 'static,
+    {
+        assert!(
+            primary.product() == fallback.product(),
+            "fallback circle must produce the same type as the primary circle"
+        );
+        let primary_name = primary.name().unwrap_or("primary");
+        let fallback_name = fallback.name().unwrap_or("fallback");
+        self.push_fallback_chain(
+            Box::new(Named(primary, primary_name)),
+            Box::new(Named(fallback, fallback_name)),
+            Some(format!("{} or {}", primary_name, fallback_name)),
+        );
+    }
+
+    fn push_fallback_chain(
+        &mut self,
+        primary: Box<dyn Transmutation>,
+        fallback: Box<dyn Transmutation>,
+        name: Option<String>,
+    ) {
+        let product = primary.product();
+        self.assert_unpinned(product);
+        self.version += 1;
+        self.forget_cached_id(product);
+        let chain = FallbackTransmutation {
+            primary: Arc::from(self.contextualize(primary)),
+            fallback: Arc::from(self.contextualize(fallback)),
+            name,
+        };
+        self.fallback_chains.entry(product).or_default().push(chain);
+    }
+
+    /// The name of the pair given to `inscribe_with_fallback` which `circle` belongs to, if any.
+    pub(crate) fn fallback_pair_name(&self, circle: &dyn Transmutation) -> Option<&str> {
+        self.fallback_chains
+            .get(&circle.product())?
+            .iter()
+            .find(|chain| {
+                std::ptr::addr_eq(&*chain.primary, circle)
+                    || std::ptr::addr_eq(&*chain.fallback, circle)
+            })?
+            .name
+            .as_deref()
+    }
+
+    /// The circles of every fallback chain producing `id`, each primary followed by its fallback.
+    pub(crate) fn fallback_candidates(
+        &self,
+        id: TypeId,
+    ) -> impl Iterator<Item = &dyn Transmutation> + '_ {
+        self.fallback_chains
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .flat_map(|chain| [&*chain.primary, &*chain.fallback])
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::error::Error;
+
+/// A transmutation which can fail, such as one which performs IO or parsing.
+pub trait FallibleTransmutation {
+    fn ingredients(&self) -> &'static [TypeId];
+    fn product(&self) -> TypeId;
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>>;
+}
+
+/// Adapts a fallible transmutation so it can be inscribed, propagating its failures out of `summon`.
+pub struct Fallible<C>(pub C);
+
+impl<C: FallibleTransmutation> Transmutation for Fallible<C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.0.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.0
+            .try_transmute(inputs)
+            .unwrap_or_else(|error| panic!("transmutation failed: {}", error))
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.0.try_transmute(inputs)
+    }
+}
+
+impl Tome {
+    /// Inscribe a note about a transmutation which can fail into the tome.
+    pub fn inscribe_fallible<C: FallibleTransmutation + 'static>(&mut self, circle: C) {
+        self.inscribe(Fallible(circle));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Tome, Transmutation};
+use notify::{RecursiveMode, Watcher};
+use serde::de::DeserializeOwned;
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+/// The reasons a file could not be inscribed as ether.
+#[derive(Debug)]
+pub enum FileEtherError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file could not be deserialized.
+    Parse(serde_json::Error),
+    /// The file could not be watched for changes.
+    Watch(notify::Error),
+}
+
+impl fmt::Display for FileEtherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read ether file: {}", e),
+            Self::Parse(e) => write!(f, "failed to parse ether file: {}", e),
+            Self::Watch(e) => write!(f, "failed to watch ether file: {}", e),
+        }
+    }
+}
+
+impl Error for FileEtherError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::Watch(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for FileEtherError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for FileEtherError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<notify::Error> for FileEtherError {
+    fn from(e: notify::Error) -> Self {
+        Self::Watch(e)
+    }
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, FileEtherError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+}
+
+/// Ether whose value is replaced whenever its file changes.
+struct WatchedEther<T>(Arc<RwLock<T>>);
+
+impl<T: Clone + 'static> Transmutation for WatchedEther<T> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(self.0.read().unwrap().clone())
+    }
+}
+
+impl Tome {
+    /// Read a JSON file now and create a note about its contents as ether.
+    pub fn inscribe_from_file<T: DeserializeOwned + Clone + 'static>(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), FileEtherError> {
+        self.ether(read_json::<T>(path.as_ref())?);
+        Ok(())
+    }
+
+    /// Read a JSON file now and create a note about its contents as ether, reading it again whenever it changes.
+    ///
+    /// If the file stops parsing after a change, the last value which parsed is kept.
+    pub fn inscribe_from_file_watched<T: DeserializeOwned + Clone + Send + Sync + 'static>(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), FileEtherError> {
+        let path: PathBuf = path.as_ref().to_owned();
+        let value = Arc::new(RwLock::new(read_json::<T>(&path)?));
+        let watched_value = value.clone();
+        let watched_path = path.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                if event.is_ok_and(|event| event.kind.is_modify() || event.kind.is_create()) {
+                    if let Ok(new_value) = read_json::<T>(&watched_path) {
+                        *watched_value.write().unwrap() = new_value;
+                    }
+                }
+            })?;
+        watcher.watch(&path, RecursiveMode::NonRecursive)?;
+        self.watchers.push(Rc::new(watcher));
+        self.inscribe(WatchedEther(value));
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{Tome, Transmutation};
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct Generator<T, F> {
+    state: Arc<Mutex<T>>,
+    step: F,
+}
+
+impl<T: Clone + 'static, F: Fn(&T) -> T> Transmutation for Generator<T, F> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        let mut state = self.state.lock().unwrap();
+        let next = (self.step)(&state);
+        Box::new(std::mem::replace(&mut *state, next))
+    }
+}
+
+impl Tome {
+    /// Produce `init` on the first summon, then `step` of the previous value on every summon after that.
+    pub fn inscribe_generator<T: Clone + 'static>(
+        &mut self,
+        init: T,
+        step: impl Fn(&T) -> T + 'static,
+    ) {
+        let state = Arc::new(Mutex::new(init.clone()));
+        let reset_state = state.clone();
+        self.generators.insert(
+            TypeId::of::<T>(),
+            Rc::new(move || *reset_state.lock().unwrap() = init.clone()),
+        );
+        self.inscribe(Generator { state, step });
+    }
+
+    /// Start the generator for `T` over from its initial value.
+    ///
+    /// Returns `false` if there is no generator for `T`.
+    pub fn reset_generator<T: 'static>(&self) -> bool {
+        self.generators
+            .get(&TypeId::of::<T>())
+            .map(|reset| reset())
+            .is_some()
+    }
+}
@@ -0,0 +1,36 @@
+use crate::Tome;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::any::TypeId;
+use std::collections::HashMap;
+
+impl Tome {
+    /// The dependency graph between types, with an edge from each ingredient to the product of every circle
+    /// consuming it.
+    pub fn type_dependencies_as_petgraph(&self) -> DiGraph<TypeId, ()> {
+        let mut graph = DiGraph::new();
+        let mut nodes: HashMap<TypeId, NodeIndex> = HashMap::new();
+        let mut node = |graph: &mut DiGraph<TypeId, ()>, id: TypeId| {
+            *nodes.entry(id).or_insert_with(|| graph.add_node(id))
+        };
+        for product in self.inscribed_products() {
+            let to = node(&mut graph, product);
+            for circle in self.candidates(product) {
+                for &ingredient in circle.ingredients() {
+                    let from = node(&mut graph, ingredient);
+                    graph.update_edge(from, to, ());
+                }
+            }
+        }
+        graph
+    }
+
+    /// The strongly connected components of the dependency graph, where every component with more than one type
+    /// is a cycle.
+    pub fn type_scc(&self) -> Vec<Vec<TypeId>> {
+        let graph = self.type_dependencies_as_petgraph();
+        petgraph::algo::kosaraju_scc(&graph)
+            .into_iter()
+            .map(|component| component.into_iter().map(|node| graph[node]).collect())
+            .collect()
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{circle_address, Tome, Transmutation};
+use std::any::TypeId;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A set of circles which are inscribed together.
+pub trait TransmutationGroup {
+    /// The circles in the group.
+    fn circles() -> Vec<Box<dyn Transmutation>>;
+}
+
+/// A circle which can only be used while a predicate on the tome holds.
+#[derive(Clone)]
+pub(crate) struct ConditionalTransmutation {
+    predicate: Rc<dyn Fn(&Tome) -> bool>,
+    circle: Arc<dyn Transmutation>,
+}
+
+impl ConditionalTransmutation {
+    pub(crate) fn new(
+        predicate: Rc<dyn Fn(&Tome) -> bool>,
+        circle: Arc<dyn Transmutation>,
+    ) -> Self {
+        Self { predicate, circle }
+    }
+
+    /// The circle, whether or not it is active.
+    pub(crate) fn circle(&self) -> Arc<dyn Transmutation> {
+        self.circle.clone()
+    }
+
+    /// Replace the circle with what `wrap` makes of it, keeping the predicate.
+    pub(crate) fn instrument(
+        &mut self,
+        wrap: impl FnOnce(&Arc<dyn Transmutation>) -> Arc<dyn Transmutation>,
+    ) {
+        self.circle = wrap(&self.circle);
+    }
+
+    /// The circle, if it is currently active in `tome`.
+    pub(crate) fn active(&self, tome: &Tome) -> Option<&dyn Transmutation> {
+        if (self.predicate)(tome) {
+            Some(&*self.circle)
+        } else {
+            None
+        }
+    }
+}
+
+impl Tome {
+    /// Inscribe every circle in the group `G`.
+    pub fn inscribe_group<G: TransmutationGroup>(&mut self) {
+        for circle in G::circles() {
+            self.inscribe_box(circle);
+        }
+    }
+
+    /// Inscribe every one of `circles` as part of the group called `name`, so they can all be removed together
+    /// with `remove_group`.
+    ///
+    /// Inscribing into a group which already exists adds to it.
+    pub fn inscribe_named_group(
+        &mut self,
+        name: impl Into<String>,
+        circles: impl IntoIterator<Item = Box<dyn Transmutation>>,
+    ) {
+        let circles: Vec<Arc<dyn Transmutation>> = circles
+            .into_iter()
+            .map(|circle| Arc::from(self.contextualize(circle)))
+            .collect();
+        for circle in &circles {
+            self.push_circle(circle.clone(), 0);
+        }
+        self.named_groups
+            .entry(name.into())
+            .or_default()
+            .extend(circles);
+    }
+
+    /// Unregister every circle inscribed as part of the group called `name`, returning them in the order they
+    /// were inscribed.
+    ///
+    /// Any manual order given to the products of the circles is forgotten.
+    pub fn remove_group(&mut self, name: &str) -> Vec<Arc<dyn Transmutation>> {
+        let removed = self.named_groups.remove(name).unwrap_or_default();
+        for circle in &removed {
+            let product = circle.product();
+            if let Some(circles) = self.circles.get_mut(&product) {
+                let before = circles.len();
+                circles.retain(|(_, existing)| {
+                    circle_address(&**existing) != circle_address(&**circle)
+                });
+                let unregistered = circles.len() != before;
+                if circles.is_empty() {
+                    self.circles.remove(&product);
+                }
+                if unregistered {
+                    self.notify_removed(&**circle);
+                }
+            }
+            self.manually_ordered.remove(&product);
+            self.forget_cached_id(product);
+        }
+        if !removed.is_empty() {
+            self.version += 1;
+        }
+        removed
+    }
+
+    /// Inscribe every circle in the group `G`, which can only be used while `predicate` returns `true`.
+    ///
+    /// The predicate is checked every time the circles are researched, so the whole group appears and disappears
+    /// together as the tome changes.
+    pub fn inscribe_conditional_group<G: TransmutationGroup>(
+        &mut self,
+        predicate: impl Fn(&Tome) -> bool + 'static,
+    ) {
+        self.version += 1;
+        let predicate: Rc<dyn Fn(&Tome) -> bool> = Rc::new(predicate);
+        for circle in G::circles() {
+            self.assert_unpinned(circle.product());
+            let circle: Arc<dyn Transmutation> = Arc::from(self.contextualize(circle));
+            self.forget_cached_id(circle.product());
+            self.conditionals
+                .entry(circle.product())
+                .or_default()
+                .push(ConditionalTransmutation::new(predicate.clone(), circle));
+        }
+    }
+
+    /// The conditional circles producing `id` which are currently active.
+    pub(crate) fn conditional_candidates(
+        &self,
+        id: TypeId,
+    ) -> impl Iterator<Item = &dyn Transmutation> + '_ {
+        self.conditionals
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(move |conditional| conditional.active(self))
+    }
+}
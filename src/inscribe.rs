@@ -0,0 +1,78 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt;
+
+/// The reasons a circle could not be inscribed with `try_inscribe`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InscribeError {
+    /// Another circle which requires ingredients already produces the product.
+    AlreadyInscribed { product: TypeId },
+}
+
+impl fmt::Display for InscribeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyInscribed { product } => {
+                write!(f, "a circle producing {:?} is already inscribed", product)
+            }
+        }
+    }
+}
+
+impl Error for InscribeError {}
+
+impl Tome {
+    /// Inscribe a circle unless another circle already produces its product, leaving the tome unchanged if so.
+    ///
+    /// Ether values, and other circles which require no ingredients, may always coexist with the circle.
+    pub fn try_inscribe<T: Transmutation + 'static>(
+        &mut self,
+        circle: T,
+    ) -> Result<(), InscribeError> {
+        let product = circle.product();
+        if self
+            .candidates(product)
+            .any(|existing| !existing.ingredients().is_empty())
+        {
+            return Err(InscribeError::AlreadyInscribed { product });
+        }
+        self.inscribe(circle);
+        Ok(())
+    }
+
+    /// Inscribe a circle, panicking if another circle already produces its product.
+    ///
+    /// Like `try_inscribe`, ether values and other circles which require no ingredients are not counted.
+    pub fn inscribe_once<T: Transmutation + 'static>(&mut self, circle: T) {
+        let product = circle.product();
+        if let Some(existing) = self
+            .candidates(product)
+            .find(|existing| !existing.ingredients().is_empty())
+        {
+            match existing.name() {
+                Some(name) => panic!(
+                    "a circle producing {:?} is already inscribed (named \"{}\")",
+                    product, name
+                ),
+                None => panic!("a circle producing {:?} is already inscribed", product),
+            }
+        }
+        self.inscribe(circle);
+    }
+
+    /// Inscribe a circle only if `condition` holds for the tome as it is now, returning whether it was inscribed.
+    ///
+    /// The condition is checked once, here, and never again when summoning.
+    pub fn inscribe_if<T: Transmutation + 'static>(
+        &mut self,
+        condition: impl Fn(&Tome) -> bool,
+        circle: T,
+    ) -> bool {
+        let inscribed = condition(self);
+        if inscribed {
+            self.inscribe(circle);
+        }
+        inscribed
+    }
+}
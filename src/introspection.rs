@@ -0,0 +1,34 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+
+impl Tome {
+    /// Every product which has a circle of any kind inscribed for it, sorted by `TypeId`.
+    pub fn registered_products(&self) -> Vec<TypeId> {
+        self.inscribed_products()
+    }
+
+    /// All of the circles which could produce `T`, in the order they are tried.
+    pub fn circles_for<T: 'static>(&self) -> Vec<&dyn Transmutation> {
+        self.circles_for_id(TypeId::of::<T>())
+    }
+
+    /// All of the circles which could produce the product with the given `TypeId`, in the order they are tried.
+    ///
+    /// Along with `registered_products`, this is enough to walk the whole tome from outside the crate.
+    pub fn circles_for_id(&self, product: TypeId) -> Vec<&dyn Transmutation> {
+        self.candidates(product).collect()
+    }
+
+    /// Every product which could be summoned right now from the ethers in the tome, sorted by `TypeId`.
+    pub fn all_reachable_from_ether(&self) -> Vec<TypeId> {
+        self.inscribed_products()
+            .into_iter()
+            .filter(|&product| self.research_id(product).is_some())
+            .collect()
+    }
+
+    /// Whether `T` could be summoned right now, without summoning it.
+    pub fn can_summon<T: 'static>(&self) -> bool {
+        self.research_id(TypeId::of::<T>()).is_some()
+    }
+}
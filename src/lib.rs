@@ -3,7 +3,6 @@
 //! A logic engine designed to magically give you what you ask for
 //!
 //! ```
-//! # #![feature(const_type_id)]
 //! use summon::{Tome, circle};
 //!
 //! #[derive(Clone)]
@@ -43,16 +42,219 @@
 //! ```
 
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, Once, OnceLock};
+use std::time::Instant;
+
+mod accumulator;
+mod alias;
+mod all;
+mod analysis;
+mod arc_fn;
+#[cfg(feature = "async")]
+mod asynchronous;
+#[cfg(feature = "async")]
+mod backpressure;
+mod builder;
+mod cache;
+mod caching_tome;
+mod cloned;
+mod collection;
+mod compiled;
+mod compose;
+mod conflict;
+mod context;
+mod cost;
+mod cow;
+mod debug;
+mod decline;
+mod default;
+#[cfg(feature = "serde")]
+mod dependency_graph;
+mod diff;
+mod dot;
+#[cfg(feature = "env")]
+mod env;
+mod error;
+mod events;
+mod expiry;
+mod explain;
+mod extend;
+mod fallback;
+mod fallible;
+#[cfg(feature = "file-ether")]
+mod file;
+mod generator;
+#[cfg(feature = "petgraph")]
+mod graph;
+mod group;
+mod inscribe;
+mod introspection;
+mod logging_tome;
+mod many;
+mod memo;
+mod metrics;
+mod named;
+mod observe;
+mod optional;
+mod ordering;
+mod overlay;
+mod overrides;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "serde")]
+mod persist;
+mod phantom;
+mod pin;
+mod pipeline;
+mod plan;
+mod precomputed;
+mod prescribe;
+#[cfg(feature = "profiling")]
+mod profiling;
+#[cfg(feature = "rand")]
+mod random;
+mod reference;
+mod remove;
+mod schema;
+mod sealed;
+#[cfg(feature = "shared-mut")]
+mod shared;
+mod shared_tome;
+mod snapshot;
+mod split;
+mod strategy;
+#[cfg(feature = "subscriptions")]
+mod subscription;
+mod summon_builder;
+mod summonable;
+#[cfg(feature = "testing")]
+mod testing;
+mod tome_builder;
+mod trace;
+mod trait_object;
+mod type_map;
+mod type_registry;
+
+pub use analysis::{TypeMismatch, ValidationError};
+#[cfg(feature = "async")]
+pub use asynchronous::AsyncTransmutation;
+pub use builder::TransmutationBuilder;
+pub use caching_tome::{CachingTome, ThreadSafeCachingTome};
+pub use compiled::CompiledTome;
+pub use conflict::{AmbiguityReport, CircleInfo, ConflictReport, SelectionReason};
+pub use context::{AndThen, Context, LoggingContext, MetricsContext, TracingContext, TracingSpan};
+pub use cost::Costed;
+pub use decline::{Declinable, TryTransmutation};
+#[cfg(feature = "serde")]
+pub use dependency_graph::{CircleNode, DependencyGraph, ProductNode};
+pub use diff::TomeDiff;
+#[cfg(feature = "env")]
+pub use env::{EnvLoader, InscribeEnvError};
+pub use error::SummonError;
+pub use explain::{ExecutionTrace, ExplainedStep, RecipeExplanation, StepRecord};
+pub use fallback::{FallbackBranch, FallbackTransmutation};
+pub use fallible::{Fallible, FallibleTransmutation};
+#[cfg(feature = "file-ether")]
+pub use file::FileEtherError;
+pub use group::TransmutationGroup;
+pub use inscribe::InscribeError;
+pub use logging_tome::LoggingTome;
+pub use many::SummonMany;
+pub use named::Named;
+pub use ordering::OrderingConflict;
+pub use overlay::{FallbackTome, ScopedTome, TomeWithEther};
+#[cfg(feature = "serde")]
+pub use persist::{LoadError, SaveError};
+pub use pipeline::{PipelineBuilder, PipelineTypes};
+pub use plan::{PlanMode, RecipePlan, SummonPlan};
+#[cfg(feature = "profiling")]
+pub use profiling::ColdStartProfile;
+pub use sealed::SealedTome;
+pub use shared_tome::SharedTome;
+pub use snapshot::TomeSnapshot;
+pub use split::SplitProduct;
+pub use strategy::{InscriberStrategy, MaxIngredients, MinIngredients, PriorityFirst};
+#[cfg(feature = "subscriptions")]
+pub use subscription::SubscriptionHandle;
+pub use summon_builder::SummonBuilder;
+pub use summon_macros::{debug_circle, inscribe, Summonable};
+pub use summonable::Summonable;
+pub use tome_builder::TomeBuilder;
+pub use trace::{CircleOutcome, SkipReason, SummonTrace, TracedCircle};
+pub use type_map::TypeMap;
+pub use type_registry::TypeRegistry;
 
 /// Transmutations require ingredients and produce a product. This is usually a function.
 pub trait Transmutation {
     fn ingredients(&self) -> &'static [TypeId];
     fn product(&self) -> TypeId;
     fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any>;
+    /// Perform the transmutation, reporting a failure instead of panicking.
+    ///
+    /// Transmutations which cannot fail can rely on the default, which calls `transmute`.
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        Ok(self.transmute(inputs))
+    }
+    /// An expired transmutation can never be performed again, so it is skipped during research.
+    fn expired(&self) -> bool {
+        false
+    }
+    /// A label describing the transmutation for debugging, if it was given one with `Named`.
+    fn name(&self) -> Option<&'static str> {
+        None
+    }
+    /// How expensive the transmutation is to perform, which is used to choose between recipes.
+    ///
+    /// The cheapest recipe is the one whose steps have the lowest total cost, so by default it is the one with
+    /// the fewest steps.
+    fn cost(&self) -> u64 {
+        1
+    }
+    /// The value the transmutation always produces, if it is an ether value inscribed with `Tome::ether`.
+    fn ether_value(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+macro_rules! forward_transmutation_impl {
+    ($pointer:ident) => {
+        impl<T: Transmutation + ?Sized> Transmutation for $pointer<T> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                (**self).ingredients()
+            }
+            fn product(&self) -> TypeId {
+                (**self).product()
+            }
+            fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                (**self).transmute(inputs)
+            }
+            fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+                (**self).try_transmute(inputs)
+            }
+            fn expired(&self) -> bool {
+                (**self).expired()
+            }
+            fn name(&self) -> Option<&'static str> {
+                (**self).name()
+            }
+            fn cost(&self) -> u64 {
+                (**self).cost()
+            }
+            fn ether_value(&self) -> Option<&dyn Any> {
+                (**self).ether_value()
+            }
+        }
+    };
 }
 
+forward_transmutation_impl!(Box);
+forward_transmutation_impl!(Arc);
+
 struct Ether<T>(T);
 
 impl<T: Clone + 'static> Transmutation for Ether<T> {
@@ -65,46 +267,166 @@ impl<T: Clone + 'static> Transmutation for Ether<T> {
     fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
         Box::new(self.0.clone())
     }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        Some(&self.0)
+    }
+}
+
+struct Identity<T>(PhantomData<fn() -> T>);
+
+impl<T: 'static> Identity<T> {
+    const INGREDIENTS: &'static [TypeId] = &[TypeId::of::<T>()];
+}
+
+impl<T: Clone + 'static> Transmutation for Identity<T> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        Self::INGREDIENTS
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(
+            inputs[0]
+                .downcast_ref::<T>()
+                .expect("transmute passed an incorrect type")
+                .clone(),
+        )
+    }
+}
+
+struct Conversion<I, O, F>(F, PhantomData<fn(&I) -> O>);
+
+impl<I: 'static, O, F> Conversion<I, O, F> {
+    const INGREDIENTS: &'static [TypeId] = &[TypeId::of::<I>()];
+}
+
+impl<I: 'static, O: 'static, F: Fn(&I) -> O> Transmutation for Conversion<I, O, F> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        Self::INGREDIENTS
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<O>()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new((self.0)(
+            inputs[0]
+                .downcast_ref::<I>()
+                .expect("transmute passed an incorrect type"),
+        ))
+    }
+}
+
+/// Produces the `&'static [TypeId]` of the given ingredient types, computed at compile time.
+#[cfg(not(feature = "stable"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! ingredient_ids {
+    ($($arg_ty:ty),*) => {{
+        const INGREDIENTS: &[std::any::TypeId] = &[$(std::any::TypeId::of::<$arg_ty>()),*];
+        INGREDIENTS
+    }};
+}
+
+/// Produces the `&'static [TypeId]` of the given ingredient types, computed once on first use so that it does not
+/// rely on `TypeId::of` being usable in a `const`.
+#[cfg(feature = "stable")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! ingredient_ids {
+    ($($arg_ty:ty),*) => {{
+        static INGREDIENTS: std::sync::OnceLock<Vec<std::any::TypeId>> = std::sync::OnceLock::new();
+        INGREDIENTS
+            .get_or_init(|| vec![$(std::any::TypeId::of::<$arg_ty>()),*])
+            .as_slice()
+    }};
+}
+
+/// Fails to compile if the product is also one of the ingredients, naming the type in the conflicting
+/// implementations of `CircularTransmutation`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! assert_not_circular {
+    ($return_ty:ty; $($arg_ty:ty),*) => {
+        $({
+            #[allow(dead_code)]
+            trait CircularTransmutation {}
+            impl CircularTransmutation for $arg_ty {}
+            impl CircularTransmutation for $return_ty {}
+        })*
+    };
 }
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! transmutation_impl {
     (($($arg_real_pat:pat in $arg_ty:tt),*) -> $return_ty:tt $body:tt) => {{
-        paste::expr! {{
-            use std::any::{Any, TypeId};
-            struct Temporary<F>(F);
-            const TEMPORARY_INGREDIENTS: &[TypeId] = &[$(TypeId::of::<$arg_ty>()),*];
-            impl<F: Fn($(&$arg_ty),*) -> $return_ty> $crate::Transmutation for Temporary<F> {
-                fn ingredients(&self) -> &'static [TypeId] {
-                    TEMPORARY_INGREDIENTS
-                }
-                fn product(&self) -> TypeId {
-                    TypeId::of::<$return_ty>()
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        struct Temporary<F>(F);
+        impl<F: Fn($(&$arg_ty),*) -> $return_ty> $crate::Transmutation for Temporary<F> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                if inputs.len() == self.ingredients().len() {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut inputs = inputs.iter();
+                    Box::new((self.0)($(inputs.next().unwrap().downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type")),*)) as Box<dyn Any>
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
                 }
-                fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
-                    if let [$([<temp_ident_ $arg_ty>]),*] = inputs {
-                        $(let [<temp_ident_ $arg_ty>] = [<temp_ident_ $arg_ty>].downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type");)*
-                        Box::new((self.0)($([<temp_ident_ $arg_ty>]),*)) as Box<dyn Any>
-                    } else {
-                        panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
+            }
+        }
+        Temporary(|$($arg_real_pat: &$arg_ty),*| -> $return_ty $body)
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! fallible_transmutation_impl {
+    (($($arg_real_pat:pat in $arg_ty:tt),*) -> Result<$return_ty:tt, $error_ty:ty> $body:tt) => {{
+        $crate::assert_not_circular!($return_ty; $($arg_ty),*);
+        use std::any::{Any, TypeId};
+        struct Temporary<F>(F);
+        impl<F: Fn($(&$arg_ty),*) -> Result<$return_ty, $error_ty>> $crate::FallibleTransmutation for Temporary<F> {
+            fn ingredients(&self) -> &'static [TypeId] {
+                $crate::ingredient_ids!($($arg_ty),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<$return_ty>()
+            }
+            fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn std::error::Error>> {
+                if inputs.len() == self.ingredients().len() {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut inputs = inputs.iter();
+                    match (self.0)($(inputs.next().unwrap().downcast_ref::<$arg_ty>().expect("transmute passed an incorrect type")),*) {
+                        Ok(product) => Ok(Box::new(product) as Box<dyn Any>),
+                        Err(error) => Err(error.into()),
                     }
+                } else {
+                    panic!("transmute passed incorrect number of arguments (expected: {}, found: {})", self.ingredients().len(), inputs.len());
                 }
             }
-            Temporary(|$($arg_real_pat: &$arg_ty),*| -> $return_ty $body)
-        }}
+        }
+        $crate::Fallible(Temporary(|$($arg_real_pat: &$arg_ty),*| -> Result<$return_ty, $error_ty> $body))
     }};
 }
 
 /// Use this to inscribe a transmutation between a set of input types and an output type.
 ///
+/// Every form expands its ingredients with a single repetition rather than recursion, so the number of
+/// ingredients is not limited by the macro recursion limit.
+///
 /// ## Pattern form
 ///
 /// This form allows seamless destructuring of input types and and type constructing the output.
 /// This is the prefered form, as it avoids the need to repeat yourself.
 ///
 /// ```
-/// # #![feature(const_type_id)]
 /// use summon::{Tome, circle};
 /// #[derive(Clone)]
 /// struct Normal(u32);
@@ -124,7 +446,6 @@ macro_rules! transmutation_impl {
 /// This form leads to some repitition, but it is currently the only way to combine tags with other data due to macro limitations.
 ///
 /// ```
-/// # #![feature(const_type_id)]
 /// use summon::{Tome, circle};
 /// #[derive(Clone)]
 /// struct Normal(u32);
@@ -138,13 +459,77 @@ macro_rules! transmutation_impl {
 /// assert_eq!(2, tome.summon::<Half>().unwrap().0);
 /// ```
 ///
+/// ## Destructuring closure form
+///
+/// The closure form can also destructure every parameter with a pattern, which avoids accessing fields.
+///
+/// ```
+/// use summon::{Tome, circle};
+/// #[derive(Clone)]
+/// struct Speed(f64);
+/// #[derive(Clone)]
+/// struct Time(f64);
+/// struct Distance(f64);
+/// let mut tome = Tome::new();
+/// tome.ether(Speed(3.0));
+/// tome.ether(Time(4.0));
+/// tome.inscribe(circle!(|Speed(s): &Speed, Time(t): &Time| -> Distance { Distance(s * t) }));
+/// assert_eq!(12.0, tome.summon::<Distance>().unwrap().0);
+/// ```
+///
+/// ## Fallible closure form
+///
+/// A closure form which returns a `Result` produces a circle which can fail. The error must convert into
+/// `Box<dyn Error>`, and a failure is reported by `summon`.
+///
+/// ```
+/// use summon::{Tome, circle};
+/// #[derive(Clone)]
+/// struct Text(&'static str);
+/// struct Number(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(Text("4"));
+/// tome.inscribe(circle!(|t: &Text| -> Result<Number, std::num::ParseIntError> { t.0.parse().map(Number) }));
+/// assert_eq!(4, tome.summon::<Number>().unwrap().0);
+/// ```
+///
+/// ## Named form
+///
+/// Any of the forms can be given a name, which is used when describing recipes.
+///
+/// ```
+/// use summon::{Tome, circle};
+/// #[derive(Clone)]
+/// struct A;
+/// struct B;
+/// let mut tome = Tome::new();
+/// tome.ether(A);
+/// tome.inscribe(circle!(name = "a to b", A => B));
+/// assert_eq!(Some("a to b"), tome.explain::<B>().unwrap().steps[1].name);
+/// ```
+///
+/// ## Cost form
+///
+/// Any of the forms can be given a cost, and the recipe whose circles have the lowest total cost is chosen.
+///
+/// ```
+/// use summon::{Tome, circle};
+/// #[derive(Clone)]
+/// struct A(u32);
+/// struct B(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(A(2));
+/// tome.inscribe(circle!(cost = 100, A(a) => B(a * 10)));
+/// tome.inscribe(circle!(A(a) => B(*a)));
+/// assert_eq!(2, tome.summon::<B>().unwrap().0);
+/// ```
+///
 /// ## Tag form
 ///
 /// This form is useful when you have some logic you want to perform. Multiple ways to produce an output is equivalent to OR.
 /// Multiple arguments to produce a single output is equivalent to an AND. There is currently no NOT or XOR equivalent (coming soon).
 ///
 /// ```
-/// # #![feature(const_type_id)]
 /// use summon::{Tome, circle};
 /// #[derive(Clone)]
 /// struct A;
@@ -154,8 +539,44 @@ macro_rules! transmutation_impl {
 /// tome.inscribe(circle!(A => B));
 /// tome.summon::<B>().unwrap();
 /// ```
+///
+/// A block can follow the product to compute it, while the ingredients still only need to exist.
+///
+/// ```
+/// use summon::{Tome, circle};
+/// #[derive(Clone)]
+/// struct A;
+/// #[derive(Clone)]
+/// struct B;
+/// struct Epsilon(f64);
+/// let mut tome = Tome::new();
+/// tome.ether(A);
+/// tome.ether(B);
+/// tome.inscribe(circle!(A, B => Epsilon { Epsilon(1e-12) }));
+/// assert_eq!(1e-12, tome.summon::<Epsilon>().unwrap().0);
+/// ```
+///
+/// ## Circular circles
+///
+/// A circle can never consume its own product, so any form doing so fails to compile with conflicting
+/// implementations of `CircularTransmutation` for the offending type.
+///
+/// ```compile_fail
+/// use summon::circle;
+/// #[derive(Clone)]
+/// struct Distance(f64);
+/// circle!(|a: &Distance| -> Distance { Distance(a.0 + 1.0) });
+/// ```
 #[macro_export]
 macro_rules! circle {
+    // Named form
+    (name = $name:literal, $($circle:tt)*) => {
+        $crate::Named::new($crate::circle!($($circle)*), $name)
+    };
+    // Cost form
+    (cost = $cost:expr, $($circle:tt)*) => {
+        $crate::Costed::new($crate::circle!($($circle)*), $cost)
+    };
     // Tag form
     ($($arg_name:ty),* => $return_ty:tt) => {
         $crate::transmutation_impl!(($(_ in $arg_name),*) -> $return_ty { $return_ty })
@@ -164,79 +585,637 @@ macro_rules! circle {
     ($($arg_name:tt $arg_pat:tt),* => $return_ty:tt $return_pat:tt) => {{
         $crate::transmutation_impl!(($($arg_name $arg_pat in $arg_name),*) -> $return_ty { $return_ty $return_pat })
     }};
+    // Tag form with a body
+    ($($arg_name:ty),* => $return_ty:tt { $($body:tt)* }) => {
+        $crate::transmutation_impl!(($(_ in $arg_name),*) -> $return_ty { $($body)* })
+    };
+    // Fallible closure form
+    (|$($arg_name:tt: &$arg_ty:ty),*| -> Result<$return_ty:tt, $error_ty:ty> $body:tt) => {{
+        $crate::fallible_transmutation_impl!(($($arg_name in $arg_ty),*) -> Result<$return_ty, $error_ty> $body)
+    }};
+    // Destructuring closure form
+    (|$($arg_ctor:ident $arg_fields:tt: &$arg_ty:ty),*| -> $return_ty:tt $body:tt) => {{
+        $crate::transmutation_impl!(($($arg_ctor $arg_fields in $arg_ty),*) -> $return_ty $body)
+    }};
     // Closure form
     (|$($arg_name:tt: &$arg_ty:ty),*| -> $return_ty:tt $body:tt) => {{
         $crate::transmutation_impl!(($($arg_name in $arg_ty),*) -> $return_ty $body)
     }};
 }
 
+/// Use this to inscribe the composition of two functions, checking their types line up.
+///
+/// ```
+/// use summon::{Tome, compose};
+/// #[derive(Clone)]
+/// struct Width(u32);
+/// #[derive(Clone)]
+/// struct Height(u32);
+/// #[derive(Clone)]
+/// struct Area(u32);
+/// struct Cost(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(Width(3));
+/// tome.ether(Height(4));
+/// fn area(w: &Width, h: &Height) -> Area {
+///     Area(w.0 * h.0)
+/// }
+/// fn cost(a: &Area) -> Cost {
+///     Cost(a.0 * 10)
+/// }
+/// compose!(tome, area: (Width, Height) -> Area, cost: Area -> Cost);
+/// assert_eq!(12, tome.summon::<Area>().unwrap().0);
+/// assert_eq!(120, tome.summon::<Cost>().unwrap().0);
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($tome:expr, $f:ident: ($a:ty, $b:ty) -> $c:tt, $g:ident: $c2:tt -> $d:ty) => {
+        $tome.inscribe_composed::<$a, $b, $c, $d>(
+            |a: &$a, b: &$b| -> $c { $f(a, b) },
+            |c: &$c2| -> $d { $g(c) },
+        )
+    };
+}
+
+/// A circle along with its priority, where circles with higher priority are tried first.
+type PrioritizedCircle = (i32, Arc<dyn Transmutation>);
+
 /// This is where all of the transmutation circles are inscribed.
-#[derive(Default)]
+///
+/// Cloning a tome is cheap, since the clone shares its circles with the original. Inscribing into either leaves
+/// the other unchanged, but state kept inside circles, such as generators, lazily computed ethers and collection
+/// ethers, is shared between them.
+#[derive(Clone, Default)]
 pub struct Tome {
-    /// Transmutation circles are organized by their products in the tomb.
-    circles: HashMap<TypeId, Vec<Box<dyn Transmutation>>>,
+    /// Transmutation circles are organized by their products in the tomb, each with its priority.
+    circles: HashMap<TypeId, Vec<PrioritizedCircle>>,
+    /// Incremented whenever the circles change so that plans can detect they are stale.
+    version: u64,
+    /// Contexts which instrument every circle as it is inscribed.
+    contexts: Vec<Rc<dyn Context>>,
+    /// The intermediate product of each composed product.
+    compositions: HashMap<TypeId, TypeId>,
+    /// The type each type registered as an alias or coercion is coerced from.
+    coercions: HashMap<TypeId, TypeId>,
+    /// Limits how many async summons may use each backpressured circle at once, by product.
+    #[cfg(feature = "async")]
+    backpressure: HashMap<TypeId, Arc<tokio::sync::Semaphore>>,
+    /// Circles which can only be performed by `summon_async` by product, fewest ingredients first.
+    #[cfg(feature = "async")]
+    async_circles: HashMap<TypeId, Vec<asynchronous::AsyncCircle>>,
+    /// Pairs of ingredients by product, where circles consuming the first should be tried before the second.
+    preferences: HashMap<TypeId, Vec<(TypeId, TypeId)>>,
+    /// Orders the circles for each product in place of sorting them by priority and number of ingredients.
+    strategy: Option<Rc<dyn strategy::InscriberStrategy>>,
+    /// Products whose circles were ordered with `reorder_circles`.
+    manually_ordered: HashSet<TypeId>,
+    /// Explicitly ordered pairs of circles by product, tried before any other circles except overrides.
+    fallback_chains: HashMap<TypeId, Vec<fallback::SharedFallback>>,
+    /// Overrideable circles by product, with the active circle last.
+    overrides: HashMap<TypeId, Vec<Arc<dyn Transmutation>>>,
+    /// Circles chosen between at random by product, along with their weights.
+    #[cfg(feature = "rand")]
+    weighted: HashMap<TypeId, random::WeightedCircles>,
+    /// The source of randomness for weighted circles, seeded from entropy on first use unless seeded explicitly.
+    #[cfg(feature = "rand")]
+    rng: random::RngCell,
+    /// The ether values collected by each accumulator by the ingredient they consume.
+    accumulators: HashMap<TypeId, Vec<accumulator::AccumulatedEthers>>,
+    /// The values of types summoned by reference, by type.
+    refs: HashMap<TypeId, reference::RefSlot>,
+    /// The `&'static T` given to `ether_static` for each `T`, by type.
+    statics: HashMap<TypeId, Rc<dyn Any>>,
+    /// The name of each product pinned with `pin_ether`, by product.
+    pinned: HashMap<TypeId, &'static str>,
+    /// How deeply ingredients may be nested while researching, if not the default.
+    max_depth: Option<usize>,
+    /// The materials kept between summons when memoization is enabled.
+    memo: memo::Memo,
+    /// How many times each product has been transmuted, when metrics are enabled.
+    metrics: Option<metrics::Metrics>,
+    /// Values injected for products along with the fingerprints of the recipes they came from.
+    precomputed: HashMap<TypeId, precomputed::Precomputed>,
+    /// Circles with a schema version attached by product, highest version first.
+    schema_versions: HashMap<TypeId, schema::VersionedCircles>,
+    /// Circles which are only active while their group's predicate holds, by product.
+    conditionals: HashMap<TypeId, Vec<group::ConditionalTransmutation>>,
+    /// The circles inscribed by each named group, so the group can be removed together.
+    named_groups: HashMap<String, Vec<Arc<dyn Transmutation>>>,
+    /// The shared items of each collection ether by product.
+    vec_ethers: HashMap<TypeId, Rc<dyn Any>>,
+    /// Resets the state of each generator by product.
+    generators: HashMap<TypeId, Rc<dyn Fn()>>,
+    /// Clears the value of each lazily computed ether by product.
+    lazies: HashMap<TypeId, Rc<dyn Fn()>>,
+    /// Checks run on each product after it is made, by product.
+    prescriptions: prescribe::Prescriptions,
+    /// Callbacks run with each product after it is made, by product.
+    observers: observe::Observers,
+    /// Callbacks told about circles as they are inscribed and removed.
+    circle_listeners: events::CircleListeners,
+    /// Human readable names for types, used when describing the tome.
+    type_registry: type_registry::TypeRegistry,
+    /// Keeps the watchers of files inscribed as ether alive.
+    #[cfg(feature = "file-ether")]
+    watchers: Vec<Rc<dyn notify::Watcher>>,
+    /// Callbacks which receive products as they are summoned.
+    #[cfg(feature = "subscriptions")]
+    subscriptions: Arc<Mutex<subscription::Subscriptions>>,
 }
 
 impl Tome {
+    /// How deeply ingredients may be nested while researching, unless changed with `set_max_depth`.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
     /// Create an empty tome.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Limit how deeply ingredients may be nested while researching, so deep recipes fail instead of
+    /// overflowing the stack.
+    ///
+    /// A type nested deeper than `max_depth` is treated as though it cannot be summoned.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.version += 1;
+        self.invalidate_cache();
+        self.max_depth = Some(max_depth);
+    }
+
+    /// How deeply ingredients may be nested while researching.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(Self::DEFAULT_MAX_DEPTH)
+    }
+
     /// Inscribe a note about a natural transmutation into the tome.
     pub fn inscribe<T: Transmutation + 'static>(&mut self, circle: T) {
-        let product_circles = self.circles.entry(circle.product()).or_default();
-        product_circles.push(Box::new(circle));
-        product_circles.sort_by_key(|c| c.ingredients().len());
+        self.inscribe_box(Box::new(circle));
+    }
+
+    /// Inscribe a circle whose type is only known at runtime, without boxing it again.
+    pub fn inscribe_dyn(&mut self, circle: Box<dyn Transmutation>) {
+        self.inscribe_box(circle);
+    }
+
+    fn inscribe_box(&mut self, circle: Box<dyn Transmutation>) {
+        self.inscribe_box_with_priority(circle, 0);
+    }
+
+    fn inscribe_box_with_priority(&mut self, circle: Box<dyn Transmutation>, priority: i32) {
+        let circle = self.contextualize(circle);
+        self.push_circle(Arc::from(circle), priority);
+    }
+
+    /// Inscribe a circle which may be shared with other tomes.
+    ///
+    /// The circle is stored without copying it unless a context has to instrument it.
+    pub fn inscribe_arc(&mut self, circle: Arc<dyn Transmutation>) {
+        let circle = if self.contexts.is_empty() {
+            circle
+        } else {
+            Arc::from(self.contextualize(Box::new(circle)))
+        };
+        self.push_circle(circle, 0);
+    }
+
+    /// Inscribe every one of `circles`, in order.
+    ///
+    /// The circles of each product are sorted once at the end rather than after every circle, which tries them
+    /// in the same order as inscribing them one at a time.
+    pub fn inscribe_from_iter(
+        &mut self,
+        circles: impl IntoIterator<Item = Box<dyn Transmutation>>,
+    ) {
+        let mut products = HashSet::new();
+        for circle in circles {
+            let circle: Arc<dyn Transmutation> = Arc::from(self.contextualize(circle));
+            let product = circle.product();
+            self.assert_unpinned(product);
+            self.notify_inscribed(&*circle);
+            self.circles.entry(product).or_default().push((0, circle));
+            products.insert(product);
+        }
+        if !products.is_empty() {
+            self.version += 1;
+        }
+        for product in products {
+            self.forget_cached_id(product);
+            self.order_circles(product);
+        }
+    }
+
+    /// Add a circle which has already been instrumented by the contexts.
+    fn push_circle(&mut self, circle: Arc<dyn Transmutation>, priority: i32) {
+        let product = circle.product();
+        self.assert_unpinned(product);
+        self.version += 1;
+        self.forget_cached_id(product);
+        self.notify_inscribed(&*circle);
+        self.circles
+            .entry(product)
+            .or_default()
+            .push((priority, circle));
+        self.order_circles(product);
+    }
+
+    /// Inscribe a circle which might not produce a `T`, making `Option<T>` summonable.
+    pub fn inscribe_optional<T: 'static>(&mut self, circle: impl Transmutation + 'static) {
+        assert_eq!(
+            TypeId::of::<Option<T>>(),
+            circle.product(),
+            "circle does not produce an optional value"
+        );
+        self.inscribe(circle);
+    }
+
+    /// Summon `A` by summoning `B` and converting it with `From`.
+    pub fn inscribe_forwarding<A: From<B> + 'static, B: Clone + 'static>(&mut self) {
+        self.inscribe_forwarding_with::<A, B>(A::from);
+    }
+
+    /// Summon `A` by summoning `B` and converting it with `convert`.
+    pub fn inscribe_forwarding_with<A: 'static, B: Clone + 'static>(
+        &mut self,
+        convert: impl Fn(B) -> A + 'static,
+    ) {
+        self.inscribe(Conversion(move |b: &B| convert(b.clone()), PhantomData));
+    }
+
+    /// Derive `U` from `T` with `f`, a shorthand for a circle with a single ingredient.
+    pub fn map_ether<T: 'static, U: 'static>(&mut self, f: impl Fn(&T) -> U + 'static) {
+        self.inscribe(Conversion(f, PhantomData));
+    }
+
+    /// Inscribe a circle only if no tome in this process has inscribed a circle of the same type before.
+    ///
+    /// Returns `true` if the circle was inscribed.
+    pub fn inscribe_once_per_process<T: Transmutation + 'static>(&mut self, circle: T) -> bool {
+        static INSCRIBED: OnceLock<Mutex<HashMap<TypeId, Arc<Once>>>> = OnceLock::new();
+        let once = INSCRIBED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Arc::new(Once::new()))
+            .clone();
+        let mut inscribed = false;
+        once.call_once(|| {
+            self.inscribe(circle);
+            inscribed = true;
+        });
+        inscribed
     }
 
     /// Create a note about how to create something out of the ether.
     pub fn ether<T: Clone + 'static>(&mut self, item: T) {
+        self.assert_unpinned(TypeId::of::<T>());
+        self.accumulate(&item);
         self.inscribe(Ether(item));
     }
 
-    /// Give me what I want.
-    pub fn summon<T: 'static>(&self) -> Option<T> {
+    /// Note that a thing can always be made from itself.
+    ///
+    /// This never makes anything summonable on its own, since a circle which consumes its own product
+    /// makes no progress and is skipped during research.
+    pub fn inscribe_identity<T: Clone + 'static>(&mut self) {
+        self.inscribe(Identity::<T>(PhantomData));
+    }
+
+    /// Give me what I want, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        if let Some(material) = self.precomputed::<T>() {
+            #[cfg(feature = "subscriptions")]
+            self.notify_subscribers(&material);
+            return Ok(material);
+        }
+        if let Some(summoned) = self.summon_memoized::<T>() {
+            return summoned;
+        }
         // Preserve all the materials we need and the thing we are summoning.
         let materials = self.preserve::<T>()?;
-        // Drop all the intermediate materials to get only the desired one.
-        Some(materials.into_material::<T>())
+        Ok(self.extract(materials))
+    }
+
+    /// Give me what I want, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+
+    /// Give me what I want, or its default if you can't.
+    ///
+    /// The default is used whenever `summon` fails, including when a transmutation fails, and is never kept in
+    /// the tome.
+    pub fn summon_or_default<T: Default + 'static>(&self) -> T {
+        self.summon_opt().unwrap_or_default()
+    }
+
+    /// Give me what I want, or make it with `fallback` if you can't.
+    ///
+    /// `fallback` is only called when `summon` fails.
+    pub fn summon_or_else<T: 'static>(&self, fallback: impl FnOnce() -> T) -> T {
+        self.summon_opt().unwrap_or_else(fallback)
+    }
+
+    /// Put what I want into `target`, overwriting anything already there.
+    ///
+    /// Returns whether `T` could be summoned; `target` is left alone if it couldn't.
+    pub fn summon_into<T: 'static>(&self, target: &mut Option<T>) -> bool {
+        match self.summon::<T>() {
+            Ok(summoned) => {
+                *target = Some(summoned);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Drop all the intermediate materials to get only the desired one.
+    fn extract<T: 'static>(&self, mut materials: Materials) -> T {
+        self.extract_from(&mut materials)
+    }
+
+    /// Take one of the materials out, leaving the rest.
+    fn extract_from<T: 'static>(&self, materials: &mut Materials) -> T {
+        let material = materials.take_material::<T>();
+        #[cfg(feature = "subscriptions")]
+        self.notify_subscribers(&material);
+        material
     }
 
     /// Give me what I want and more.
-    fn preserve<T: 'static>(&self) -> Option<Materials> {
-        // Find a recipe to create the item. This may fail.
-        let recipe: Recipe = self.research::<T>()?;
-        // Perform the whole recipe. This cannot fail, excpet via panic.
-        let materials: Materials = recipe.steps.into_iter().collect();
-        // Create all the materials in the recipe.
-        Some(materials)
+    ///
+    /// Every material made along the way is kept, so intermediate products can be inspected with `Materials::get`.
+    pub fn preserve<T: 'static>(&self) -> Result<Materials, SummonError> {
+        self.perform_retrying(TypeId::of::<T>(), |recipe| self.perform(recipe))
     }
 
-    fn research<T: 'static>(&self) -> Option<Recipe<'_>> {
+    /// Research a recipe for `id` and `perform` it, researching again without any circle which declines or whose
+    /// product is rejected by a prescription.
+    fn perform_retrying<'a, R>(
+        &'a self,
+        id: TypeId,
+        mut perform: impl FnMut(Recipe<'a>) -> Result<R, SummonError>,
+    ) -> Result<R, SummonError> {
+        let mut declined = HashSet::new();
+        loop {
+            // Find a recipe to create the item which avoids every circle that has declined. This may fail.
+            let mut research = Research {
+                declined: declined.clone(),
+                ..Research::default()
+            };
+            let recipe: Recipe = self
+                .research_in(id, &mut research)
+                .ok_or_else(|| self.diagnose(id))?;
+            let steps = recipe.steps.clone();
+            match perform(recipe) {
+                Err(SummonError::TransmutationFailed { at, source })
+                    if source.is::<decline::Declined>() || source.is::<prescribe::Rejected>() =>
+                {
+                    let step = steps
+                        .into_iter()
+                        .find(|step| step.product() == at)
+                        .expect("failed step is not in the recipe");
+                    declined.insert(circle_address(step));
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Perform the whole recipe, stopping at the first transmutation which fails.
+    fn perform(&self, recipe: Recipe<'_>) -> Result<Materials, SummonError> {
+        Self::perform_on(self.materials(), recipe)
+    }
+
+    /// Perform the whole recipe on top of some materials, stopping at the first transmutation which fails.
+    fn perform_on(mut materials: Materials, recipe: Recipe<'_>) -> Result<Materials, SummonError> {
+        for step in recipe.steps {
+            materials.try_apply(step)?;
+        }
+        Ok(materials)
+    }
+
+    /// Find a recipe for every product in the tome that can currently be summoned.
+    ///
+    /// Recipes are only researched as the iterator is advanced.
+    pub fn iter_recipes(&self) -> impl Iterator<Item = (TypeId, Recipe<'_>)> + '_ {
+        self.circles
+            .keys()
+            .filter_map(move |&id| self.research_id(id).map(|recipe| (id, recipe)))
+    }
+
+    /// Find the recipe `summon` would perform to make `T`, without performing it.
+    pub fn research<T: 'static>(&self) -> Option<Recipe<'_>> {
         self.research_id(TypeId::of::<T>())
     }
 
     fn research_id(&self, id: TypeId) -> Option<Recipe<'_>> {
-        self.circles.get(&id).and_then(|possibilities| {
-            possibilities.iter().find_map(|circle| {
-                let ingredients = circle.ingredients();
-                eprintln!("ingredients: {}", ingredients.len());
-                ingredients
-                    .iter()
-                    .fold(Some(Recipe::default()), |recipe, &ingredient| {
-                        recipe.and_then(|recipe| {
-                            self.research_id(ingredient).map(|next| recipe.join(next))
-                        })
-                    })
-                    .map(|recipe| recipe.join((**circle).into()))
+        self.research_in(id, &mut Research::default())
+    }
+
+    /// Research `id` while the types in `research` are already being researched further up.
+    ///
+    /// A type which is already being researched cannot be used, since that would be a cycle.
+    fn research_in<'a>(&'a self, id: TypeId, research: &mut Research<'a>) -> Option<Recipe<'a>> {
+        // Every type further up is still being researched, so this is how deeply the type is nested.
+        if research.researching.len() >= self.max_depth() {
+            research.depth_exceeded = true;
+            return None;
+        }
+        if let Some(recipe) = research.cheapest.get(&id) {
+            return Some(recipe.clone());
+        }
+        if !research.researching.insert(id) {
+            return None;
+        }
+        #[cfg(feature = "rand")]
+        let recipe = self.research_weighted(id, research);
+        #[cfg(not(feature = "rand"))]
+        let recipe = None;
+        let overlay = research.overlay;
+        let explicit = self.ordered_explicitly(id)
+            || overlay.is_some_and(|overlay| overlay.candidates(id).next().is_some());
+        #[cfg(feature = "async")]
+        let async_candidates = self.async_candidates(id, research.asynchronous);
+        #[cfg(not(feature = "async"))]
+        let async_candidates = std::iter::empty();
+        let recipe = recipe.or_else(|| {
+            // Boxed so that the chained iterators are not kept in every frame of this recursion.
+            let candidates: Box<dyn Iterator<Item = &dyn Transmutation>> = Box::new(
+                overlay
+                    .into_iter()
+                    .flat_map(|overlay| overlay.candidates(id))
+                    .chain(self.candidates(id))
+                    .chain(async_candidates),
+            );
+            let mut cheapest: Option<Recipe> = None;
+            for circle in candidates {
+                let recipe = match self.research_circle_in(circle, research) {
+                    Some(recipe) if explicit => return Some(recipe),
+                    Some(recipe) => recipe,
+                    None => continue,
+                };
+                // Keep the first of the cheapest recipes, so that ties are broken by the order of the circles.
+                if cheapest
+                    .as_ref()
+                    .is_none_or(|chosen| recipe.cost() < chosen.cost())
+                {
+                    cheapest = Some(recipe);
+                }
+            }
+            cheapest
+        });
+        research.researching.remove(&id);
+        if let Some(recipe) = &recipe {
+            research.cheapest.insert(id, recipe.clone());
+        }
+        recipe
+    }
+
+    #[cfg(feature = "rand")]
+    fn weighted_candidates(&self, id: TypeId) -> impl Iterator<Item = &dyn Transmutation> {
+        self.weighted
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .map(|(_, circle)| &**circle)
+    }
+
+    #[cfg(not(feature = "rand"))]
+    fn weighted_candidates(&self, _: TypeId) -> std::iter::Empty<&dyn Transmutation> {
+        std::iter::empty()
+    }
+
+    /// Find a recipe which ends with a specific circle that produces `id`.
+    fn research_circle<'a>(
+        &'a self,
+        id: TypeId,
+        circle: &'a dyn Transmutation,
+    ) -> Option<Recipe<'a>> {
+        let mut research = Research::default();
+        research.researching.insert(id);
+        self.research_circle_in(circle, &mut research)
+    }
+
+    /// Find a recipe which ends with a specific circle, whose product is already being researched.
+    fn research_circle_in<'a>(
+        &'a self,
+        circle: &'a dyn Transmutation,
+        research: &mut Research<'a>,
+    ) -> Option<Recipe<'a>> {
+        if circle.expired() || research.declined.contains(&circle_address(circle)) {
+            return None;
+        }
+        // A circle which consumes its own product, or any type it is being researched for, can never make
+        // progress.
+        circle
+            .ingredients()
+            .iter()
+            .try_fold(Recipe::default(), |recipe, &ingredient| {
+                self.research_in(ingredient, research)
+                    .map(|next| recipe.join(next))
             })
-        })
+            .map(|recipe| recipe.join(circle.into()))
+    }
+
+    /// All of the circles which could produce a product, in the order they should be tried.
+    fn candidates(&self, id: TypeId) -> impl Iterator<Item = &dyn Transmutation> + '_ {
+        self.overrides
+            .get(&id)
+            .and_then(|history| history.last())
+            .map(|circle| &**circle)
+            .into_iter()
+            .chain(self.fallback_candidates(id))
+            .chain(self.weighted_candidates(id))
+            .chain(
+                self.circles
+                    .get(&id)
+                    .into_iter()
+                    .flatten()
+                    .map(|(_, circle)| &**circle),
+            )
+            .chain(self.versioned_candidates(id))
+            .chain(self.conditional_candidates(id))
+    }
+
+    /// Every product which has a circle of any kind inscribed for it.
+    pub(crate) fn inscribed_products(&self) -> Vec<TypeId> {
+        let mut products: Vec<TypeId> = self
+            .circles
+            .keys()
+            .chain(self.overrides.keys())
+            .chain(self.fallback_chains.keys())
+            .chain(self.schema_versions.keys())
+            .chain(self.conditionals.keys())
+            .copied()
+            .collect();
+        #[cfg(feature = "rand")]
+        products.extend(self.weighted.keys().copied());
+        products.sort();
+        products.dedup();
+        products
+    }
+
+    /// The product of every circle of any kind which consumes `ingredient`, including conditional circles which
+    /// are not currently active.
+    pub(crate) fn consumers_of(&self, ingredient: TypeId) -> Vec<TypeId> {
+        let consumes = |circle: &dyn Transmutation| circle.ingredients().contains(&ingredient);
+        let mut products = consuming(&self.circles, |(_, circle)| consumes(&**circle));
+        products.extend(consuming(&self.overrides, |circle| consumes(&**circle)));
+        products.extend(consuming(&self.fallback_chains, |chain| {
+            chain.circles().iter().any(|circle| consumes(&**circle))
+        }));
+        products.extend(consuming(&self.schema_versions, |(_, circle)| {
+            consumes(&**circle)
+        }));
+        products.extend(consuming(&self.conditionals, |conditional| {
+            consumes(&*conditional.circle())
+        }));
+        #[cfg(feature = "rand")]
+        products.extend(consuming(&self.weighted, |(_, circle)| consumes(&**circle)));
+        #[cfg(feature = "async")]
+        products.extend(consuming(&self.async_circles, |circle| consumes(circle)));
+        products.sort();
+        products.dedup();
+        products
     }
 }
 
+/// The products in `store` with any circle which `consumes` something.
+fn consuming<C>(store: &HashMap<TypeId, Vec<C>>, consumes: impl Fn(&C) -> bool) -> Vec<TypeId> {
+    store
+        .iter()
+        .filter(|(_, circles)| circles.iter().any(&consumes))
+        .map(|(&product, _)| product)
+        .collect()
+}
+
+/// The state of a single search for a recipe.
 #[derive(Default)]
-struct Recipe<'a> {
+struct Research<'a> {
+    /// The types being researched further up, which cannot be used again without a cycle.
+    researching: HashSet<TypeId>,
+    /// A tome whose circles are tried before the circles of the tome being researched.
+    overlay: Option<&'a Tome>,
+    /// Set when a type was not researched because it was nested too deeply.
+    depth_exceeded: bool,
+    /// The addresses of circles which declined to transmute, which cannot be used again.
+    declined: HashSet<*const ()>,
+    /// The recipes already chosen for types during this search, so they are not researched again.
+    cheapest: HashMap<TypeId, Recipe<'a>>,
+    /// Whether async circles may be used, since only `summon_async` can perform them.
+    #[cfg(feature = "async")]
+    asynchronous: bool,
+}
+
+/// Identifies a circle by its address, ignoring its vtable.
+fn circle_address(circle: &dyn Transmutation) -> *const () {
+    circle as *const dyn Transmutation as *const ()
+}
+
+/// The ordered steps required to summon a product.
+#[derive(Clone, Default)]
+pub struct Recipe<'a> {
     steps: Vec<&'a dyn Transmutation>,
     products: HashMap<TypeId, usize>,
 }
@@ -251,18 +1230,42 @@ impl<'a> From<&'a dyn Transmutation> for Recipe<'a> {
 }
 
 impl<'a> Recipe<'a> {
+    /// The transmutations to perform, in the order they must be performed.
+    ///
+    /// The order only depends on the order of the circles and their ingredients, so the same tome always
+    /// performs the same steps in the same order.
+    pub fn steps(&self) -> &[&'a dyn Transmutation] {
+        &self.steps
+    }
+
+    /// Maps each product in the recipe to the index of the step which produces it.
+    pub fn products(&self) -> &HashMap<TypeId, usize> {
+        &self.products
+    }
+
+    /// Perform every step of the recipe with the tome it was researched in and take out `T`.
+    pub fn execute<T: 'static>(self, tome: &Tome) -> Result<T, SummonError> {
+        let materials = tome.perform(self)?;
+        Ok(tome.extract(materials))
+    }
+
+    /// The total cost of the steps in the recipe.
+    pub fn cost(&self) -> u64 {
+        self.steps
+            .iter()
+            .fold(0, |total, step| total.saturating_add(step.cost()))
+    }
+
     fn join(self, other: Self) -> Self {
         let Self {
             mut steps,
             mut products,
         } = self;
-        let Self {
-            steps: other_steps,
-            products: other_products,
-        } = other;
-        for (product, step) in other_products {
-            products.entry(product).or_insert_with(|| {
-                steps.push(other_steps[step]);
+        // Walk the other steps in order so that every step still comes after its ingredients. Only the first step
+        // for each product is kept, and every circle has a single product, so no circle is ever performed twice.
+        for step in other.steps {
+            products.entry(step.product()).or_insert_with(|| {
+                steps.push(step);
                 steps.len() - 1
             });
         }
@@ -270,9 +1273,18 @@ impl<'a> Recipe<'a> {
     }
 }
 
+/// The products made while performing a recipe, by type.
 #[derive(Default)]
 pub struct Materials {
     materials: HashMap<TypeId, Box<dyn Any>>,
+    /// Every transmutation performed so far, if they are being recorded.
+    trace: Option<ExecutionTrace>,
+    /// Counts the transmutations performed, if metrics are enabled.
+    metrics: Option<metrics::Metrics>,
+    /// Checks run on each product after it is made.
+    prescriptions: Option<prescribe::Prescriptions>,
+    /// Callbacks run with each product after it is made.
+    observers: Option<observe::Observers>,
 }
 
 impl Materials {
@@ -280,22 +1292,104 @@ impl Materials {
         Self::default()
     }
 
-    fn get(&self, id: TypeId) -> Option<&dyn Any> {
+    /// Record every transmutation performed on the materials from now on.
+    fn traced(self) -> Self {
+        Self {
+            trace: Some(vec![]),
+            ..self
+        }
+    }
+
+    /// Note that `recipe` is being performed, if transmutations are being recorded or counted.
+    fn record(&mut self, recipe: &dyn Transmutation) {
+        if let Some(metrics) = &self.metrics {
+            *metrics.lock().unwrap().entry(recipe.product()).or_default() += 1;
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.push(StepRecord {
+                product: recipe.product(),
+                ingredients: recipe.ingredients().to_vec(),
+                name: recipe.name(),
+                performed_at: Instant::now(),
+            });
+        }
+    }
+
+    /// The material of type `T`, if it has been made.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.get_id(TypeId::of::<T>()).map(|material| {
+            material
+                .downcast_ref()
+                .expect("material has an incorrect type")
+        })
+    }
+
+    fn get_id(&self, id: TypeId) -> Option<&dyn Any> {
         self.materials.get(&id).map(|b| &**b)
     }
 
-    fn apply(&mut self, recipe: &dyn Transmutation) {
+    /// Perform a transmutation on the materials, replacing any material of the same type as its product.
+    ///
+    /// Panics if any of its ingredients have not been made, or if the transmutation fails.
+    pub fn apply(&mut self, recipe: &dyn Transmutation) {
         let product_type = recipe.product();
         let ingredients: Vec<&dyn Any> = recipe
             .ingredients()
             .iter()
-            .map(|&ingredient| self.get(ingredient).unwrap())
+            .map(|&ingredient| self.get_id(ingredient).unwrap())
             .collect();
         let product = recipe.transmute(&ingredients);
+        if !self.accepted(product_type, &*product) {
+            panic!("transmutation failed: {}", prescribe::Rejected);
+        }
+        self.insert(recipe, product);
+    }
+
+    fn try_apply(&mut self, recipe: &dyn Transmutation) -> Result<(), SummonError> {
+        let product_type = recipe.product();
+        let ingredients: Vec<&dyn Any> = recipe
+            .ingredients()
+            .iter()
+            .map(|&ingredient| self.get_id(ingredient).unwrap())
+            .collect();
+        let product = recipe.try_transmute(&ingredients).map_err(|source| {
+            SummonError::TransmutationFailed {
+                at: product_type,
+                source,
+            }
+        })?;
+        if !self.accepted(product_type, &*product) {
+            return Err(SummonError::TransmutationFailed {
+                at: product_type,
+                source: Box::new(prescribe::Rejected),
+            });
+        }
+        self.insert(recipe, product);
+        Ok(())
+    }
+
+    /// Keep the product of a transmutation which was performed, telling anything observing it.
+    fn insert(&mut self, recipe: &dyn Transmutation, product: Box<dyn Any>) {
+        let product_type = recipe.product();
+        self.record(recipe);
+        if let Some(observers) = &self.observers {
+            observe::notify(observers, product_type, &*product);
+        }
         self.materials.insert(product_type, product);
     }
 
-    fn into_material<T: 'static>(mut self) -> T {
+    /// Whether the product passes its prescriptions, if there are any.
+    fn accepted(&self, id: TypeId, product: &dyn Any) -> bool {
+        self.prescriptions
+            .as_ref()
+            .is_none_or(|prescriptions| prescribe::accepted(prescriptions, id, product))
+    }
+
+    fn take_id(&mut self, id: TypeId) -> Option<Box<dyn Any>> {
+        self.materials.remove(&id)
+    }
+
+    fn take_material<T: 'static>(&mut self) -> T {
         *self
             .materials
             .remove(&TypeId::of::<T>())
@@ -305,6 +1399,16 @@ impl Materials {
     }
 }
 
+impl IntoIterator for Materials {
+    type Item = (TypeId, Box<dyn Any>);
+    type IntoIter = std::collections::hash_map::IntoIter<TypeId, Box<dyn Any>>;
+
+    /// Hand over every material which has been made, along with its type, in no particular order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.materials.into_iter()
+    }
+}
+
 impl<'a> FromIterator<&'a dyn Transmutation> for Materials {
     fn from_iter<T>(iter: T) -> Self
     where
@@ -0,0 +1,49 @@
+use crate::{SummonError, Tome};
+use std::any::TypeId;
+
+/// Borrows a tome and logs every transmutation performed while summoning through it, made with
+/// `Tome::with_logging`.
+///
+/// Unlike `LoggingContext`, this leaves the tome and its circles unchanged.
+pub struct LoggingTome<'a> {
+    tome: &'a Tome,
+    logger: Box<dyn Fn(&str) + 'a>,
+}
+
+impl LoggingTome<'_> {
+    /// Give me what I want, logging each step before it is performed, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        let id = TypeId::of::<T>();
+        let recipe = self
+            .tome
+            .research_id(id)
+            .ok_or_else(|| self.tome.diagnose(id))?;
+        let mut materials = self.tome.materials();
+        for step in recipe.steps {
+            (self.logger)(&format!(
+                "Applying circle producing {} with {} ingredients",
+                self.tome.type_registry().describe(step.product()),
+                step.ingredients().len()
+            ));
+            materials.try_apply(step)?;
+        }
+        Ok(self.tome.extract(materials))
+    }
+
+    /// Give me what I want, logging each step before it is performed, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+}
+
+impl Tome {
+    /// Summon through the returned tome to log each transmutation with `logger` as it is performed.
+    ///
+    /// Types are described by their name from `register_type` if they have one.
+    pub fn with_logging<'a>(&'a self, logger: impl Fn(&str) + 'a) -> LoggingTome<'a> {
+        LoggingTome {
+            tome: self,
+            logger: Box::new(logger),
+        }
+    }
+}
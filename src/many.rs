@@ -0,0 +1,96 @@
+use crate::{Materials, Recipe, SummonError, Tome};
+use std::any::TypeId;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A tuple of distinct types which can be summoned together, sharing the steps their recipes have in common.
+///
+/// This is implemented for tuples of one to eight types.
+pub trait SummonMany: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn ids() -> Vec<TypeId>;
+    /// The tuple with each type wrapped in an `Option`, returned by `try_summon_all`.
+    type Optional;
+    #[doc(hidden)]
+    fn extract(tome: &Tome, materials: &mut Materials) -> Self;
+    #[doc(hidden)]
+    fn extract_optional(tome: &Tome, materials: &mut Materials) -> Self::Optional;
+}
+
+macro_rules! summon_many_impl {
+    ($($ty:ident),+) => {
+        impl<$($ty: 'static),+> sealed::Sealed for ($($ty,)+) {}
+
+        impl<$($ty: 'static),+> SummonMany for ($($ty,)+) {
+            type Optional = ($(Option<$ty>,)+);
+            fn ids() -> Vec<TypeId> {
+                vec![$(TypeId::of::<$ty>()),+]
+            }
+            fn extract(tome: &Tome, materials: &mut Materials) -> Self {
+                ($(tome.extract_from::<$ty>(materials),)+)
+            }
+            fn extract_optional(tome: &Tome, materials: &mut Materials) -> Self::Optional {
+                ($(
+                    materials
+                        .get_id(TypeId::of::<$ty>())
+                        .is_some()
+                        .then(|| tome.extract_from::<$ty>(materials)),
+                )+)
+            }
+        }
+    };
+}
+
+summon_many_impl!(A);
+summon_many_impl!(A, B);
+summon_many_impl!(A, B, C);
+summon_many_impl!(A, B, C, D);
+summon_many_impl!(A, B, C, D, E);
+summon_many_impl!(A, B, C, D, E, G);
+summon_many_impl!(A, B, C, D, E, G, H);
+summon_many_impl!(A, B, C, D, E, G, H, I);
+
+impl Tome {
+    /// Summon several distinct types at once, performing the steps their recipes share only once.
+    pub fn summon_tuple<Types: SummonMany>(&self) -> Result<Types, SummonError> {
+        let ids = Types::ids();
+        for (index, id) in ids.iter().enumerate() {
+            assert!(
+                !ids[..index].contains(id),
+                "summon_tuple requires distinct types"
+            );
+        }
+        let recipe = ids.iter().try_fold(Recipe::default(), |recipe, &id| {
+            self.research_id(id)
+                .map(|next| recipe.join(next))
+                .ok_or_else(|| self.diagnose(id))
+        })?;
+        let mut materials = self.perform(recipe)?;
+        Ok(Types::extract(self, &mut materials))
+    }
+
+    /// Summon whichever of several distinct types can be summoned, performing the steps their recipes share only
+    /// once.
+    ///
+    /// A type is `None` if it has no recipe, or if a transmutation its recipe needs fails.
+    pub fn try_summon_all<Types: SummonMany>(&self) -> Types::Optional {
+        let recipe = Types::ids()
+            .into_iter()
+            .filter_map(|id| self.research_id(id))
+            .fold(Recipe::default(), Recipe::join);
+        let mut materials = self.materials();
+        for step in recipe.steps {
+            let available = step
+                .ingredients()
+                .iter()
+                .all(|&ingredient| materials.get_id(ingredient).is_some());
+            if available {
+                // A failed step leaves its product out, so only the steps depending on it are skipped.
+                let _ = materials.try_apply(step);
+            }
+        }
+        Types::extract_optional(self, &mut materials)
+    }
+}
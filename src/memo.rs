@@ -0,0 +1,144 @@
+use crate::{circle_address, Materials, SummonError, Tome};
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// The materials made by previous summons, when memoization is enabled.
+#[derive(Default)]
+pub(crate) struct Memo {
+    pub(crate) enabled: bool,
+    materials: RefCell<Materials>,
+    /// The address of the circle which made each kept material, so it is made again once another circle is used.
+    makers: RefCell<HashMap<TypeId, *const ()>>,
+}
+
+impl Clone for Memo {
+    /// A clone starts with an empty cache, since its circles may change independently.
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            materials: RefCell::default(),
+            makers: RefCell::default(),
+        }
+    }
+}
+
+impl Tome {
+    /// Keep every material made by `summon` so later summons reuse it instead of performing its circle again.
+    ///
+    /// `summon` hands the summoned product itself over rather than keeping it, since it may not be `Clone`, so
+    /// only the last step of its recipe is performed again by the next summon. `summon_cached` keeps the product
+    /// too and hands out a clone of it, so nothing is performed again.
+    ///
+    /// Changing the circles of the tome forgets every kept material which depends on the products they make. A
+    /// kept material is also made again whenever the recipe researched for it uses a different circle than the
+    /// one which made it, such as when that circle has expired or its conditional group became inactive.
+    pub fn enable_cache(&mut self) {
+        self.memo.enabled = true;
+    }
+
+    /// Stop keeping materials between summons and forget those already kept.
+    pub fn disable_cache(&mut self) {
+        self.memo.enabled = false;
+        self.invalidate_cache();
+    }
+
+    /// Forget every material kept by the cache.
+    pub fn invalidate_cache(&self) {
+        self.memo.materials.replace(Materials::new());
+        self.memo.makers.borrow_mut().clear();
+    }
+
+    /// Forget the kept material of type `T` and every kept material made from it, directly or indirectly.
+    pub fn forget_cache<T: 'static>(&self) {
+        self.forget_cached_id(TypeId::of::<T>());
+    }
+
+    /// Forget the kept material with the given `TypeId` and every kept material which depends on it.
+    ///
+    /// Dependents are found by walking the circles of every kind which consume each forgotten type.
+    pub(crate) fn forget_cached_id(&self, changed: TypeId) {
+        let mut materials = self.memo.materials.borrow_mut();
+        if materials.materials.is_empty() {
+            return;
+        }
+        let mut forgotten = vec![changed];
+        let mut next = 0;
+        while let Some(&ingredient) = forgotten.get(next) {
+            next += 1;
+            materials.materials.remove(&ingredient);
+            for product in self.consumers_of(ingredient) {
+                if !forgotten.contains(&product) {
+                    forgotten.push(product);
+                }
+            }
+        }
+    }
+
+    /// Summon `T` through the cache, keeping the product and handing out a clone of it, so its circle is only
+    /// performed again once it has been forgotten.
+    ///
+    /// This is the same as `summon` when the cache is not enabled.
+    pub fn summon_cached<T: Clone + 'static>(&self) -> Result<T, SummonError> {
+        if !self.memo.enabled {
+            return self.summon::<T>();
+        }
+        if let Some(material) = self.precomputed::<T>() {
+            #[cfg(feature = "subscriptions")]
+            self.notify_subscribers(&material);
+            return Ok(material);
+        }
+        self.summon_through_cache::<T, _>(|materials| {
+            let material = materials
+                .get::<T>()
+                .expect("material was not found")
+                .clone();
+            #[cfg(feature = "subscriptions")]
+            self.notify_subscribers(&material);
+            material
+        })
+    }
+
+    /// Summon `T` through the cache, if it is enabled.
+    pub(crate) fn summon_memoized<T: 'static>(&self) -> Option<Result<T, SummonError>> {
+        if !self.memo.enabled {
+            return None;
+        }
+        Some(self.summon_through_cache::<T, _>(|materials| self.extract_from(materials)))
+    }
+
+    /// Make `T` and everything it is made from, reusing the kept materials, and then `finish` with them.
+    ///
+    /// Declining circles and rejected products are retried just like `summon` does.
+    fn summon_through_cache<T: 'static, R>(
+        &self,
+        finish: impl FnOnce(&mut Materials) -> R,
+    ) -> Result<R, SummonError> {
+        let mut materials = self.memo.materials.borrow_mut();
+        // The kept materials may have been made before metrics, prescriptions or observers changed.
+        let fresh = self.materials();
+        materials.metrics = fresh.metrics;
+        materials.prescriptions = fresh.prescriptions;
+        materials.observers = fresh.observers;
+        let mut makers = self.memo.makers.borrow_mut();
+        self.perform_retrying(TypeId::of::<T>(), |recipe| {
+            let mut remade = HashSet::new();
+            for step in recipe.steps {
+                let product = step.product();
+                let kept = materials.get_id(product).is_some()
+                    && makers.get(&product) == Some(&circle_address(step))
+                    && !step
+                        .ingredients()
+                        .iter()
+                        .any(|ingredient| remade.contains(ingredient));
+                if !kept {
+                    materials.try_apply(step)?;
+                    makers.insert(product, circle_address(step));
+                    remade.insert(product);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(finish(&mut materials))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{Materials, Tome};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// How many times each product has been transmuted, shared by a tome and the materials it performs.
+pub(crate) type Metrics = Arc<Mutex<HashMap<TypeId, u64>>>;
+
+impl Tome {
+    /// Count how many times each product is transmuted from now on.
+    ///
+    /// Clones of the tome made afterwards share the counts with it.
+    pub fn enable_metrics(&mut self) {
+        self.metrics.get_or_insert_with(Metrics::default);
+    }
+
+    /// The number of times each product has been transmuted since metrics were enabled or last reset.
+    ///
+    /// This is empty if metrics were never enabled.
+    pub fn metrics(&self) -> HashMap<TypeId, u64> {
+        self.metrics
+            .as_ref()
+            .map(|metrics| metrics.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+
+    /// Forget every count, leaving metrics enabled.
+    pub fn reset_metrics(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.lock().unwrap().clear();
+        }
+    }
+
+    /// Create empty materials which count the transmutations performed on them if metrics are enabled, and
+    /// which check and observe products with the tome's prescriptions and subscribers.
+    pub(crate) fn materials(&self) -> Materials {
+        Materials {
+            metrics: self.metrics.clone(),
+            prescriptions: Some(self.prescriptions.clone()),
+            observers: Some(self.observers.clone()),
+            ..Materials::default()
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Transmutation;
+use std::any::{Any, TypeId};
+use std::error::Error;
+
+/// A transmutation labelled with a name, which is used when describing recipes.
+pub struct Named<C>(pub C, pub &'static str);
+
+impl<C: Transmutation> Named<C> {
+    /// Label `circle` with `name`.
+    pub fn new(circle: C, name: &'static str) -> Self {
+        Self(circle, name)
+    }
+}
+
+impl<C: Transmutation> Transmutation for Named<C> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        self.0.ingredients()
+    }
+    fn product(&self) -> TypeId {
+        self.0.product()
+    }
+    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+        self.0.transmute(inputs)
+    }
+    fn try_transmute(&self, inputs: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        self.0.try_transmute(inputs)
+    }
+    fn expired(&self) -> bool {
+        self.0.expired()
+    }
+    fn name(&self) -> Option<&'static str> {
+        Some(self.1)
+    }
+    fn cost(&self) -> u64 {
+        self.0.cost()
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        self.0.ether_value()
+    }
+}
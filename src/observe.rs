@@ -0,0 +1,36 @@
+use crate::Tome;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Called with a product after it is made.
+type Observer = Rc<dyn Fn(&dyn Any)>;
+
+/// The observers of each product.
+pub(crate) type Observers = Rc<HashMap<TypeId, Vec<Observer>>>;
+
+/// Call every observer of the product.
+pub(crate) fn notify(observers: &Observers, id: TypeId, product: &dyn Any) {
+    for observer in observers.get(&id).into_iter().flatten() {
+        observer(product);
+    }
+}
+
+impl Tome {
+    /// Call `callback` with every `T` made from now on, including those made as ingredients of other products.
+    ///
+    /// Unlike `subscribe_to_product`, this is called every time a circle producing `T` is performed, rather than
+    /// only when `T` is summoned.
+    pub fn subscribe<T: 'static>(&mut self, callback: impl Fn(&T) + 'static) {
+        Rc::make_mut(&mut self.observers)
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Rc::new(move |product: &dyn Any| {
+                callback(
+                    product
+                        .downcast_ref()
+                        .expect("observer passed an incorrect type"),
+                )
+            }));
+    }
+}
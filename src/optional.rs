@@ -0,0 +1,30 @@
+use crate::{Conversion, Ether, Tome};
+use std::marker::PhantomData;
+
+impl Tome {
+    /// Let circles consume `Option<T>`, which is `Some` when `T` can be summoned and `None` otherwise.
+    ///
+    /// Since `Option<T>` can always be summoned, a circle consuming it never fails for lack of a `T`.
+    ///
+    /// ```
+    /// use summon::{Tome, circle};
+    /// #[derive(Clone)]
+    /// struct Nickname(&'static str);
+    /// struct Greeting(String);
+    /// type MaybeNickname = Option<Nickname>;
+    /// let mut tome = Tome::new();
+    /// tome.optional_ingredient::<Nickname>();
+    /// tome.inscribe(circle!(|n: &MaybeNickname| -> Greeting {
+    ///     Greeting(format!("hello {}", n.as_ref().map_or("stranger", |n| n.0)))
+    /// }));
+    /// assert_eq!("hello stranger", tome.summon::<Greeting>().unwrap().0);
+    /// tome.ether(Nickname("pal"));
+    /// assert_eq!("hello pal", tome.summon::<Greeting>().unwrap().0);
+    /// ```
+    pub fn optional_ingredient<T: Clone + 'static>(&mut self) {
+        self.inscribe_fallback_chain::<Option<T>, _, _>(
+            Conversion(|item: &T| Some(item.clone()), PhantomData),
+            Ether(None::<T>),
+        );
+    }
+}
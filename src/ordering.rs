@@ -0,0 +1,249 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// A set of preferences for a product which contradict each other.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderingConflict {
+    /// The product the preferences were given for.
+    pub product: TypeId,
+    /// The ingredients whose preferences form a cycle.
+    pub types: Vec<TypeId>,
+    /// Describes the contradiction.
+    pub explanation: String,
+}
+
+impl Tome {
+    /// Inscribe a circle which is tried before every circle for the same product with a lower priority.
+    ///
+    /// Priority takes precedence over the number of ingredients and the cost of recipes. Circles inscribed with
+    /// `inscribe` have a priority of `0`.
+    pub fn inscribe_with_priority<T: Transmutation + 'static>(&mut self, circle: T, priority: i32) {
+        self.inscribe_box_with_priority(Box::new(circle), priority);
+    }
+
+    /// When researching `T`, try circles consuming `Preferred` before circles consuming `Over`.
+    ///
+    /// Preferences take precedence over the number of ingredients. If the preferences for `T` contradict
+    /// each other they are ignored, and the contradiction is reported by `compute_ordering_conflicts`.
+    pub fn prefer<T: 'static, Preferred: 'static, Over: 'static>(&mut self) {
+        let product = TypeId::of::<T>();
+        self.version += 1;
+        self.forget_cached_id(product);
+        self.preferences
+            .entry(product)
+            .or_default()
+            .push((TypeId::of::<Preferred>(), TypeId::of::<Over>()));
+        self.order_circles(product);
+    }
+
+    /// Rearrange the circles for `T`, moving the circle at position `order[i]` to position `i`.
+    ///
+    /// This replaces the ingredient count and preference ordering for `T` until `reset_circle_order` is called.
+    /// Returns `false` and leaves the circles alone if `order` is not a permutation of their positions.
+    pub fn reorder_circles<T: 'static>(&mut self, order: Vec<usize>) -> bool {
+        let product = TypeId::of::<T>();
+        let circles = match self.circles.get_mut(&product) {
+            Some(circles) => circles,
+            None => return order.is_empty(),
+        };
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        if !sorted.into_iter().eq(0..circles.len()) {
+            return false;
+        }
+        self.version += 1;
+        let mut taken: Vec<_> = circles.drain(..).map(Some).collect();
+        circles.extend(order.into_iter().map(|index| taken[index].take().unwrap()));
+        self.manually_ordered.insert(product);
+        self.forget_cached_id(product);
+        true
+    }
+
+    /// Undo `reorder_circles`, ordering the circles for `T` by their number of ingredients and preferences again.
+    pub fn reset_circle_order<T: 'static>(&mut self) {
+        let product = TypeId::of::<T>();
+        self.version += 1;
+        self.forget_cached_id(product);
+        self.manually_ordered.remove(&product);
+        self.order_circles(product);
+    }
+
+    /// Find every set of preferences which contradict each other.
+    pub fn compute_ordering_conflicts(&self) -> Vec<OrderingConflict> {
+        let mut conflicts = vec![];
+        for (&product, preferences) in &self.preferences {
+            let mut reported = HashSet::new();
+            for &(preferred, _) in preferences {
+                if reported.contains(&preferred) {
+                    continue;
+                }
+                let cycle = preference_cycle(preferences, preferred);
+                if cycle.is_empty() {
+                    continue;
+                }
+                reported.extend(cycle.iter().copied());
+                let explanation = format!(
+                    "preferences for {:?} form a cycle: {}",
+                    product,
+                    cycle
+                        .iter()
+                        .chain(cycle.first())
+                        .map(|ty| format!("{:?}", ty))
+                        .collect::<Vec<_>>()
+                        .join(" > ")
+                );
+                conflicts.push(OrderingConflict {
+                    product,
+                    types: cycle,
+                    explanation,
+                });
+            }
+        }
+        conflicts
+    }
+
+    /// Check whether a preference for `product` says to try `earlier` before `later`.
+    pub(crate) fn is_preferred(
+        &self,
+        product: TypeId,
+        earlier: &dyn Transmutation,
+        later: &dyn Transmutation,
+    ) -> bool {
+        self.preferences.get(&product).is_some_and(|preferences| {
+            preferences.iter().any(|(preferred, over)| {
+                earlier.ingredients().contains(preferred) && later.ingredients().contains(over)
+            })
+        })
+    }
+
+    /// The priority `circle` was inscribed with for `product`, which is `0` unless it was given to
+    /// `inscribe_with_priority`.
+    pub(crate) fn priority_of(&self, product: TypeId, circle: &dyn Transmutation) -> i32 {
+        self.circles
+            .get(&product)
+            .into_iter()
+            .flatten()
+            .find(|(_, inscribed)| std::ptr::addr_eq(&**inscribed, circle))
+            .map_or(0, |&(priority, _)| priority)
+    }
+
+    /// Check whether the circles for `product` are tried in an order given explicitly, rather than the cheapest
+    /// recipe being chosen.
+    pub(crate) fn ordered_explicitly(&self, product: TypeId) -> bool {
+        self.manually_ordered.contains(&product)
+            || self.strategy.is_some()
+            || self.preferences.contains_key(&product)
+            || self
+                .circles
+                .get(&product)
+                .is_some_and(|circles| circles.iter().any(|&(priority, _)| priority != 0))
+            || self.overrides.contains_key(&product)
+            || self.fallback_chains.contains_key(&product)
+    }
+
+    /// Sort the circles for a product with the tome's strategy, or by their priority and number of ingredients,
+    /// and then apply any preferences, unless they were ordered manually.
+    pub(crate) fn order_circles(&mut self, product: TypeId) {
+        if self.manually_ordered.contains(&product) {
+            return;
+        }
+        let circles = match self.circles.get_mut(&product) {
+            Some(circles) => circles,
+            None => return,
+        };
+        match &self.strategy {
+            Some(strategy) => strategy.order(circles),
+            None => {
+                circles.sort_by_key(|(priority, c)| (Reverse(*priority), c.ingredients().len()))
+            }
+        }
+        let preferences = match self.preferences.get(&product) {
+            Some(preferences) => preferences,
+            None => return,
+        };
+
+        // `prefers[earlier][later]` when a circle must be tried before another.
+        let count = circles.len();
+        let consumes =
+            |index: usize, ingredient: TypeId| circles[index].1.ingredients().contains(&ingredient);
+        let prefers: Vec<Vec<bool>> = (0..count)
+            .map(|earlier| {
+                (0..count)
+                    .map(|later| {
+                        earlier != later
+                            && preferences.iter().any(|&(preferred, over)| {
+                                consumes(earlier, preferred) && consumes(later, over)
+                            })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // A preferred circle takes the place of the earliest circle it is transitively preferred over.
+        let place: Vec<usize> = (0..count)
+            .map(|index| {
+                let mut seen = vec![index];
+                let mut pending = vec![index];
+                while let Some(earlier) = pending.pop() {
+                    for (later, &preferred) in prefers[earlier].iter().enumerate() {
+                        if preferred && !seen.contains(&later) {
+                            seen.push(later);
+                            pending.push(later);
+                        }
+                    }
+                }
+                seen.into_iter().min().unwrap()
+            })
+            .collect();
+
+        // Repeatedly take the circle with the earliest place which has nothing left preferred over it.
+        let mut order = vec![];
+        let mut remaining: Vec<usize> = (0..count).collect();
+        while let Some(position) = remaining
+            .iter()
+            .enumerate()
+            .filter(|&(_, &later)| !remaining.iter().any(|&earlier| prefers[earlier][later]))
+            .min_by_key(|&(_, &index)| (place[index], index))
+            .map(|(position, _)| position)
+        {
+            order.push(remaining.remove(position));
+        }
+        // Contradicting preferences leave the ingredient count order in place.
+        if !remaining.is_empty() {
+            return;
+        }
+
+        let mut taken: Vec<_> = circles.drain(..).map(Some).collect();
+        circles.extend(order.into_iter().map(|index| taken[index].take().unwrap()));
+    }
+}
+
+/// Find the types which are both preferred over and preferred under `start`, including `start`.
+fn preference_cycle(preferences: &[(TypeId, TypeId)], start: TypeId) -> Vec<TypeId> {
+    let reachable = |from: TypeId| {
+        let mut seen = vec![];
+        let mut pending = vec![from];
+        while let Some(ty) = pending.pop() {
+            for &(preferred, over) in preferences {
+                if preferred == ty && !seen.contains(&over) {
+                    seen.push(over);
+                    pending.push(over);
+                }
+            }
+        }
+        seen
+    };
+    let below = reachable(start);
+    if !below.contains(&start) {
+        return vec![];
+    }
+    let mut cycle = vec![start];
+    for ty in below {
+        if ty != start && reachable(ty).contains(&start) {
+            cycle.push(ty);
+        }
+    }
+    cycle
+}
@@ -0,0 +1,149 @@
+use crate::{Research, SummonError, Tome, Transmutation};
+use std::any::TypeId;
+use std::ops::Deref;
+
+/// A tome which inherits the circles of a parent tome and can inscribe its own without changing the parent.
+///
+/// Circles inscribed into the scope are tried before the parent's circles.
+pub struct ScopedTome<'a> {
+    parent: &'a Tome,
+    local: Tome,
+}
+
+impl ScopedTome<'_> {
+    /// Inscribe a note about a natural transmutation into the scope.
+    pub fn inscribe<T: Transmutation + 'static>(&mut self, circle: T) {
+        self.local.inscribe(circle);
+    }
+
+    /// Create a note in the scope about how to create something out of the ether.
+    pub fn ether<T: Clone + 'static>(&mut self, item: T) {
+        self.local.ether(item);
+    }
+
+    /// Give me what I want from the scope or its parent, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        self.parent.summon_over::<T>(&self.local)
+    }
+
+    /// Give me what I want from the scope or its parent, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+}
+
+/// A tome with some of its ethers replaced, made with `Tome::with_ether`.
+///
+/// Only `summon` and `summon_opt` see the replaced ethers. Everything else is read from the original tome.
+pub struct TomeWithEther<'a> {
+    tome: &'a Tome,
+    ethers: Tome,
+}
+
+impl TomeWithEther<'_> {
+    /// Replace another ether, in addition to those already replaced.
+    pub fn with_ether<T: Clone + 'static>(mut self, item: T) -> Self {
+        self.ethers.ether(item);
+        self
+    }
+
+    /// Give me what I want using the replaced ethers, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        self.tome.summon_over::<T>(&self.ethers)
+    }
+
+    /// Give me what I want using the replaced ethers, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+}
+
+impl Deref for TomeWithEther<'_> {
+    type Target = Tome;
+
+    fn deref(&self) -> &Tome {
+        self.tome
+    }
+}
+
+/// Two independent tomes summoned from as one, made with `Tome::with_fallback`.
+///
+/// The circles of the primary tome are tried before those of the fallback, and either tome can provide the
+/// ingredients of the other's circles.
+pub struct FallbackTome {
+    primary: Tome,
+    fallback: Tome,
+}
+
+impl FallbackTome {
+    /// The tome whose circles are tried first.
+    pub fn primary(&self) -> &Tome {
+        &self.primary
+    }
+
+    /// The tome whose circles are tried when the primary's cannot be used.
+    pub fn fallback(&self) -> &Tome {
+        &self.fallback
+    }
+
+    /// Give me what I want from either tome, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        self.fallback.summon_over::<T>(&self.primary)
+    }
+
+    /// Give me what I want from either tome, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon::<T>().ok()
+    }
+}
+
+impl Tome {
+    /// Combine two tomes, trying the circles of `primary` before those of `fallback`.
+    pub fn with_fallback(primary: Tome, fallback: Tome) -> FallbackTome {
+        FallbackTome { primary, fallback }
+    }
+
+    /// Use `item` in place of any other way of making `T` for the summons made through the returned tome.
+    ///
+    /// The tome itself is left unchanged.
+    pub fn with_ether<T: Clone + 'static>(&self, item: T) -> TomeWithEther<'_> {
+        TomeWithEther {
+            tome: self,
+            ethers: Tome::new(),
+        }
+        .with_ether(item)
+    }
+
+    /// Create a scope which inherits this tome's circles.
+    pub fn scope(&self) -> ScopedTome<'_> {
+        ScopedTome {
+            parent: self,
+            local: Tome::new(),
+        }
+    }
+
+    /// Summon `T` as if the circles inscribed by `inject` were added to the tome, without changing it.
+    ///
+    /// The injected circles are tried before any of the tome's own circles.
+    pub fn summon_with<T: 'static, F: FnOnce(&mut Tome)>(
+        &self,
+        inject: F,
+    ) -> Result<T, SummonError> {
+        let mut overlay = Tome::new();
+        inject(&mut overlay);
+        self.summon_over::<T>(&overlay)
+    }
+
+    /// Summon `T`, trying the circles of `overlay` before the tome's own circles.
+    pub(crate) fn summon_over<T: 'static>(&self, overlay: &Tome) -> Result<T, SummonError> {
+        let id = TypeId::of::<T>();
+        let mut research = Research {
+            overlay: Some(overlay),
+            ..Research::default()
+        };
+        let recipe = self
+            .research_in(id, &mut research)
+            .ok_or_else(|| self.diagnose(id))?;
+        Ok(self.extract(self.perform(recipe)?))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::sync::Arc;
+
+impl Tome {
+    /// Reserve a slot for `T` whose circle can be replaced later with `override_circle`.
+    ///
+    /// The circle in the slot is always tried before any other circles for `T`.
+    pub fn inscribe_overrideable<T: 'static>(
+        &mut self,
+        default_circle: impl Transmutation + 'static,
+    ) {
+        let product = TypeId::of::<T>();
+        assert_eq!(
+            product,
+            default_circle.product(),
+            "circle does not produce the overrideable type"
+        );
+        self.assert_unpinned(product);
+        self.version += 1;
+        self.forget_cached_id(product);
+        self.circles.entry(product).or_default();
+        let default_circle = Arc::from(self.contextualize(Box::new(default_circle)));
+        self.overrides.insert(product, vec![default_circle]);
+    }
+
+    /// Replace the circle in the slot for `T`, keeping the previous one in its history.
+    ///
+    /// Returns `false` if `T` was not inscribed as overrideable.
+    pub fn override_circle<T: 'static>(
+        &mut self,
+        new_circle: impl Transmutation + 'static,
+    ) -> bool {
+        let product = TypeId::of::<T>();
+        assert_eq!(
+            product,
+            new_circle.product(),
+            "circle does not produce the overrideable type"
+        );
+        self.assert_unpinned(product);
+        let new_circle = Arc::from(self.contextualize(Box::new(new_circle)));
+        match self.overrides.get_mut(&product) {
+            Some(history) => {
+                self.version += 1;
+                history.push(new_circle);
+                self.forget_cached_id(product);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restore the circle that was in the slot for `T` before the last override.
+    ///
+    /// Returns `false` if there is no override to undo.
+    pub fn undo_override<T: 'static>(&mut self) -> bool {
+        let product = TypeId::of::<T>();
+        match self.overrides.get_mut(&product) {
+            Some(history) if history.len() > 1 => {
+                self.version += 1;
+                history.pop();
+                self.forget_cached_id(product);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Every circle that has occupied the slot for `T`, starting with the default and ending with the active one.
+    pub fn override_history<T: 'static>(&self) -> Vec<&dyn Transmutation> {
+        self.overrides
+            .get(&TypeId::of::<T>())
+            .map(|history| history.iter().map(|circle| &**circle).collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::shared_tome::SharedCircle;
+use crate::{SharedTome, Transmutation};
+use rayon::prelude::*;
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Converts the product of a circle into one which can be sent between threads.
+pub(crate) type Sendable = fn(Box<dyn Any>) -> Box<dyn Any + Send + Sync>;
+
+/// Materials which can be sent between threads.
+type Products = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+/// Mark a product of type `T` as one which can be sent between threads.
+pub(crate) fn sendable<T: Send + Sync + 'static>(
+    product: Box<dyn Any>,
+) -> Box<dyn Any + Send + Sync> {
+    product
+        .downcast::<T>()
+        .expect("circle produced an incorrect type")
+}
+
+/// A circle along with the plans for each of its ingredients.
+struct Plan<'a> {
+    circle: &'a SharedCircle,
+    ingredients: Vec<Plan<'a>>,
+    /// Every product made while following the plan.
+    products: HashSet<TypeId>,
+}
+
+impl SharedTome {
+    /// Inscribe a circle producing `P` which `summon_parallel` may perform on any thread.
+    pub fn inscribe_parallel<P, C>(&mut self, circle: C)
+    where
+        P: Send + Sync + 'static,
+        C: Transmutation + Send + Sync + 'static,
+    {
+        assert_eq!(
+            TypeId::of::<P>(),
+            circle.product(),
+            "circle does not produce the given type"
+        );
+        self.inscribe_shared(SharedCircle {
+            circle: Arc::new(circle),
+            sendable: Some(sendable::<P>),
+        });
+    }
+
+    /// Summon `T`, producing independent ingredients of each circle on separate threads.
+    ///
+    /// Only ethers and circles inscribed with `inscribe_parallel` are used. Anything needed by more than one
+    /// ingredient of a circle is made first, after which the ingredients are independent and made in parallel.
+    pub fn summon_parallel<T: Send + Sync + 'static>(&self) -> Option<T> {
+        let id = TypeId::of::<T>();
+        let plan = self.plan(id, &mut HashSet::new())?;
+        let mut products = Products::new();
+        self.perform_plan(&plan, &[], &mut products)?;
+        products
+            .remove(&id)
+            .map(|product| *product.downcast::<T>().unwrap())
+    }
+
+    fn plan(&self, id: TypeId, researching: &mut HashSet<TypeId>) -> Option<Plan<'_>> {
+        if !researching.insert(id) {
+            return None;
+        }
+        let plan = self
+            .circles
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter(|shared| shared.sendable.is_some() && !shared.circle.expired())
+            .find_map(|shared| {
+                let ingredients = shared
+                    .circle
+                    .ingredients()
+                    .iter()
+                    .map(|&ingredient| self.plan(ingredient, researching))
+                    .collect::<Option<Vec<_>>>()?;
+                let mut products: HashSet<TypeId> = ingredients
+                    .iter()
+                    .flat_map(|plan| plan.products.iter().copied())
+                    .collect();
+                products.insert(id);
+                Some(Plan {
+                    circle: shared,
+                    ingredients,
+                    products,
+                })
+            });
+        researching.remove(&id);
+        plan
+    }
+
+    /// Perform `plan` into `made`, reusing anything already in `known` or `made`.
+    fn perform_plan(
+        &self,
+        plan: &Plan<'_>,
+        known: &[&Products],
+        made: &mut Products,
+    ) -> Option<()> {
+        let available = |made: &Products, id: &TypeId| {
+            made.contains_key(id) || known.iter().any(|products| products.contains_key(id))
+        };
+        let circle = &plan.circle.circle;
+        if available(made, &circle.product()) {
+            return Some(());
+        }
+        // Make everything needed by more than one ingredient first, so what is left for each is independent.
+        let mut needed_by: HashMap<TypeId, usize> = HashMap::new();
+        for ingredient in &plan.ingredients {
+            for &product in &ingredient.products {
+                *needed_by.entry(product).or_default() += 1;
+            }
+        }
+        for ingredient in &plan.ingredients {
+            for shared in ingredient.subplans() {
+                let product = shared.circle.circle.product();
+                if needed_by[&product] > 1 && !available(made, &product) {
+                    self.perform_plan(shared, known, made)?;
+                }
+            }
+        }
+        if plan.ingredients.len() > 1 {
+            let mut layers = known.to_vec();
+            layers.push(made);
+            let performed: Vec<Products> = plan
+                .ingredients
+                .par_iter()
+                .map(|ingredient| {
+                    let mut products = Products::new();
+                    self.perform_plan(ingredient, &layers, &mut products)?;
+                    Some(products)
+                })
+                .collect::<Option<_>>()?;
+            made.extend(performed.into_iter().flatten());
+        } else {
+            for ingredient in &plan.ingredients {
+                self.perform_plan(ingredient, known, made)?;
+            }
+        }
+        let inputs: Vec<&dyn Any> = circle
+            .ingredients()
+            .iter()
+            .map(|ingredient| {
+                let product = made
+                    .get(ingredient)
+                    .or_else(|| known.iter().find_map(|products| products.get(ingredient)))
+                    .expect("ingredient was performed before its consumer");
+                &**product as &dyn Any
+            })
+            .collect();
+        let product = circle.try_transmute(&inputs).ok()?;
+        let sendable = plan
+            .circle
+            .sendable
+            .expect("plan only uses sendable circles");
+        made.insert(circle.product(), sendable(product));
+        Some(())
+    }
+}
+
+impl Plan<'_> {
+    /// This plan and every plan within it, ingredients before the circles consuming them.
+    fn subplans(&self) -> Vec<&Self> {
+        let mut subplans: Vec<&Self> = self
+            .ingredients
+            .iter()
+            .flat_map(|ingredient| ingredient.subplans())
+            .collect();
+        subplans.push(self);
+        subplans
+    }
+}
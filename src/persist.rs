@@ -0,0 +1,166 @@
+use crate::{Tome, TypeRegistry};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::fmt;
+use std::io::{Read, Write};
+
+/// Converts the ether values of a registered type to and from JSON.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EtherCodec {
+    save: fn(&dyn Any) -> Result<Value, serde_json::Error>,
+    load: fn(&mut Tome, Value) -> Result<(), serde_json::Error>,
+}
+
+fn save<T: Serialize + 'static>(value: &dyn Any) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(
+        value
+            .downcast_ref::<T>()
+            .expect("ether value has an incorrect type"),
+    )
+}
+
+fn load<T: DeserializeOwned + Clone + 'static>(
+    tome: &mut Tome,
+    value: Value,
+) -> Result<(), serde_json::Error> {
+    tome.ether(serde_json::from_value::<T>(value)?);
+    Ok(())
+}
+
+/// The reasons the ether values of a tome could not be saved.
+#[derive(Debug)]
+pub enum SaveError {
+    /// A value could not be serialized or written.
+    Serialize(serde_json::Error),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(e) => write!(f, "failed to save ether: {}", e),
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Serialize(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialize(e)
+    }
+}
+
+/// The reasons saved ether values could not be loaded into a tome.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The saved values could not be read or deserialized.
+    Parse(serde_json::Error),
+    /// A type was saved under a name which is not registered with `register_serde_type`.
+    UnknownType { name: String },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "failed to load ether: {}", e),
+            Self::UnknownType { name } => {
+                write!(f, "failed to load ether: unknown type {:?}", name)
+            }
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            Self::UnknownType { .. } => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl TypeRegistry {
+    /// Name the type `T` and allow its ether values to be saved and loaded under that name.
+    pub fn register_serde<T: Serialize + DeserializeOwned + Clone + 'static>(
+        &mut self,
+        name: impl Into<String>,
+    ) {
+        self.register::<T>(name);
+        self.codecs.insert(
+            TypeId::of::<T>(),
+            EtherCodec {
+                save: save::<T>,
+                load: load::<T>,
+            },
+        );
+    }
+}
+
+impl Tome {
+    /// Name the type `T`, as with `register_type`, and include its ether values in `save_ether` and `load_ether`.
+    pub fn register_serde_type<T: Serialize + DeserializeOwned + Clone + 'static>(
+        &mut self,
+        name: impl Into<String>,
+    ) {
+        self.type_registry.register_serde::<T>(name);
+    }
+
+    /// Write every ether value of the types registered with `register_serde_type` as a JSON object, which maps
+    /// the name of each type to its values in the order they are tried.
+    ///
+    /// Only values inscribed with `ether` are saved, not those made by other circles.
+    pub fn save_ether<W: Write>(&self, writer: W) -> Result<(), SaveError> {
+        let mut saved = Map::new();
+        for (&id, codec) in &self.type_registry.codecs {
+            let values = self
+                .candidates(id)
+                .filter_map(|circle| circle.ether_value())
+                .map(codec.save)
+                .collect::<Result<Vec<Value>, _>>()?;
+            if !values.is_empty() {
+                let name = self
+                    .type_registry
+                    .name_of(id)
+                    .expect("serializable type has no name");
+                saved.insert(name.to_owned(), Value::Array(values));
+            }
+        }
+        serde_json::to_writer(writer, &saved)?;
+        Ok(())
+    }
+
+    /// Read ether values written by `save_ether` and inscribe each of them with `ether`.
+    ///
+    /// Every saved type must be registered with `register_serde_type` under the same name.
+    pub fn load_ether<R: Read>(&mut self, reader: R) -> Result<(), LoadError> {
+        let saved: Map<String, Value> = serde_json::from_reader(reader)?;
+        for (name, values) in saved {
+            let codec = self
+                .type_registry
+                .codecs
+                .iter()
+                .find(|&(&id, _)| self.type_registry.name_of(id) == Some(name.as_str()))
+                .map(|(_, &codec)| codec)
+                .ok_or_else(|| LoadError::UnknownType { name: name.clone() })?;
+            for value in serde_json::from_value::<Vec<Value>>(values)? {
+                (codec.load)(self, value)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Tome;
+use std::marker::PhantomData;
+
+impl Tome {
+    /// Create a note about making `PhantomData<T>` out of the ether, so that circles can take it as an ingredient
+    /// to require the marker `T` without any value behind it.
+    ///
+    /// ```
+    /// use std::marker::PhantomData;
+    /// use summon::{Tome, circle};
+    /// struct Metric;
+    /// #[derive(Clone)]
+    /// struct Meters(f64);
+    /// struct Length(f64);
+    /// let mut tome = Tome::new();
+    /// tome.ether(Meters(2.0));
+    /// tome.inscribe(circle!(|_: &PhantomData<Metric>, m: &Meters| -> Length { Length(m.0) }));
+    /// assert!(tome.summon::<Length>().is_err());
+    /// tome.inscribe_phantom::<Metric>();
+    /// assert_eq!(2.0, tome.summon::<Length>().unwrap().0);
+    /// ```
+    pub fn inscribe_phantom<T: 'static>(&mut self) {
+        self.ether(PhantomData::<T>);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Tome;
+use std::any::{type_name, TypeId};
+
+impl Tome {
+    /// Create a note about how to create something out of the ether, and pin it so that inscribing any other
+    /// circle producing a `T` panics until it is unpinned with `unpin`.
+    ///
+    /// Panics if a circle producing `T` is already inscribed, since it could be used instead of `item`.
+    pub fn pin_ether<T: Clone + 'static>(&mut self, item: T) {
+        let product = TypeId::of::<T>();
+        if self.candidates(product).next().is_some() {
+            panic!(
+                "cannot pin {} because a circle producing it is already inscribed",
+                type_name::<T>()
+            );
+        }
+        self.ether(item);
+        self.pinned.insert(product, type_name::<T>());
+    }
+
+    /// Allow circles producing a `T` to be inscribed again, returning whether it was pinned.
+    pub fn unpin<T: 'static>(&mut self) -> bool {
+        self.pinned.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    /// Panic if the product has been pinned with `pin_ether`.
+    pub(crate) fn assert_unpinned(&self, product: TypeId) {
+        if let Some(name) = self.pinned.get(&product) {
+            panic!(
+                "cannot inscribe a circle producing {} because it is pinned",
+                name
+            );
+        }
+    }
+}
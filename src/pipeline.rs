@@ -0,0 +1,121 @@
+use crate::{Conversion, Tome, Transmutation};
+use std::marker::PhantomData;
+
+/// Builds a chain of circles where each step consumes the product of the step before it.
+///
+/// ```
+/// use summon::{PipelineBuilder, Tome};
+/// #[derive(Clone)]
+/// struct Raw(u32);
+/// #[derive(Clone)]
+/// struct Parsed(u32);
+/// struct Checked(u32);
+/// let mut tome = Tome::new();
+/// tome.ether(Raw(4));
+/// PipelineBuilder::new::<Raw>()
+///     .then(|r: &Raw| Parsed(r.0 + 1))
+///     .then(|p: &Parsed| Checked(p.0 * 2))
+///     .build_into(&mut tome);
+/// assert_eq!(10, tome.summon::<Checked>().unwrap().0);
+/// ```
+///
+/// Each step must consume the product of the previous step:
+///
+/// ```compile_fail
+/// use summon::{PipelineBuilder, Tome};
+/// struct Raw(u32);
+/// struct Parsed(u32);
+/// struct Checked(u32);
+/// PipelineBuilder::new::<Raw>()
+///     .then(|r: &Raw| Parsed(r.0))
+///     .then(|r: &Raw| Checked(r.0))
+///     .build_into(&mut Tome::new());
+/// ```
+pub struct PipelineBuilder<Last = ()> {
+    steps: Vec<Box<dyn Transmutation>>,
+    _phantom: PhantomData<fn() -> Last>,
+}
+
+impl PipelineBuilder {
+    /// Start a pipeline which begins from `A`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<A: 'static>() -> PipelineBuilder<A> {
+        PipelineBuilder {
+            steps: vec![],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Last: 'static> PipelineBuilder<Last> {
+    /// Add a step producing `Next` from the product of the previous step.
+    pub fn then<Next: 'static>(
+        mut self,
+        step: impl Fn(&Last) -> Next + 'static,
+    ) -> PipelineBuilder<Next> {
+        self.steps.push(Box::new(Conversion(step, PhantomData)));
+        PipelineBuilder {
+            steps: self.steps,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Inscribe every step of the pipeline into `tome`.
+    pub fn build_into(self, tome: &mut Tome) {
+        for step in self.steps {
+            tome.inscribe_box(step);
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A list of types where each can be converted into the next with `From`.
+///
+/// This is implemented for tuples of two to eight types.
+pub trait PipelineTypes: sealed::Sealed {
+    #[doc(hidden)]
+    fn inscribe_into(tome: &mut Tome);
+}
+
+macro_rules! pipeline_types_impl {
+    ($($all:ident),+) => {
+        pipeline_types_impl!(@emit [$($all),+] [] $($all),+);
+    };
+    (@emit [$($all:ident),+] [$($bounds:tt)*] $prev:ident, $next:ident $(, $rest:ident)*) => {
+        pipeline_types_impl!(@emit [$($all),+] [$($bounds)* $prev: Clone + 'static, $next: From<$prev> + 'static,] $next $(, $rest)*);
+    };
+    (@emit [$($all:ident),+] [$($bounds:tt)*] $last:ident) => {
+        impl<$($all),+> sealed::Sealed for ($($all,)+) where $($bounds)* {}
+
+        impl<$($all),+> PipelineTypes for ($($all,)+) where $($bounds)* {
+            fn inscribe_into(tome: &mut Tome) {
+                pipeline_types_impl!(@inscribe tome $($all),+);
+            }
+        }
+    };
+    (@inscribe $tome:ident $prev:ident, $next:ident $(, $rest:ident)*) => {
+        $tome.inscribe_forwarding::<$next, $prev>();
+        pipeline_types_impl!(@inscribe $tome $next $(, $rest)*);
+    };
+    (@inscribe $tome:ident $last:ident) => {};
+}
+
+pipeline_types_impl!(A, B);
+pipeline_types_impl!(A, B, C);
+pipeline_types_impl!(A, B, C, D);
+pipeline_types_impl!(A, B, C, D, E);
+pipeline_types_impl!(A, B, C, D, E, G);
+pipeline_types_impl!(A, B, C, D, E, G, H);
+pipeline_types_impl!(A, B, C, D, E, G, H, I);
+
+impl Tome {
+    /// Inscribe a circle converting each type in `Types` into the next with `From`.
+    ///
+    /// For example, `inscribe_piped::<(A, B, C)>()` makes `B` summonable from `A` and `C` from `B`.
+    pub fn inscribe_piped<Types: PipelineTypes>(&mut self) {
+        Types::inscribe_into(self);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{ExplainedStep, Tome, Transmutation};
+use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// What a plan should do when the tome has changed since it was made.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlanMode {
+    /// Panic, since the plan may no longer be valid.
+    Strict,
+    /// Research a new recipe and execute that instead.
+    Lenient,
+}
+
+/// A recipe for `T` which has been researched ahead of time and can be executed many times.
+pub struct SummonPlan<T> {
+    /// Each step by its product and its position among the candidate circles for that product.
+    steps: Vec<(TypeId, usize)>,
+    fingerprint: u64,
+    version: u64,
+    mode: PlanMode,
+    _product: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for SummonPlan<T> {
+    fn clone(&self) -> Self {
+        Self {
+            steps: self.steps.clone(),
+            fingerprint: self.fingerprint,
+            version: self.version,
+            mode: self.mode,
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<T: 'static> SummonPlan<T> {
+    /// Change what happens when the plan is executed against a tome that has changed.
+    pub fn with_mode(mut self, mode: PlanMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Identifies the recipe by the product and ingredients of each of its steps.
+    ///
+    /// Two plans with the same fingerprint perform the same kinds of circles in the same order.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Check whether the tome has changed since the plan was made.
+    pub fn is_stale(&self, tome: &Tome) -> bool {
+        self.version != tome.version
+    }
+
+    /// Perform the planned recipe without researching it again.
+    ///
+    /// Returns `None` if a transmutation fails, declines or has its product rejected.
+    pub fn execute(&self, tome: &Tome) -> Option<T> {
+        if self.is_stale(tome) {
+            match self.mode {
+                PlanMode::Strict => panic!("summon plan is stale since the tome has changed"),
+                PlanMode::Lenient => return tome.summon_opt::<T>(),
+            }
+        }
+        let mut materials = tome.materials();
+        for &(product, index) in &self.steps {
+            let step = tome
+                .candidates(product)
+                .nth(index)
+                .expect("summon plan step was not found");
+            materials.try_apply(step).ok()?;
+        }
+        Some(tome.extract(materials))
+    }
+}
+
+/// The steps which would be performed to summon `T`, planned without performing any of them.
+pub struct RecipePlan<T> {
+    steps: Vec<ExplainedStep>,
+    plan: SummonPlan<T>,
+}
+
+impl<T> Clone for RecipePlan<T> {
+    fn clone(&self) -> Self {
+        Self {
+            steps: self.steps.clone(),
+            plan: self.plan.clone(),
+        }
+    }
+}
+
+impl<T: 'static> RecipePlan<T> {
+    /// The steps of the plan, in the order they would be performed, ending with `T`.
+    pub fn steps(&self) -> &[ExplainedStep] {
+        &self.steps
+    }
+
+    /// Perform the planned steps, or research a new recipe if the tome has changed since planning.
+    pub fn execute(&self, tome: &Tome) -> Option<T> {
+        self.plan.execute(tome)
+    }
+}
+
+impl Tome {
+    /// Plan how `T` would be summoned without performing any transmutations.
+    ///
+    /// The plan can be inspected to decide whether summoning is worthwhile and executed later.
+    pub fn dry_run<T: 'static>(&self) -> Option<RecipePlan<T>> {
+        Some(RecipePlan {
+            steps: self.explain::<T>()?.steps,
+            plan: self.build_summon_plan::<T>()?.with_mode(PlanMode::Lenient),
+        })
+    }
+
+    /// Research a recipe for `T` now so that it can be executed later.
+    pub fn build_summon_plan<T: 'static>(&self) -> Option<SummonPlan<T>> {
+        let recipe = self.research::<T>()?;
+        let steps = recipe
+            .steps
+            .iter()
+            .map(|&step| {
+                let product = step.product();
+                let index = self
+                    .candidates(product)
+                    .position(|circle| std::ptr::eq(circle, step))
+                    .expect("recipe step is not inscribed in the tome");
+                (product, index)
+            })
+            .collect();
+        Some(SummonPlan {
+            steps,
+            fingerprint: fingerprint(&recipe.steps),
+            version: self.version,
+            mode: PlanMode::Strict,
+            _product: PhantomData,
+        })
+    }
+}
+
+/// Hash the product and ingredients of each step of a recipe.
+pub(crate) fn fingerprint(steps: &[&dyn Transmutation]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for step in steps {
+        step.product().hash(&mut hasher);
+        step.ingredients().hash(&mut hasher);
+    }
+    hasher.finish()
+}
@@ -0,0 +1,48 @@
+use crate::{plan, SummonPlan, Tome};
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+
+/// A value computed ahead of time along with the fingerprint of the recipe that produced it.
+#[derive(Clone)]
+pub(crate) struct Precomputed {
+    fingerprint: u64,
+    value: Rc<dyn Fn() -> Box<dyn Any>>,
+}
+
+impl Tome {
+    /// Summon `T` with `value_fn` instead of performing a recipe, as long as the recipe researched for `T`
+    /// still has the same fingerprint as `plan`.
+    pub fn inscribe_precomputed<T: 'static>(
+        &mut self,
+        plan: &SummonPlan<T>,
+        value_fn: impl Fn() -> T + 'static,
+    ) {
+        self.precomputed.insert(
+            TypeId::of::<T>(),
+            Precomputed {
+                fingerprint: plan.fingerprint(),
+                value: Rc::new(move || Box::new(value_fn())),
+            },
+        );
+    }
+
+    /// Summon a clone of `value` instead of performing a recipe, as long as the recipe researched for `T`
+    /// still has the same fingerprint as `plan`.
+    pub fn inject_precomputed<T: Clone + 'static>(&mut self, plan: &SummonPlan<T>, value: T) {
+        self.inscribe_precomputed(plan, move || value.clone());
+    }
+
+    /// The precomputed value of `T`, if there is one and its recipe is still the one researched.
+    pub(crate) fn precomputed<T: 'static>(&self) -> Option<T> {
+        let precomputed = self.precomputed.get(&TypeId::of::<T>())?;
+        let recipe = self.research::<T>()?;
+        if plan::fingerprint(&recipe.steps) != precomputed.fingerprint {
+            return None;
+        }
+        Some(
+            *(precomputed.value)()
+                .downcast::<T>()
+                .expect("precomputed value has an incorrect type"),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use crate::Tome;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+/// Checks a product after it is made.
+type Prescription = Rc<dyn Fn(&dyn Any) -> bool>;
+
+/// The prescriptions for each product.
+pub(crate) type Prescriptions = Rc<HashMap<TypeId, Vec<Prescription>>>;
+
+/// The failure reported when a product is rejected by one of its prescriptions.
+#[derive(Debug)]
+pub(crate) struct Rejected;
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "product was rejected by a prescription")
+    }
+}
+
+impl Error for Rejected {}
+
+/// Whether every prescription for the product accepts it.
+pub(crate) fn accepted(prescriptions: &Prescriptions, id: TypeId, product: &dyn Any) -> bool {
+    prescriptions
+        .get(&id)
+        .into_iter()
+        .flatten()
+        .all(|prescription| prescription(product))
+}
+
+impl Tome {
+    /// Check every `T` made with `predicate`, including those made as ingredients.
+    ///
+    /// A circle whose product is rejected is treated as though it declined, so `summon` tries the next
+    /// alternative, and fails if no alternative is accepted.
+    pub fn prescribe<T: 'static>(&mut self, predicate: impl Fn(&T) -> bool + 'static) {
+        self.forget_cached_id(TypeId::of::<T>());
+        Rc::make_mut(&mut self.prescriptions)
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Rc::new(move |product: &dyn Any| {
+                predicate(
+                    product
+                        .downcast_ref()
+                        .expect("prescription passed an incorrect type"),
+                )
+            }));
+    }
+}
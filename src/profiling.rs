@@ -0,0 +1,48 @@
+use crate::Tome;
+use std::time::{Duration, Instant};
+
+/// The cost of summoning something from scratch, split into planning and execution.
+#[derive(Copy, Clone, Debug)]
+pub struct ColdStartProfile {
+    /// Time spent researching the recipe.
+    pub planning_cost: Duration,
+    /// Time spent performing the transmutations in the recipe.
+    pub execution_cost: Duration,
+    /// The number of steps in the recipe.
+    pub total_steps: usize,
+    /// The number of steps which required no ingredients.
+    pub ether_steps: usize,
+    /// The number of steps which consumed ingredients.
+    pub compute_steps: usize,
+}
+
+impl Tome {
+    /// Summon something once, measuring how long planning and execution took separately.
+    ///
+    /// Returns `None` if `T` cannot be summoned, including when a transmutation fails.
+    pub fn cold_start_cost<T: 'static>(&self) -> Option<ColdStartProfile> {
+        let planning_start = Instant::now();
+        let recipe = self.research::<T>()?;
+        let planning_cost = planning_start.elapsed();
+
+        let total_steps = recipe.steps.len();
+        let ether_steps = recipe
+            .steps
+            .iter()
+            .filter(|step| step.ingredients().is_empty())
+            .count();
+
+        let execution_start = Instant::now();
+        let mut materials = Self::perform_on(self.materials(), recipe).ok()?;
+        drop(materials.take_material::<T>());
+        let execution_cost = execution_start.elapsed();
+
+        Some(ColdStartProfile {
+            planning_cost,
+            execution_cost,
+            total_steps,
+            ether_steps,
+            compute_steps: total_steps - ether_steps,
+        })
+    }
+}
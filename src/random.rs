@@ -0,0 +1,73 @@
+use crate::{Recipe, Research, Tome, Transmutation};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
+
+pub(crate) type WeightedCircles = Vec<(u32, Arc<dyn Transmutation>)>;
+
+/// The source of randomness for weighted circles, which is copied when the tome is cloned.
+#[derive(Default)]
+pub(crate) struct RngCell(Mutex<Option<StdRng>>);
+
+impl Clone for RngCell {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.0.lock().unwrap().clone()))
+    }
+}
+
+impl Tome {
+    /// Inscribe several circles for `T`, one of which is chosen at random in proportion to its weight
+    /// each time `T` is researched.
+    ///
+    /// Only circles whose ingredients can be summoned are chosen between. The other circles for `T` are only
+    /// used when none of these can be.
+    pub fn inscribe_weighted_random<T: 'static>(
+        &mut self,
+        circles: Vec<(u32, Box<dyn Transmutation>)>,
+    ) {
+        let product = TypeId::of::<T>();
+        assert!(
+            circles
+                .iter()
+                .all(|(_, circle)| circle.product() == product),
+            "circle does not produce the weighted type"
+        );
+        self.assert_unpinned(product);
+        self.version += 1;
+        self.forget_cached_id(product);
+        self.circles.entry(product).or_default();
+        let circles: Vec<_> = circles
+            .into_iter()
+            .map(|(weight, circle)| (weight, Arc::from(self.contextualize(circle))))
+            .collect();
+        self.weighted.entry(product).or_default().extend(circles);
+    }
+
+    /// Make the choice between weighted circles reproducible.
+    pub fn seed_rng(&mut self, seed: u64) {
+        *self.rng.0.lock().unwrap() = Some(StdRng::seed_from_u64(seed));
+    }
+
+    pub(crate) fn research_weighted<'a>(
+        &'a self,
+        id: TypeId,
+        research: &mut Research<'a>,
+    ) -> Option<Recipe<'a>> {
+        let (weights, mut recipes): (Vec<u32>, Vec<Recipe>) = self
+            .weighted
+            .get(&id)?
+            .iter()
+            .filter_map(|(weight, circle)| {
+                self.research_circle_in(&**circle, research)
+                    .map(|recipe| (*weight, recipe))
+            })
+            .unzip();
+        // This fails when there are no choices or when all of the weights are zero.
+        let choices = WeightedIndex::new(&weights).ok()?;
+        let mut rng = self.rng.0.lock().unwrap();
+        let choice = choices.sample(rng.get_or_insert_with(StdRng::from_entropy));
+        Some(recipes.swap_remove(choice))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::Tome;
+use std::any::{Any, TypeId};
+use std::cell::OnceCell;
+use std::rc::Rc;
+
+/// Holds the value of a type summoned by reference, once it has been summoned.
+pub(crate) struct RefSlot {
+    cell: Box<dyn Any>,
+    /// Makes an empty cell of the same type.
+    empty: fn() -> Box<dyn Any>,
+}
+
+impl Clone for RefSlot {
+    /// A clone starts out empty, since its circles may change independently.
+    fn clone(&self) -> Self {
+        Self {
+            cell: (self.empty)(),
+            empty: self.empty,
+        }
+    }
+}
+
+fn empty_cell<T: 'static>() -> Box<dyn Any> {
+    Box::new(OnceCell::<T>::new())
+}
+
+impl Tome {
+    /// Allow `T` to be summoned by reference with `summon_ref`.
+    pub fn reserve_ref<T: 'static>(&mut self) {
+        self.refs
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| RefSlot {
+                cell: empty_cell::<T>(),
+                empty: empty_cell::<T>,
+            });
+    }
+
+    /// Borrow `T`, summoning it the first time and keeping it in the tome for every call after that.
+    ///
+    /// Returns `None` if `T` was not reserved with `reserve_ref` or cannot be summoned. The value is kept until
+    /// it is released with `release_ref`, even if the tome changes. A `T` given to `ether_static` is borrowed
+    /// directly instead, without reserving it.
+    pub fn summon_ref<T: 'static>(&self) -> Option<&T> {
+        if let Some(value) = self.summon_static::<T>() {
+            return Some(value);
+        }
+        let cell = self
+            .refs
+            .get(&TypeId::of::<T>())?
+            .cell
+            .downcast_ref::<OnceCell<T>>()
+            .expect("reference slot has an incorrect type");
+        if let Some(value) = cell.get() {
+            return Some(value);
+        }
+        let value = self.summon_opt::<T>()?;
+        Some(cell.get_or_init(|| value))
+    }
+
+    /// Take the value kept for `T` by `summon_ref`, so it is summoned again when it is next borrowed.
+    pub fn release_ref<T: 'static>(&mut self) -> Option<T> {
+        self.refs
+            .get_mut(&TypeId::of::<T>())?
+            .cell
+            .downcast_mut::<OnceCell<T>>()
+            .expect("reference slot has an incorrect type")
+            .take()
+    }
+
+    /// Create a note about a value which lives for the whole program, making `&'static T` summonable.
+    ///
+    /// The reference can also be borrowed with `summon_static` or `summon_ref` without allocating anything.
+    pub fn ether_static<T: ?Sized + 'static>(&mut self, value: &'static T) {
+        self.statics.insert(TypeId::of::<T>(), Rc::new(value));
+        self.ether(value);
+    }
+
+    /// Borrow the value given to `ether_static` for `T`, if there is one.
+    ///
+    /// No recipe is researched, so this never allocates.
+    pub fn summon_static<T: ?Sized + 'static>(&self) -> Option<&'static T> {
+        self.statics.get(&TypeId::of::<T>()).map(|value| {
+            *value
+                .downcast_ref::<&'static T>()
+                .expect("static ether has an incorrect type")
+        })
+    }
+}
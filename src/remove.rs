@@ -0,0 +1,178 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::sync::Arc;
+
+impl Tome {
+    /// Unregister every circle producing a `T`, returning them in the order they were tried.
+    pub fn remove<T: 'static>(&mut self) -> Vec<Arc<dyn Transmutation>> {
+        self.remove_matching::<T, _>(|_| true)
+    }
+
+    /// Unregister every circle producing a `T` which satisfies `predicate`, returning them in the order they were
+    /// tried.
+    ///
+    /// Any manual order given to the circles producing a `T` is forgotten if anything is removed.
+    pub fn remove_matching<T: 'static, F: Fn(&dyn Transmutation) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> Vec<Arc<dyn Transmutation>> {
+        let id = TypeId::of::<T>();
+        let Some(circles) = self.circles.remove(&id) else {
+            return vec![];
+        };
+        let (removed, kept): (Vec<_>, Vec<_>) = circles
+            .into_iter()
+            .partition(|(_, circle)| predicate(&**circle));
+        if !kept.is_empty() {
+            self.circles.insert(id, kept);
+        }
+        if !removed.is_empty() {
+            self.version += 1;
+            self.manually_ordered.remove(&id);
+            self.forget_cached_id(id);
+        }
+        for (_, circle) in &removed {
+            self.notify_removed(&**circle);
+        }
+        removed.into_iter().map(|(_, circle)| circle).collect()
+    }
+
+    /// Unregister every circle producing a `T`, and every circle which consumes a type whose circles were
+    /// unregistered, returning each of those types starting with `T`.
+    ///
+    /// Every kind of circle is unregistered, including overrides, versioned, conditional and weighted circles,
+    /// along with any value given to `ether_static`. A fallback chain is only unregistered once both of its
+    /// circles are. A type which still has other circles is listed but can still be summoned with them.
+    pub fn forget<T: 'static>(&mut self) -> Vec<TypeId> {
+        let id = TypeId::of::<T>();
+        self.statics.remove(&id);
+        self.remove_circles_where(|circle| circle.product() == id);
+        let mut forgotten = vec![id];
+        loop {
+            let removed = self.remove_circles_where(|circle| {
+                circle
+                    .ingredients()
+                    .iter()
+                    .any(|ingredient| forgotten.contains(ingredient))
+            });
+            if removed.is_empty() {
+                break;
+            }
+            for product in removed {
+                if !forgotten.contains(&product) {
+                    forgotten.push(product);
+                }
+            }
+        }
+        self.forget_cached_id(id);
+        self.version += 1;
+        forgotten
+    }
+
+    /// Unregister every circle with an ingredient which no circle produces, repeating until every remaining
+    /// ingredient has a circle, and return the product of each circle removed in the order they were removed.
+    ///
+    /// Every kind of circle is swept, including overrides, versioned, conditional and weighted circles. A fallback
+    /// chain is only removed once both of its circles are dead, since the fallback covers a dead primary. Like
+    /// `validate`, this only considers which circles are inscribed, not whether they can be performed.
+    pub fn gc(&mut self) -> Vec<TypeId> {
+        let mut collected = vec![];
+        loop {
+            let live: HashSet<TypeId> = self
+                .inscribed_products()
+                .into_iter()
+                .filter(|&product| self.candidates(product).next().is_some())
+                .collect();
+            let removed = self.remove_circles_where(|circle| {
+                circle
+                    .ingredients()
+                    .iter()
+                    .any(|ingredient| !live.contains(ingredient))
+            });
+            if removed.is_empty() {
+                break;
+            }
+            collected.extend(removed);
+        }
+        collected
+    }
+
+    /// Unregister every circle of any kind which is `dead`, returning the product of each, sorted by product.
+    ///
+    /// A fallback chain is only unregistered once both of its circles are dead.
+    fn remove_circles_where(&mut self, dead: impl Fn(&dyn Transmutation) -> bool) -> Vec<TypeId> {
+        let mut removed: Vec<(TypeId, Arc<dyn Transmutation>)> =
+            sweep(&mut self.circles, |(_, circle)| dead(&**circle))
+                .into_iter()
+                .map(|(product, (_, circle))| (product, circle))
+                .collect();
+        removed.extend(sweep(&mut self.overrides, |circle| dead(&**circle)));
+        for (product, chain) in sweep(&mut self.fallback_chains, |chain| {
+            chain.circles().iter().all(|circle| dead(&**circle))
+        }) {
+            removed.extend(chain.circles().map(|circle| (product, circle)));
+        }
+        removed.extend(
+            sweep(&mut self.schema_versions, |(_, circle)| dead(&**circle))
+                .into_iter()
+                .map(|(product, (_, circle))| (product, circle)),
+        );
+        removed.extend(
+            sweep(&mut self.conditionals, |conditional| {
+                dead(&*conditional.circle())
+            })
+            .into_iter()
+            .map(|(product, conditional)| (product, conditional.circle())),
+        );
+        #[cfg(feature = "rand")]
+        removed.extend(
+            sweep(&mut self.weighted, |(_, circle)| dead(&**circle))
+                .into_iter()
+                .map(|(product, (_, circle))| (product, circle)),
+        );
+        #[cfg(feature = "async")]
+        let async_removed = sweep(&mut self.async_circles, |circle| dead(circle));
+        #[cfg(not(feature = "async"))]
+        let async_removed: Vec<(TypeId, Arc<dyn Transmutation>)> = vec![];
+
+        // Sorted by product so the order does not depend on the order of the stores.
+        removed.sort_by_key(|&(product, _)| product);
+        let removed: Vec<(TypeId, &dyn Transmutation)> = removed
+            .iter()
+            .map(|(product, circle)| (*product, &**circle))
+            .chain(
+                async_removed
+                    .iter()
+                    .map(|(product, circle)| (*product, circle as &dyn Transmutation)),
+            )
+            .collect();
+        for &(product, circle) in &removed {
+            self.manually_ordered.remove(&product);
+            self.forget_cached_id(product);
+            self.notify_removed(circle);
+        }
+        if !removed.is_empty() {
+            self.version += 1;
+        }
+        removed.into_iter().map(|(product, _)| product).collect()
+    }
+}
+
+/// Remove every circle in `store` which is `dead`, returning each with its product.
+///
+/// A product only loses its entry when a circle was removed and none are left.
+fn sweep<C>(store: &mut HashMap<TypeId, Vec<C>>, dead: impl Fn(&C) -> bool) -> Vec<(TypeId, C)> {
+    let mut removed = vec![];
+    store.retain(|&product, circles| {
+        if !circles.iter().any(&dead) {
+            return true;
+        }
+        let (swept, kept): (Vec<C>, Vec<C>) = mem::take(circles).into_iter().partition(&dead);
+        *circles = kept;
+        removed.extend(swept.into_iter().map(|circle| (product, circle)));
+        !circles.is_empty()
+    });
+    removed
+}
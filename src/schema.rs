@@ -0,0 +1,73 @@
+use crate::{Tome, Transmutation};
+use std::any::TypeId;
+use std::sync::Arc;
+
+/// Circles for a product along with their schema versions, highest version first.
+pub(crate) type VersionedCircles = Vec<(u64, Arc<dyn Transmutation>)>;
+
+impl Tome {
+    /// Inscribe a circle producing `T` with a schema version attached.
+    ///
+    /// Versioned circles are tried after unversioned ones by `summon`, highest version first.
+    pub fn inscribe_with_schema_version<T: 'static, C: Transmutation + 'static>(
+        &mut self,
+        version: u64,
+        circle: C,
+    ) {
+        let product = TypeId::of::<T>();
+        assert_eq!(
+            product,
+            circle.product(),
+            "versioned circle must produce the versioned type"
+        );
+        self.assert_unpinned(product);
+        self.version += 1;
+        self.forget_cached_id(product);
+        let circle = Arc::from(self.contextualize(Box::new(circle)));
+        let versioned = self.schema_versions.entry(product).or_default();
+        versioned.push((version, circle));
+        versioned.sort_by_key(|&(version, _)| std::cmp::Reverse(version));
+    }
+
+    /// Summon `T` using only a circle with a schema version of at least `min_version`.
+    pub fn summon_versioned<T: 'static>(&self, min_version: u64) -> Option<T> {
+        self.summon_with_schema::<T>(|version| version >= min_version)
+    }
+
+    /// Summon `T` using only a circle with exactly the schema version `exact_version`.
+    pub fn summon_at_version<T: 'static>(&self, exact_version: u64) -> Option<T> {
+        self.summon_with_schema::<T>(|version| version == exact_version)
+    }
+
+    /// The highest schema version of any circle producing `T`.
+    pub fn latest_version<T: 'static>(&self) -> Option<u64> {
+        self.schema_versions
+            .get(&TypeId::of::<T>())?
+            .first()
+            .map(|&(version, _)| version)
+    }
+
+    fn summon_with_schema<T: 'static>(&self, accept: impl Fn(u64) -> bool) -> Option<T> {
+        let id = TypeId::of::<T>();
+        let recipe = self
+            .schema_versions
+            .get(&id)?
+            .iter()
+            .filter(|&&(version, _)| accept(version))
+            .find_map(|(_, circle)| self.research_circle(id, &**circle))?;
+        let materials = Self::perform_on(self.materials(), recipe).ok()?;
+        Some(self.extract(materials))
+    }
+
+    /// The versioned circles producing `id`, highest version first.
+    pub(crate) fn versioned_candidates(
+        &self,
+        id: TypeId,
+    ) -> impl Iterator<Item = &dyn Transmutation> + '_ {
+        self.schema_versions
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .map(|(_, circle)| &**circle)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{circle_address, Materials, SharedTome, SummonError, Transmutation};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A shared circle, which can be performed from any thread.
+type SendCircle = Arc<dyn Transmutation + Send + Sync>;
+
+/// A shared tome which can no longer be changed, along with a recipe for every product which could be summoned
+/// when it was sealed.
+///
+/// Cloning a sealed tome is cheap, since the clones share their recipes, and it can be summoned from
+/// concurrently.
+#[derive(Clone)]
+pub struct SealedTome {
+    recipes: Arc<HashMap<TypeId, Vec<SendCircle>>>,
+}
+
+impl SealedTome {
+    /// Give me what I want using the sealed recipe, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        let target = TypeId::of::<T>();
+        let steps = self
+            .recipes
+            .get(&target)
+            .ok_or(SummonError::NoPossibleRecipe { target })?;
+        let mut materials = Materials::new();
+        for step in steps {
+            materials.try_apply(&**step)?;
+        }
+        Ok(materials.take_material())
+    }
+
+    /// Give me what I want using the sealed recipe, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon().ok()
+    }
+
+    /// Whether a recipe for `T` was found when the tome was sealed.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.recipes.contains_key(&TypeId::of::<T>())
+    }
+}
+
+impl SharedTome {
+    /// Research a recipe for every product once and stop the tome from being changed any further.
+    ///
+    /// Circles which had expired when the tome was sealed are never used, and those which expire later are still
+    /// used.
+    pub fn seal(self) -> SealedTome {
+        let circles: HashMap<*const (), &SendCircle> = self
+            .circles
+            .values()
+            .flatten()
+            .map(|shared| (circle_address(&*shared.circle), &shared.circle))
+            .collect();
+        let recipes = self
+            .circles
+            .keys()
+            .filter_map(|&id| {
+                let recipe = self.research_in(id, &mut Default::default())?;
+                let steps = recipe
+                    .steps()
+                    .iter()
+                    .map(|&step| circles[&circle_address(step)].clone())
+                    .collect();
+                Some((id, steps))
+            })
+            .collect();
+        SealedTome {
+            recipes: Arc::new(recipes),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Tome;
+use std::sync::{Arc, RwLock};
+
+impl Tome {
+    /// Inscribe `initial` as ether behind a lock, summoned as `Arc<RwLock<T>>` so it can be shared and mutated.
+    pub fn inscribe_mutable<T: 'static>(&mut self, initial: T) {
+        self.ether(Arc::new(RwLock::new(initial)));
+    }
+
+    /// Apply `f` to the ether inscribed with `inscribe_mutable`, which is seen by every later summon.
+    ///
+    /// Returns `false` if there is no such ether.
+    pub fn mutate_ether<T: 'static>(&self, f: impl FnOnce(&mut T)) -> bool {
+        match self.summon_opt::<Arc<RwLock<T>>>() {
+            Some(shared) => {
+                f(&mut shared.write().unwrap());
+                true
+            }
+            None => false,
+        }
+    }
+}
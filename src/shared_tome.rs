@@ -0,0 +1,100 @@
+#[cfg(feature = "rayon")]
+use crate::parallel;
+use crate::{Ether, Materials, Recipe, SummonError, Tome, Transmutation};
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A tome whose circles are all `Send + Sync`, so it can be shared between threads and summoned from concurrently.
+///
+/// This supports only plain circles and ethers. Everything else needs a `Tome`.
+#[derive(Clone, Default)]
+pub struct SharedTome {
+    /// Transmutation circles organized by their products, fewest ingredients first.
+    pub(crate) circles: HashMap<TypeId, Vec<SharedCircle>>,
+}
+
+/// A circle in a shared tome.
+#[derive(Clone)]
+pub(crate) struct SharedCircle {
+    pub(crate) circle: Arc<dyn Transmutation + Send + Sync>,
+    /// Present when the product is known to be `Send + Sync`, so the circle can be performed on any thread.
+    #[cfg(feature = "rayon")]
+    pub(crate) sendable: Option<parallel::Sendable>,
+}
+
+impl SharedTome {
+    /// Create an empty tome.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inscribe a note about a natural transmutation into the tome.
+    pub fn inscribe<C: Transmutation + Send + Sync + 'static>(&mut self, circle: C) {
+        self.inscribe_shared(SharedCircle {
+            circle: Arc::new(circle),
+            #[cfg(feature = "rayon")]
+            sendable: None,
+        });
+    }
+
+    pub(crate) fn inscribe_shared(&mut self, shared: SharedCircle) {
+        let circles = self.circles.entry(shared.circle.product()).or_default();
+        circles.push(shared);
+        circles.sort_by_key(|shared| shared.circle.ingredients().len());
+    }
+
+    /// Create a note about how to create something out of the ether.
+    pub fn ether<T: Clone + Send + Sync + 'static>(&mut self, item: T) {
+        self.inscribe_shared(SharedCircle {
+            circle: Arc::new(Ether(item)),
+            #[cfg(feature = "rayon")]
+            sendable: Some(parallel::sendable::<T>),
+        });
+    }
+
+    /// Give me what I want, or tell me why you can't.
+    pub fn summon<T: 'static>(&self) -> Result<T, SummonError> {
+        let target = TypeId::of::<T>();
+        let recipe = self
+            .research_in(target, &mut HashSet::new())
+            .ok_or(SummonError::NoPossibleRecipe { target })?;
+        Ok(Tome::perform_on(Materials::new(), recipe)?.take_material())
+    }
+
+    /// Give me what I want, if you can.
+    pub fn summon_opt<T: 'static>(&self) -> Option<T> {
+        self.summon().ok()
+    }
+
+    pub(crate) fn research_in(
+        &self,
+        id: TypeId,
+        researching: &mut HashSet<TypeId>,
+    ) -> Option<Recipe<'_>> {
+        if !researching.insert(id) {
+            return None;
+        }
+        let recipe = self
+            .circles
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .find_map(|shared| {
+                let circle: &dyn Transmutation = &*shared.circle;
+                if circle.expired() {
+                    return None;
+                }
+                circle
+                    .ingredients()
+                    .iter()
+                    .try_fold(Recipe::default(), |recipe, &ingredient| {
+                        self.research_in(ingredient, researching)
+                            .map(|next| recipe.join(next))
+                    })
+                    .map(|recipe| recipe.join(circle.into()))
+            });
+        researching.remove(&id);
+        recipe
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{PrioritizedCircle, Tome};
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// The circles inscribed in a tome at some point, which can be restored into it later.
+///
+/// Taking a snapshot is cheap, since the circles are shared with the tome rather than copied.
+#[derive(Clone)]
+pub struct TomeSnapshot {
+    circles: HashMap<TypeId, Vec<PrioritizedCircle>>,
+}
+
+impl Tome {
+    /// Remember the circles currently inscribed, so they can be restored with `restore`.
+    pub fn snapshot(&self) -> TomeSnapshot {
+        TomeSnapshot {
+            circles: self.circles.clone(),
+        }
+    }
+
+    /// Replace the inscribed circles with those from a snapshot, forgetting every circle inscribed since.
+    ///
+    /// Only the circles are restored, so any other state of the tome, such as preferences, is kept.
+    pub fn restore(&mut self, snapshot: TomeSnapshot) {
+        self.version += 1;
+        self.circles = snapshot.circles;
+        self.invalidate_cache();
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Conversion, Tome, Transmutation};
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A tuple product whose elements can each be summoned on their own.
+///
+/// This is implemented for tuples of two to eight types.
+pub trait SplitProduct: sealed::Sealed {
+    #[doc(hidden)]
+    fn inscribe_splitters(tome: &mut Tome);
+}
+
+macro_rules! split_product_impl {
+    ($($ty:ident $index:tt),+) => {
+        impl<$($ty: Clone + 'static),+> sealed::Sealed for ($($ty,)+) {}
+
+        impl<$($ty: Clone + 'static),+> SplitProduct for ($($ty,)+) {
+            fn inscribe_splitters(tome: &mut Tome) {
+                $(tome.inscribe(Conversion(|tuple: &Self| -> $ty { tuple.$index.clone() }, PhantomData));)+
+            }
+        }
+    };
+}
+
+split_product_impl!(A 0, B 1);
+split_product_impl!(A 0, B 1, C 2);
+split_product_impl!(A 0, B 1, C 2, D 3);
+split_product_impl!(A 0, B 1, C 2, D 3, E 4);
+split_product_impl!(A 0, B 1, C 2, D 3, E 4, G 5);
+split_product_impl!(A 0, B 1, C 2, D 3, E 4, G 5, H 6);
+split_product_impl!(A 0, B 1, C 2, D 3, E 4, G 5, H 6, I 7);
+
+impl Tome {
+    /// Inscribe a circle producing a tuple, along with circles which split each element out of it.
+    ///
+    /// Every element can then be summoned on its own, performing the circle once per summon.
+    pub fn inscribe_splitting<Tuple: SplitProduct + 'static, C: Transmutation + 'static>(
+        &mut self,
+        circle: C,
+    ) {
+        assert_eq!(
+            TypeId::of::<Tuple>(),
+            circle.product(),
+            "circle does not produce the split tuple"
+        );
+        self.inscribe(circle);
+        Tuple::inscribe_splitters(self);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Tome, Transmutation};
+use std::cmp::Reverse;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Decides the order in which the circles for a product are tried, replacing the default ordering of a tome.
+///
+/// A tome with a strategy tries its circles in exactly this order rather than choosing the cheapest recipe.
+/// Preferences and manual orders still apply on top of it.
+pub trait InscriberStrategy {
+    /// Sort the circles for a product, each along with its priority, into the order they should be tried.
+    fn order(&self, circles: &mut [(i32, Arc<dyn Transmutation>)]);
+}
+
+/// Tries circles with higher priority first, and then those with fewer ingredients, like a tome without a
+/// strategy.
+pub struct MinIngredients;
+
+impl InscriberStrategy for MinIngredients {
+    fn order(&self, circles: &mut [(i32, Arc<dyn Transmutation>)]) {
+        circles.sort_by_key(|(priority, circle)| (Reverse(*priority), circle.ingredients().len()));
+    }
+}
+
+/// Tries circles with higher priority first, and then those with more ingredients.
+pub struct MaxIngredients;
+
+impl InscriberStrategy for MaxIngredients {
+    fn order(&self, circles: &mut [(i32, Arc<dyn Transmutation>)]) {
+        circles.sort_by_key(|(priority, circle)| {
+            (Reverse(*priority), Reverse(circle.ingredients().len()))
+        });
+    }
+}
+
+/// Tries circles with higher priority first, and otherwise in the order they were inscribed.
+pub struct PriorityFirst;
+
+impl InscriberStrategy for PriorityFirst {
+    fn order(&self, circles: &mut [(i32, Arc<dyn Transmutation>)]) {
+        circles.sort_by_key(|&(priority, _)| Reverse(priority));
+    }
+}
+
+impl Tome {
+    /// Create an empty tome which orders the circles for each product with `strategy`.
+    pub fn new_with_strategy(strategy: impl InscriberStrategy + 'static) -> Self {
+        Self {
+            strategy: Some(Rc::new(strategy)),
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::Tome;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+type Callback = Box<dyn Fn(&dyn Any) + Send>;
+
+/// All of the callbacks waiting on products of a tome.
+#[derive(Default)]
+pub(crate) struct Subscriptions {
+    next_id: u64,
+    callbacks: HashMap<TypeId, Vec<(u64, Callback)>>,
+}
+
+/// Keeps a subscription alive. The subscription is cancelled when this is dropped.
+pub struct SubscriptionHandle {
+    subscriptions: Weak<Mutex<Subscriptions>>,
+    product: TypeId,
+    id: u64,
+}
+
+impl SubscriptionHandle {
+    /// Stop receiving summoned products.
+    pub fn cancel(self) {}
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        if let Some(subscriptions) = self.subscriptions.upgrade() {
+            let mut subscriptions = subscriptions.lock().unwrap();
+            if let Some(callbacks) = subscriptions.callbacks.get_mut(&self.product) {
+                callbacks.retain(|&(id, _)| id != self.id);
+            }
+        }
+    }
+}
+
+impl Tome {
+    /// Receive a copy of every `T` that is summoned until the handle is dropped.
+    pub fn subscribe_to_product<T: Clone + 'static>(
+        &mut self,
+        callback: impl Fn(T) + Send + 'static,
+    ) -> SubscriptionHandle {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let id = subscriptions.next_id;
+        subscriptions.next_id += 1;
+        subscriptions
+            .callbacks
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push((
+                id,
+                Box::new(move |product: &dyn Any| {
+                    callback(
+                        product
+                            .downcast_ref::<T>()
+                            .expect("subscriber passed an incorrect type")
+                            .clone(),
+                    )
+                }),
+            ));
+        SubscriptionHandle {
+            subscriptions: Arc::downgrade(&self.subscriptions),
+            product: TypeId::of::<T>(),
+            id,
+        }
+    }
+
+    pub(crate) fn notify_subscribers<T: 'static>(&self, product: &T) {
+        let subscriptions = self.subscriptions.lock().unwrap();
+        if let Some(callbacks) = subscriptions.callbacks.get(&TypeId::of::<T>()) {
+            for (_, callback) in callbacks {
+                callback(product);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Ether, Tome, Transmutation};
+use std::marker::PhantomData;
+
+/// Summons a `Target` with some temporary ethers, made with `Tome::builder`.
+///
+/// The ethers are tried before the tome's own circles and are dropped once the target is summoned.
+pub struct SummonBuilder<'a, Target> {
+    tome: &'a Tome,
+    ethers: Vec<Box<dyn Transmutation>>,
+    target: PhantomData<fn() -> Target>,
+}
+
+impl<Target: 'static> SummonBuilder<'_, Target> {
+    /// Add a temporary ether, in addition to those already added.
+    pub fn with<T: Clone + 'static>(mut self, item: T) -> Self {
+        self.ethers.push(Box::new(Ether(item)));
+        self
+    }
+
+    /// Give me the target using the temporary ethers, if you can.
+    pub fn summon(self) -> Option<Target> {
+        let mut scope = Tome::new();
+        for ether in self.ethers {
+            scope.inscribe_box(ether);
+        }
+        self.tome.summon_over::<Target>(&scope).ok()
+    }
+}
+
+impl Tome {
+    /// Start summoning `Target` with temporary ethers that are never added to the tome.
+    ///
+    /// ```
+    /// use summon::{Tome, circle};
+    /// #[derive(Clone)]
+    /// struct Time(f64);
+    /// #[derive(Clone)]
+    /// struct ConstantAcceleration(f64);
+    /// struct Distance(f64);
+    /// let mut tome = Tome::new();
+    /// tome.inscribe(circle!(ConstantAcceleration(a), Time(t) => Distance(0.5 * a * t.powi(2))));
+    /// let distance = tome
+    ///     .builder::<Distance>()
+    ///     .with(Time(4.0))
+    ///     .with(ConstantAcceleration(3.0))
+    ///     .summon();
+    /// assert_eq!(24.0, distance.unwrap().0);
+    /// assert!(tome.summon::<Distance>().is_err());
+    /// ```
+    pub fn builder<Target: 'static>(&self) -> SummonBuilder<'_, Target> {
+        SummonBuilder {
+            tome: self,
+            ethers: Vec::new(),
+            target: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Tome;
+
+/// A type which knows how to inscribe the circle that builds it.
+///
+/// This is usually derived, in which case the circle builds the struct from its fields.
+///
+/// ```
+/// use summon::{Summonable, Tome};
+/// #[derive(Clone)]
+/// struct Host(&'static str);
+/// #[derive(Clone)]
+/// struct Port(u16);
+/// #[derive(Summonable)]
+/// struct Address {
+///     host: Host,
+///     port: Port,
+/// }
+/// let mut tome = Tome::new();
+/// tome.ether(Host("localhost"));
+/// tome.ether(Port(8080));
+/// Address::inscribe(&mut tome);
+/// let address = tome.summon::<Address>().unwrap();
+/// assert_eq!(("localhost", 8080), (address.host.0, address.port.0));
+/// ```
+pub trait Summonable {
+    /// Inscribe the circle which builds this type.
+    fn inscribe(tome: &mut Tome);
+}
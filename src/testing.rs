@@ -0,0 +1,54 @@
+use crate::Tome;
+use serde::Serialize;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+impl Tome {
+    /// Summon `T` and assert that it is equal to `expected`, describing the whole tome on failure.
+    pub fn produce_all_and_assert<T: PartialEq + Debug + 'static>(&self, expected: T) {
+        match self.summon::<T>() {
+            Ok(actual) => assert!(
+                actual == expected,
+                "summoned {:?} but expected {:?}\n{}",
+                actual,
+                expected,
+                self.summarize()
+            ),
+            Err(error) => panic!(
+                "could not summon {}: {}\n{}",
+                std::any::type_name::<T>(),
+                error,
+                self.summarize()
+            ),
+        }
+    }
+
+    /// Summon `T` and compare its JSON against the golden file at `path`, writing the file if it does not exist.
+    pub fn produce_and_snapshot<T: Serialize + 'static>(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let actual = match self.summon::<T>() {
+            Ok(actual) => {
+                serde_json::to_string_pretty(&actual).expect("failed to serialize snapshot")
+            }
+            Err(error) => panic!(
+                "could not summon {}: {}\n{}",
+                std::any::type_name::<T>(),
+                error,
+                self.summarize()
+            ),
+        };
+        if path.exists() {
+            let expected = fs::read_to_string(path).expect("failed to read snapshot");
+            assert!(
+                actual == expected,
+                "snapshot {} does not match\nsummoned: {}\nexpected: {}",
+                path.display(),
+                actual,
+                expected
+            );
+        } else {
+            fs::write(path, actual).expect("failed to write snapshot");
+        }
+    }
+}
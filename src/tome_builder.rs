@@ -0,0 +1,50 @@
+use crate::{Tome, Transmutation};
+
+/// Builds a tome by chaining calls instead of mutating it.
+///
+/// ```
+/// use summon::{circle, TomeBuilder};
+/// #[derive(Clone)]
+/// struct A(u32);
+/// struct B(u32);
+/// let tome = TomeBuilder::new()
+///     .ether(A(2))
+///     .inscribe(circle!(A(a) => B(a * 3)))
+///     .build();
+/// assert_eq!(6, tome.summon::<B>().unwrap().0);
+/// ```
+#[derive(Default)]
+pub struct TomeBuilder {
+    tome: Tome,
+}
+
+impl TomeBuilder {
+    /// Start building an empty tome.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a note about how to create something out of the ether.
+    pub fn ether<T: Clone + 'static>(mut self, item: T) -> Self {
+        self.tome.ether(item);
+        self
+    }
+
+    /// Inscribe a note about a natural transmutation into the tome.
+    pub fn inscribe<T: Transmutation + 'static>(mut self, circle: T) -> Self {
+        self.tome.inscribe(circle);
+        self
+    }
+
+    /// Finish building the tome.
+    pub fn build(self) -> Tome {
+        self.tome
+    }
+}
+
+impl From<Tome> for TomeBuilder {
+    /// Continue building an existing tome.
+    fn from(tome: Tome) -> Self {
+        Self { tome }
+    }
+}
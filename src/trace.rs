@@ -0,0 +1,133 @@
+use crate::{circle_address, Research, Tome, Transmutation};
+use std::any::TypeId;
+use std::collections::HashSet;
+
+/// Why a circle could not be used while researching a product.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The ingredient could not be summoned.
+    MissingIngredient(TypeId),
+    /// The ingredient was already being researched further up, so using it would be a cycle.
+    Cycle(TypeId),
+    /// The ingredient was nested deeper than the tome's maximum depth.
+    DepthExceeded(TypeId),
+    /// The circle has expired.
+    Expired,
+}
+
+/// What became of a circle which could produce a product.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CircleOutcome {
+    /// The circle is the one used to make the product.
+    Selected,
+    /// The circle could have been used, but another circle was chosen.
+    Passed,
+    /// The circle could not be used.
+    Skipped(SkipReason),
+}
+
+/// A circle considered while researching a product, along with the research of its ingredients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracedCircle {
+    /// The name of the circle, if it has one.
+    pub name: Option<&'static str>,
+    /// The ingredients the circle consumes.
+    pub ingredients: Vec<TypeId>,
+    /// Whether the circle was used, and why not if it could not be.
+    pub outcome: CircleOutcome,
+    /// The research of each ingredient, in order, up to the first which could not be summoned.
+    pub ingredient_traces: Vec<SummonTrace>,
+}
+
+/// The decisions made while researching a product, made with `Tome::trace_summon`.
+///
+/// Each circle which could produce the product is listed in the order it was tried, and each of its ingredients
+/// is traced in the same way, mirroring the research itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummonTrace {
+    /// The product being researched.
+    pub product: TypeId,
+    /// Every circle which could produce the product, in the order they were tried.
+    pub alternatives: Vec<TracedCircle>,
+}
+
+impl SummonTrace {
+    /// The circle used to make the product, if it can be made.
+    pub fn selected(&self) -> Option<&TracedCircle> {
+        self.alternatives
+            .iter()
+            .find(|circle| circle.outcome == CircleOutcome::Selected)
+    }
+}
+
+impl Tome {
+    /// Trace how `T` would be researched, recording every circle tried for it and its ingredients, and why each
+    /// one was used, passed over or skipped.
+    ///
+    /// Nothing is performed.
+    pub fn trace_summon<T: 'static>(&self) -> SummonTrace {
+        self.trace_in(TypeId::of::<T>(), &mut HashSet::new())
+    }
+
+    fn trace_in(&self, id: TypeId, researching: &mut HashSet<TypeId>) -> SummonTrace {
+        let selected = {
+            let mut research = Research {
+                researching: researching.clone(),
+                ..Research::default()
+            };
+            self.research_in(id, &mut research).map(|recipe| {
+                circle_address(*recipe.steps().last().expect("a recipe always has a step"))
+            })
+        };
+        researching.insert(id);
+        let alternatives = self
+            .candidates(id)
+            .map(|circle| self.trace_circle(circle, selected, researching))
+            .collect();
+        researching.remove(&id);
+        SummonTrace {
+            product: id,
+            alternatives,
+        }
+    }
+
+    fn trace_circle(
+        &self,
+        circle: &dyn Transmutation,
+        selected: Option<*const ()>,
+        researching: &mut HashSet<TypeId>,
+    ) -> TracedCircle {
+        let mut ingredient_traces = vec![];
+        let outcome = if circle.expired() {
+            CircleOutcome::Skipped(SkipReason::Expired)
+        } else {
+            let skipped = circle.ingredients().iter().find_map(|&ingredient| {
+                if researching.contains(&ingredient) {
+                    return Some(SkipReason::Cycle(ingredient));
+                }
+                if researching.len() >= self.max_depth() {
+                    return Some(SkipReason::DepthExceeded(ingredient));
+                }
+                let trace = self.trace_in(ingredient, researching);
+                let missing = trace.selected().is_none();
+                ingredient_traces.push(trace);
+                if missing {
+                    Some(SkipReason::MissingIngredient(ingredient))
+                } else {
+                    None
+                }
+            });
+            match skipped {
+                Some(reason) => CircleOutcome::Skipped(reason),
+                None if selected == Some(circle_address(circle)) => CircleOutcome::Selected,
+                None => CircleOutcome::Passed,
+            }
+        };
+        TracedCircle {
+            name: circle.name(),
+            ingredients: circle.ingredients().to_vec(),
+            outcome,
+            ingredient_traces,
+        }
+    }
+}
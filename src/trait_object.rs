@@ -0,0 +1,40 @@
+use crate::{SummonError, Tome};
+
+/// Create a circle producing a boxed trait object, like the closure form of `circle!`.
+///
+/// The product is `Box<dyn Trait>`, so the body may return any boxed implementation of the trait.
+///
+/// ```
+/// use summon::{Tome, circle_trait};
+/// trait Animal {
+///     fn legs(&self) -> u32;
+/// }
+/// struct Dog;
+/// impl Animal for Dog {
+///     fn legs(&self) -> u32 {
+///         4
+///     }
+/// }
+/// #[derive(Clone)]
+/// struct Config;
+/// let mut tome = Tome::new();
+/// tome.ether(Config);
+/// tome.inscribe(circle_trait!(|_c: &Config| -> dyn Animal { Box::new(Dog) }));
+/// assert_eq!(4, tome.summon_trait::<dyn Animal>().unwrap().legs());
+/// ```
+#[macro_export]
+macro_rules! circle_trait {
+    (|$($arg_name:tt: &$arg_ty:ty),*| -> $trait_ty:ty { $($body:tt)* }) => {{
+        type TraitProduct = Box<$trait_ty>;
+        $crate::transmutation_impl!(($($arg_name in $arg_ty),*) -> TraitProduct { $($body)* })
+    }};
+}
+
+impl Tome {
+    /// Give me a boxed trait object, or tell me why you can't.
+    ///
+    /// This summons `Box<T>`, which circles made with `circle_trait!` produce.
+    pub fn summon_trait<T: ?Sized + 'static>(&self) -> Result<Box<T>, SummonError> {
+        self.summon::<Box<T>>()
+    }
+}
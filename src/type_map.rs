@@ -0,0 +1,59 @@
+use crate::{Recipe, SummonError, Tome};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+/// Summoned materials of many different types, made with `Tome::summon_all_typed`.
+#[derive(Default)]
+pub struct TypeMap(HashMap<TypeId, Box<dyn Any>>);
+
+impl TypeMap {
+    /// Get the material of type `T`, if it was summoned.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|material| material.downcast_ref())
+    }
+
+    /// Take out the material of type `T`, if it was summoned.
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.0
+            .remove(&TypeId::of::<T>())
+            .and_then(|material| material.downcast().ok())
+            .map(|material| *material)
+    }
+
+    /// The number of materials in the map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the map has no materials.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Tome {
+    /// Summon every one of `types` at once, or tell me why one of them can't be summoned.
+    ///
+    /// The recipes for all of the types are joined before anything is performed, so an ingredient shared between
+    /// them is only made once.
+    pub fn summon_all_typed(
+        &self,
+        types: impl IntoIterator<Item = TypeId>,
+    ) -> Result<TypeMap, SummonError> {
+        let types: Vec<TypeId> = types.into_iter().collect();
+        let mut recipe = Recipe::default();
+        for &id in &types {
+            let researched = self.research_id(id).ok_or_else(|| self.diagnose(id))?;
+            recipe = recipe.join(researched);
+        }
+        let mut materials = self.perform(recipe)?;
+        Ok(TypeMap(
+            types
+                .into_iter()
+                .filter_map(|id| materials.take_id(id).map(|material| (id, material)))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{SummonError, Tome};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Human readable names for types, used in place of their `TypeId` when describing them.
+///
+/// A registry can be used on its own, or a tome can keep one with `Tome::register_type`.
+#[derive(Clone, Debug, Default)]
+pub struct TypeRegistry {
+    names: HashMap<TypeId, String>,
+    /// Saves and loads the ether values of types registered with `register_serde`.
+    #[cfg(feature = "serde")]
+    pub(crate) codecs: HashMap<TypeId, crate::persist::EtherCodec>,
+}
+
+impl TypeRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name the type `T`, replacing any name it already had.
+    pub fn register<T: 'static>(&mut self, name: impl Into<String>) {
+        self.names.insert(TypeId::of::<T>(), name.into());
+    }
+
+    /// The name registered for a type, if it has one.
+    pub fn name_of(&self, id: TypeId) -> Option<&str> {
+        self.names.get(&id).map(String::as_str)
+    }
+
+    /// The name registered for a type, or its `TypeId` if it has none.
+    pub fn describe(&self, id: TypeId) -> String {
+        self.name_of(id)
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("{:?}", id))
+    }
+
+    /// Display an error with the names from this registry in place of `TypeId`s.
+    pub fn display_error<'a>(&'a self, error: &'a SummonError) -> impl fmt::Display + 'a {
+        struct Named<'a>(&'a TypeRegistry, &'a SummonError);
+
+        impl fmt::Display for Named<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.1.write_with(f, |id| self.0.describe(id))
+            }
+        }
+
+        Named(self, error)
+    }
+}
+
+impl Tome {
+    /// Name the type `T` when describing the tome, such as in `to_dot` and `explain`.
+    pub fn register_type<T: 'static>(&mut self, name: impl Into<String>) {
+        self.type_registry.register::<T>(name);
+    }
+
+    /// The names registered for types with `register_type`.
+    pub fn type_registry(&self) -> &TypeRegistry {
+        &self.type_registry
+    }
+}
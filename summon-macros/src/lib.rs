@@ -0,0 +1,227 @@
+//! Procedural macros for [summon](https://docs.rs/summon/), which are re-exported from there.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, FnArg, ItemFn, Path, ReturnType, Type,
+};
+
+/// Generates `inscribe_<name>(tome: &mut Tome)` next to a function, which inscribes the function as a circle
+/// with `Tome::inscribe_debug_only`.
+///
+/// Every parameter of the function must be a reference to an ingredient.
+#[proc_macro_attribute]
+pub fn debug_circle(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new(Span::call_site(), "debug_circle takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let function = parse_macro_input!(item as ItemFn);
+    match debug_circle_impl(&function) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn debug_circle_impl(function: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let visibility = &function.vis;
+    let inscribe = format_ident!("inscribe_{}", function.sig.ident);
+    let (product, circle) = function_circle(function)?;
+
+    Ok(quote! {
+        #function
+
+        /// Inscribe the circle in debug builds only.
+        #visibility fn #inscribe(tome: &mut ::summon::Tome) {
+            #circle
+            tome.inscribe_debug_only::<#product, _>(Circle);
+        }
+    })
+}
+
+/// Inscribes a function as a circle into a global tome before `main` runs.
+///
+/// The argument is a static which dereferences to a `Mutex` of a tome, such as a
+/// `LazyLock<Mutex<SharedTome>>`. Every parameter of the function must be a reference to an ingredient.
+///
+/// The circle is inscribed by placing a function pointer in the platform's static initializer section, which
+/// is unsafe by nature: it runs before `main`, before the standard library guarantees anything is set up, and
+/// in no particular order relative to other initializers, so a panic there aborts the program. This is only
+/// supported on Linux, Android, FreeBSD, NetBSD, macOS, iOS and Windows, and is a compile error on any other
+/// target rather than silently never inscribing the circle.
+#[proc_macro_attribute]
+pub fn inscribe(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let tome = parse_macro_input!(attr as Path);
+    let function = parse_macro_input!(item as ItemFn);
+    match inscribe_impl(&tome, &function) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn inscribe_impl(tome: &Path, function: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let initializer = format_ident!("__summon_inscribe_{}", function.sig.ident);
+    let (_, circle) = function_circle(function)?;
+
+    Ok(quote! {
+        #function
+
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "macos",
+            target_os = "ios",
+            windows
+        )))]
+        ::core::compile_error!("#[summon::inscribe] is not supported on this target");
+
+        #[used]
+        #[allow(non_upper_case_globals)]
+        #[cfg_attr(
+            any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "netbsd"),
+            link_section = ".init_array"
+        )]
+        #[cfg_attr(any(target_os = "macos", target_os = "ios"), link_section = "__DATA,__mod_init_func")]
+        #[cfg_attr(windows, link_section = ".CRT$XCU")]
+        static #initializer: extern "C" fn() = {
+            extern "C" fn #initializer() {
+                #circle
+                #tome
+                    .lock()
+                    .expect("the global tome was poisoned")
+                    .inscribe(Circle);
+            }
+            #initializer
+        };
+    })
+}
+
+/// Finds the product of a function and defines a `Circle` struct which transmutes with it.
+fn function_circle(function: &ItemFn) -> syn::Result<(&Type, proc_macro2::TokenStream)> {
+    let name = &function.sig.ident;
+    let product = match &function.sig.output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => {
+            return Err(Error::new_spanned(
+                &function.sig,
+                "a circle must return its product",
+            ))
+        }
+    };
+    let ingredients = function
+        .sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Typed(typed) => match &*typed.ty {
+                Type::Reference(reference) if reference.mutability.is_none() => {
+                    Ok(&*reference.elem)
+                }
+                ty => Err(Error::new_spanned(
+                    ty,
+                    "circle ingredients must be shared references",
+                )),
+            },
+            FnArg::Receiver(receiver) => {
+                Err(Error::new_spanned(receiver, "a circle cannot take self"))
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let indices = 0..ingredients.len();
+
+    let circle = quote! {
+        use ::std::any::{Any, TypeId};
+        struct Circle;
+        impl ::summon::Transmutation for Circle {
+            fn ingredients(&self) -> &'static [TypeId] {
+                ::summon::ingredient_ids!(#(#ingredients),*)
+            }
+            fn product(&self) -> TypeId {
+                TypeId::of::<#product>()
+            }
+            fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                Box::new(#name(#(
+                    inputs[#indices]
+                        .downcast_ref::<#ingredients>()
+                        .expect("transmute passed an incorrect type")
+                ),*))
+            }
+        }
+    };
+    Ok((product, circle))
+}
+
+/// Implements `Summonable` for a struct, inscribing a circle which builds it from a clone of each of its fields.
+///
+/// Every field type becomes an ingredient, so each must be summonable and `Clone`.
+#[proc_macro_derive(Summonable)]
+pub fn derive_summonable(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match derive_summonable_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn derive_summonable_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "Summonable cannot be derived for generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "Summonable can only be derived for structs",
+            ))
+        }
+    };
+    let ingredients: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let values = ingredients.iter().enumerate().map(|(index, ty)| {
+        quote! {
+            inputs[#index]
+                .downcast_ref::<#ty>()
+                .expect("transmute passed an incorrect type")
+                .clone()
+        }
+    });
+    let construct = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote! { #name { #(#names: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #name(#(#values),*) },
+        Fields::Unit => quote! { #name },
+    };
+
+    Ok(quote! {
+        impl ::summon::Summonable for #name {
+            fn inscribe(tome: &mut ::summon::Tome) {
+                use ::std::any::{Any, TypeId};
+                struct Circle;
+                impl ::summon::Transmutation for Circle {
+                    fn ingredients(&self) -> &'static [TypeId] {
+                        ::summon::ingredient_ids!(#(#ingredients),*)
+                    }
+                    fn product(&self) -> TypeId {
+                        TypeId::of::<#name>()
+                    }
+                    fn transmute(&self, inputs: &[&dyn Any]) -> Box<dyn Any> {
+                        Box::new(#construct)
+                    }
+                }
+                tome.inscribe(Circle);
+            }
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use summon::{circle, Tome, TransmutationBuilder};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Item(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Label(String);
+
+type Items = Vec<Item>;
+type Labels = Vec<Label>;
+
+#[test]
+fn accumulates_every_ether() {
+    let mut tome = Tome::new();
+    tome.ether(Item(1));
+    tome.ether(Item(2));
+    tome.inscribe_accumulator::<Items, Item, _>(
+        TransmutationBuilder::new::<Item>()
+            .require::<Item>()
+            .build(|inputs| inputs[0].downcast_ref::<Item>().unwrap().clone()),
+    );
+    tome.ether(Item(3));
+    assert_eq!(
+        vec![Item(1), Item(2), Item(3)],
+        tome.summon::<Items>().unwrap()
+    );
+}
+
+#[test]
+fn accumulator_transforms_items() {
+    let mut tome = Tome::new();
+    tome.inscribe_accumulator::<Labels, Label, _>(circle!(Item(n) => Label(n.to_string())));
+    assert!(tome.summon::<Labels>().unwrap().is_empty());
+    tome.ether(Item(7));
+    tome.ether(Item(8));
+    assert_eq!(
+        vec![Label("7".to_owned()), Label("8".to_owned())],
+        tome.summon::<Labels>().unwrap()
+    );
+}
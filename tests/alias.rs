@@ -0,0 +1,43 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Meters(f64);
+#[derive(Debug, PartialEq)]
+struct Distance(f64);
+#[derive(Debug, PartialEq)]
+struct Feet(f64);
+struct Trip(f64);
+
+impl From<Meters> for Distance {
+    fn from(meters: Meters) -> Self {
+        Distance(meters.0)
+    }
+}
+
+#[test]
+fn aliases_are_summonable_and_explained() {
+    let mut tome = Tome::new();
+    tome.ether(Meters(3.0));
+    tome.register_alias::<Meters, Distance>();
+    tome.register_coerce::<Meters, Feet>(|m| Feet(m.0 * 3.28084));
+    tome.inscribe(circle!(Distance(d) => Trip(d * 2.0)));
+
+    assert_eq!(Distance(3.0), tome.summon::<Distance>().unwrap());
+    assert_eq!(Feet(3.0 * 3.28084), tome.summon::<Feet>().unwrap());
+    assert_eq!(6.0, tome.summon::<Trip>().unwrap().0);
+    assert_eq!(
+        Some(TypeId::of::<Meters>()),
+        tome.coerced_from::<Distance>()
+    );
+    assert_eq!(None, tome.coerced_from::<Trip>());
+
+    let coercions: Vec<bool> = tome
+        .explain::<Trip>()
+        .unwrap()
+        .steps
+        .iter()
+        .map(|step| step.coercion)
+        .collect();
+    assert_eq!(vec![false, true, false], coercions);
+}
@@ -0,0 +1,44 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Speed(f64);
+#[derive(Clone)]
+struct Time(f64);
+struct Distance;
+#[derive(Debug, PartialEq)]
+struct Score(f64);
+struct Unknown;
+
+#[test]
+fn summon_all_returns_every_path() {
+    let mut tome = Tome::new();
+    tome.ether(Speed(3.0));
+    tome.ether(Time(2.0));
+    tome.inscribe(circle!(Speed(s), Time(t) => Score(s * t)));
+    tome.inscribe(circle!(Speed(s) => Score(*s)));
+    tome.inscribe(circle!(|_u: &Unknown| -> Score { Score(0.0) }));
+    assert_eq!(vec![Score(3.0), Score(6.0)], tome.summon_all::<Score>());
+    assert!(tome.summon_all::<Distance>().is_empty());
+}
+
+static SLOW_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn summon_iter_performs_circles_lazily() {
+    let mut tome = Tome::new();
+    tome.ether(Speed(3.0));
+    tome.ether(Time(2.0));
+    tome.inscribe(circle!(Speed(s) => Score(*s)));
+    tome.inscribe(circle!(|s: &Speed, t: &Time| -> Score {
+        SLOW_RUNS.fetch_add(1, Ordering::SeqCst);
+        Score(s.0 * t.0)
+    }));
+    let mut scores = tome.summon_iter::<Score>();
+    assert_eq!(Some(Score(3.0)), scores.next());
+    assert_eq!(0, SLOW_RUNS.load(Ordering::SeqCst));
+    assert_eq!(Some(Score(6.0)), scores.next());
+    assert_eq!(1, SLOW_RUNS.load(Ordering::SeqCst));
+    assert_eq!(None, scores.next());
+    assert_eq!(0, tome.summon_iter::<Distance>().count());
+}
@@ -0,0 +1,184 @@
+use std::any::{Any, TypeId};
+use summon::{circle, Tome, Transmutation, ValidationError};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct C;
+struct D;
+struct E;
+#[derive(Clone)]
+struct G;
+
+#[test]
+fn find_all_missing_types_reports_each_product() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A, B => D));
+    tome.inscribe(circle!(C => E));
+    tome.inscribe(circle!(A => G));
+    let missing = tome.find_all_missing_types();
+    assert_eq!(2, missing.len());
+    assert_eq!(vec![TypeId::of::<B>()], missing[&TypeId::of::<D>()]);
+    assert_eq!(vec![TypeId::of::<C>()], missing[&TypeId::of::<E>()]);
+    assert!(!tome.is_complete());
+
+    tome.ether(B);
+    tome.ether(C);
+    assert!(tome.find_all_missing_types().is_empty());
+    assert!(tome.is_complete());
+}
+
+#[test]
+fn find_all_missing_types_covers_every_kind_of_circle() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe_with_schema_version::<D, _>(1, circle!(A, B => D));
+    tome.inscribe_fallback_chain::<E, _, _>(circle!(C => E), circle!(B => E));
+    let missing = tome.find_all_missing_types();
+    assert_eq!(vec![TypeId::of::<B>()], missing[&TypeId::of::<D>()]);
+    assert_eq!(
+        vec![TypeId::of::<C>(), TypeId::of::<B>()],
+        missing[&TypeId::of::<E>()]
+    );
+    assert!(!tome.is_complete());
+    assert!(tome.summarize().starts_with("tome with 3 products:"));
+
+    tome.ether(B);
+    assert!(tome.is_complete());
+}
+
+struct Liar;
+
+impl Transmutation for Liar {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<A>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(B)
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        Some(&B)
+    }
+}
+
+#[test]
+fn validate_ingredient_types_detects_malformed_ether() {
+    let mut tome = Tome::new();
+    tome.ether(B);
+    tome.inscribe(circle!(B => D));
+    tome.inscribe(circle!(A => E));
+    assert_eq!(Ok(()), tome.validate_ingredient_types());
+
+    tome.inscribe(Liar);
+    let mismatches = tome.validate_ingredient_types().unwrap_err();
+    assert_eq!(1, mismatches.len());
+    assert_eq!(TypeId::of::<E>(), mismatches[0].product);
+    assert_eq!(TypeId::of::<A>(), mismatches[0].ingredient);
+
+    tome.register_type::<A>("A");
+    let mismatches = tome.validate_ingredient_types().unwrap_err();
+    assert_eq!("A", mismatches[0].expected_type_name);
+}
+
+/// A circle without ingredients which must never be performed.
+struct Offline;
+
+impl Transmutation for Offline {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<A>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        panic!("offline")
+    }
+}
+
+#[test]
+fn validate_ingredient_types_performs_no_transmutation() {
+    let mut tome = Tome::new();
+    tome.inscribe(Offline);
+    tome.inscribe(circle!(A => E));
+    assert_eq!(Ok(()), tome.validate_ingredient_types());
+}
+
+#[test]
+fn validate_reports_unsatisfiable_ingredients_with_their_chain() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A, B => C));
+    tome.inscribe(circle!(C => D));
+    tome.inscribe(circle!(A => E));
+    let errors = tome.validate();
+    assert_eq!(
+        vec![ValidationError {
+            missing: TypeId::of::<B>(),
+            required_by: vec![TypeId::of::<D>(), TypeId::of::<C>()],
+        }],
+        errors
+    );
+
+    tome.inscribe(circle!(G => B));
+    let errors = tome.validate();
+    assert_eq!(1, errors.len());
+    assert_eq!(TypeId::of::<G>(), errors[0].missing);
+    assert_eq!(
+        vec![TypeId::of::<D>(), TypeId::of::<C>(), TypeId::of::<B>()],
+        errors[0].required_by
+    );
+
+    tome.ether(G);
+    assert!(tome.validate().is_empty());
+}
+
+#[test]
+fn verify_completeness_reports_missing_ingredients() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A, B => C));
+    tome.inscribe(circle!(C => D));
+    tome.inscribe(circle!(A => E));
+    assert_eq!(
+        vec![(TypeId::of::<C>(), TypeId::of::<B>())],
+        tome.verify_completeness()
+    );
+
+    tome.ether(B);
+    assert!(tome.verify_completeness().is_empty());
+}
+
+#[test]
+fn debug_and_display_describe_products() {
+    let mut tome = Tome::new();
+    tome.register_type::<A>("A");
+    tome.register_type::<C>("C");
+    tome.ether(A);
+    tome.inscribe(circle!(name = "combine", A, B => C));
+    tome.inscribe(circle!(A => C));
+    let debug = format!("{:?}", tome);
+    assert!(debug.starts_with("Tome { products: 2, circles: {"));
+    assert!(debug.contains(
+        "\"C\": [Circle { name: None, ingredients: 1 }, Circle { name: Some(\"combine\"), ingredients: 2 }]"
+    ));
+    let display = tome.to_string();
+    assert!(display.starts_with("Tome { 2 products: ["));
+    assert!(display.contains("A(0 ingredients)"));
+    assert!(display.contains("C(1 ingredient | \"combine\": 2 ingredients)"));
+}
+
+#[test]
+fn validate_ingredient_types_checks_fallback_chains() {
+    let mut tome = Tome::new();
+    tome.inscribe(Liar);
+    tome.inscribe_fallback_chain::<E, _, _>(circle!(B => E), circle!(A => E));
+    let mismatches = tome.validate_ingredient_types().unwrap_err();
+    assert_eq!(TypeId::of::<E>(), mismatches[0].product);
+    assert_eq!(TypeId::of::<A>(), mismatches[0].ingredient);
+}
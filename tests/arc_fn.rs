@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use summon::{circle_arc, SharedTome, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+#[derive(Debug, PartialEq)]
+struct Area(u32);
+
+type AreaFn = Arc<dyn Fn(&Width, &Height) -> Area + Send + Sync>;
+
+#[test]
+fn shared_function_in_many_tomes() {
+    let scale = Arc::new(10);
+    let captured = scale.clone();
+    let area: AreaFn = Arc::new(move |w, h| Area(w.0 * h.0 * *captured));
+
+    let mut tome = Tome::new();
+    tome.ether(Width(2));
+    tome.ether(Height(3));
+    tome.inscribe(circle_arc!([Width, Height] => Area, area.clone()));
+
+    let mut shared = SharedTome::new();
+    shared.ether(Width(4));
+    shared.ether(Height(5));
+    shared.inscribe(circle_arc!([Width, Height] => Area, area.clone()));
+
+    assert_eq!(Area(60), tome.summon::<Area>().unwrap());
+    assert_eq!(Area(200), shared.summon::<Area>().unwrap());
+    assert_eq!(3, Arc::strong_count(&area));
+    assert_eq!(2, Arc::strong_count(&scale));
+}
@@ -0,0 +1,78 @@
+use summon::{circle, Tome};
+
+macro_rules! ingredients {
+    ($($name:ident = $value:expr),*) => {
+        $(
+            #[derive(Clone)]
+            struct $name(u32);
+        )*
+
+        fn ethers(tome: &mut Tome) {
+            $(tome.ether($name($value));)*
+        }
+    };
+}
+
+ingredients!(
+    I0 = 0,
+    I1 = 1,
+    I2 = 2,
+    I3 = 3,
+    I4 = 4,
+    I5 = 5,
+    I6 = 6,
+    I7 = 7,
+    I8 = 8,
+    I9 = 9,
+    I10 = 10,
+    I11 = 11,
+    I12 = 12,
+    I13 = 13,
+    I14 = 14,
+    I15 = 15
+);
+
+#[derive(Debug, PartialEq)]
+struct Total(u32);
+
+#[test]
+fn sixteen_ingredients() {
+    let mut tome = Tome::new();
+    ethers(&mut tome);
+    tome.inscribe(circle!(|a: &I0,
+                           b: &I1,
+                           c: &I2,
+                           d: &I3,
+                           e: &I4,
+                           f: &I5,
+                           g: &I6,
+                           h: &I7,
+                           i: &I8,
+                           j: &I9,
+                           k: &I10,
+                           l: &I11,
+                           m: &I12,
+                           n: &I13,
+                           o: &I14,
+                           p: &I15|
+     -> Total {
+        Total(
+            a.0 + b.0
+                + c.0
+                + d.0
+                + e.0
+                + f.0
+                + g.0
+                + h.0
+                + i.0
+                + j.0
+                + k.0
+                + l.0
+                + m.0
+                + n.0
+                + o.0
+                + p.0,
+        )
+    }));
+    assert_eq!(Total(120), tome.summon::<Total>().unwrap());
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "async")]
+
+use std::time::Duration;
+use summon::{circle, circle_async, Tome};
+use tokio::time::{sleep, Instant};
+
+#[derive(Clone)]
+struct Id(u32);
+struct Profile(u32);
+struct Orders(u32);
+struct Summary(u32);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Id(7));
+    tome.inscribe_async(circle_async!(async |id: &Id| -> Profile {
+        sleep(Duration::from_millis(50)).await;
+        Profile(id.0 * 10)
+    }));
+    tome.inscribe_async(circle_async!(async |id: &Id| -> Orders {
+        sleep(Duration::from_millis(50)).await;
+        Orders(id.0 + 1)
+    }));
+    tome.inscribe(circle!(Profile(p), Orders(o) => Summary(p + o)));
+    tome
+}
+
+#[tokio::test(start_paused = true)]
+async fn summon_async_awaits_independent_circles_concurrently() {
+    let tome = tome();
+    let start = Instant::now();
+    assert_eq!(78, tome.summon_async::<Summary>().await.unwrap().0);
+    assert_eq!(Duration::from_millis(50), start.elapsed());
+}
+
+#[test]
+fn summon_ignores_async_circles() {
+    let tome = tome();
+    assert!(tome.summon::<Profile>().is_err());
+    assert!(tome.summon::<Summary>().is_err());
+}
+
+struct Rating;
+
+#[tokio::test(start_paused = true)]
+async fn summon_async_returns_none_when_a_circle_fails() {
+    let mut tome = tome();
+    tome.inscribe(circle!(|_s: &Summary| -> Result<Rating, std::fmt::Error> {
+        Err(std::fmt::Error)
+    }));
+    assert!(tome.summon_async::<Rating>().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn summon_async_checks_and_observes_async_products() {
+    let mut tome = tome();
+    let observed = std::rc::Rc::new(std::cell::Cell::new(0));
+    let counter = observed.clone();
+    tome.subscribe::<Profile>(move |profile| counter.set(profile.0));
+    assert_eq!(78, tome.summon_async::<Summary>().await.unwrap().0);
+    assert_eq!(70, observed.get());
+
+    tome.prescribe::<Orders>(|orders| orders.0 > 100);
+    assert!(tome.summon_async::<Summary>().await.is_none());
+}
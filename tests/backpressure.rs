@@ -0,0 +1,55 @@
+#![cfg(feature = "async")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Request;
+struct Started;
+#[derive(Debug, PartialEq)]
+struct Response;
+
+static ACTIVE: AtomicU32 = AtomicU32::new(0);
+static MAX_ACTIVE: AtomicU32 = AtomicU32::new(0);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Request);
+    tome.inscribe(circle!(|_r: &Request| -> Started {
+        let active = ACTIVE.fetch_add(1, Ordering::SeqCst) + 1;
+        MAX_ACTIVE.fetch_max(active, Ordering::SeqCst);
+        Started
+    }));
+    tome.inscribe_with_backpressure::<Response, _>(
+        circle!(|_s: &Started| -> Response {
+            ACTIVE.fetch_sub(1, Ordering::SeqCst);
+            Response
+        }),
+        2,
+    );
+    tome
+}
+
+#[tokio::test]
+async fn backpressure_limits_concurrent_summons() {
+    let tome = tome();
+    let (a, b, c, d) = tokio::join!(
+        tome.summon_async::<Response>(),
+        tome.summon_async::<Response>(),
+        tome.summon_async::<Response>(),
+        tome.summon_async::<Response>(),
+    );
+    assert!([a, b, c, d].iter().all(|r| *r == Some(Response)));
+    assert_eq!(2, MAX_ACTIVE.load(Ordering::SeqCst));
+    assert_eq!(0, ACTIVE.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn non_blocking_summon_fails_when_full() {
+    let mut tome = Tome::new();
+    tome.ether(Request);
+    tome.inscribe_with_backpressure::<Response, _>(circle!(Request => Response), 0);
+    assert_eq!(None, tome.try_summon_async_nb::<Response>().await);
+    tome.inscribe_with_backpressure::<Response, _>(circle!(Request => Response), 1);
+    assert_eq!(Some(Response), tome.try_summon_async_nb::<Response>().await);
+}
@@ -1,6 +1,5 @@
-#![feature(const_type_id)]
-
-use summon::{circle, Tome};
+use std::any::TypeId;
+use summon::{circle, Tome, Transmutation, TransmutationBuilder};
 
 #[derive(Clone)]
 struct A;
@@ -38,9 +37,6 @@ fn sum_circle() {
     tome.ether(InitialVelocity(5.0));
     tome.ether(InitialPosition(6.0));
     tome.ether(Time(4.0));
-    // tome.inscribe(
-    //     bend!((ConstantAcceleration(&a), InitialVelocity(&v), InitialPosition(&p), Time(&t)) -> Distance(0.5 * a * t.powi(2) + v * t + p)),
-    // );
     tome.inscribe(circle!(|a: &ConstantAcceleration,
                            v: &InitialVelocity,
                            p: &InitialPosition,
@@ -54,6 +50,86 @@ fn sum_circle() {
     );
 }
 
+struct TimeSquared(f64);
+struct AccelerationTerm(f64);
+struct VelocityTerm(f64);
+struct PositionTerm(f64);
+
+#[test]
+fn sum_map_ether() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.ether(Time(4.0));
+    tome.map_ether(|t: &Time| TimeSquared(t.0.powi(2)));
+    tome.map_ether(|p: &InitialPosition| PositionTerm(p.0));
+    tome.inscribe(
+        circle!(ConstantAcceleration(a), TimeSquared(t2) => AccelerationTerm(0.5 * a * t2)),
+    );
+    tome.inscribe(circle!(InitialVelocity(v), Time(t) => VelocityTerm(v * t)));
+    tome.inscribe(
+        circle!(AccelerationTerm(a), VelocityTerm(v), PositionTerm(p) => Distance(a + v + p)),
+    );
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        tome.summon::<Distance>().unwrap().0
+    );
+}
+
+#[test]
+fn sum_fn() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.ether(Time(4.0));
+    const INGREDIENTS: &[TypeId] = &[
+        TypeId::of::<ConstantAcceleration>(),
+        TypeId::of::<InitialVelocity>(),
+        TypeId::of::<InitialPosition>(),
+        TypeId::of::<Time>(),
+    ];
+    tome.inscribe_fn(INGREDIENTS, TypeId::of::<Distance>(), |inputs| {
+        let a = inputs[0].downcast_ref::<ConstantAcceleration>().unwrap();
+        let v = inputs[1].downcast_ref::<InitialVelocity>().unwrap();
+        let p = inputs[2].downcast_ref::<InitialPosition>().unwrap();
+        let t = inputs[3].downcast_ref::<Time>().unwrap();
+        Box::new(Distance(0.5 * a.0 * t.0.powi(2) + v.0 * t.0 + p.0))
+    });
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        tome.summon::<Distance>().unwrap().0
+    );
+}
+
+#[test]
+fn sum_builder() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.ether(Time(4.0));
+    tome.inscribe(
+        TransmutationBuilder::new::<Distance>()
+            .require::<ConstantAcceleration>()
+            .require::<InitialVelocity>()
+            .require::<InitialPosition>()
+            .require::<Time>()
+            .build(|inputs| {
+                let a = inputs[0].downcast_ref::<ConstantAcceleration>().unwrap();
+                let v = inputs[1].downcast_ref::<InitialVelocity>().unwrap();
+                let p = inputs[2].downcast_ref::<InitialPosition>().unwrap();
+                let t = inputs[3].downcast_ref::<Time>().unwrap();
+                Distance(0.5 * a.0 * t.0.powi(2) + v.0 * t.0 + p.0)
+            }),
+    );
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        tome.summon::<Distance>().unwrap().0
+    );
+}
+
 #[test]
 fn sum_bend() {
     let mut tome = Tome::new();
@@ -69,3 +145,100 @@ fn sum_bend() {
         tome.summon::<Distance>().unwrap().0
     );
 }
+
+#[test]
+fn sum_destructured() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.ether(Time(4.0));
+    tome.inscribe(circle!(|ConstantAcceleration(a): &ConstantAcceleration,
+                           InitialVelocity(v): &InitialVelocity,
+                           InitialPosition(p): &InitialPosition,
+                           Time(t): &Time|
+     -> Distance {
+        Distance(0.5 * a * t.powi(2) + v * t + p)
+    }));
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        tome.summon::<Distance>().unwrap().0
+    );
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Retries(u32);
+
+#[test]
+fn summon_or_default_prefers_ether() {
+    let mut tome = Tome::new();
+    assert_eq!(Retries(0), tome.summon_or_default::<Retries>());
+    assert!(tome.summon::<Retries>().is_err());
+    tome.ether(Retries(3));
+    assert_eq!(Retries(3), tome.summon_or_default::<Retries>());
+    assert_eq!(Retries(3), tome.summon::<Retries>().unwrap());
+}
+
+#[test]
+fn summon_or_else_only_falls_back_on_failure() {
+    let mut tome = Tome::new();
+    let mut fallbacks = 0;
+    assert_eq!(
+        Retries(5),
+        tome.summon_or_else(|| {
+            fallbacks += 1;
+            Retries(5)
+        })
+    );
+    assert_eq!(1, fallbacks);
+    tome.ether(Retries(3));
+    assert_eq!(
+        Retries(3),
+        tome.summon_or_else(|| {
+            fallbacks += 1;
+            Retries(5)
+        })
+    );
+    assert_eq!(1, fallbacks);
+}
+
+#[test]
+fn summon_into_overwrites_target() {
+    let mut tome = Tome::new();
+    let mut retries = Some(Retries(1));
+    assert!(!tome.summon_into(&mut retries));
+    assert_eq!(Some(Retries(1)), retries);
+    tome.ether(Retries(3));
+    assert!(tome.summon_into(&mut retries));
+    assert_eq!(Some(Retries(3)), retries);
+}
+
+#[derive(Clone)]
+struct RealPhysicsOn;
+#[derive(Clone)]
+struct HighPrecisionMode;
+#[derive(Debug, PartialEq)]
+struct Epsilon(f64);
+
+#[test]
+fn fuse_with_body() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(RealPhysicsOn, HighPrecisionMode => Epsilon { Epsilon(1e-12) }));
+    assert!(tome.summon::<Epsilon>().is_err());
+    tome.ether(RealPhysicsOn);
+    tome.ether(HighPrecisionMode);
+    assert_eq!(Epsilon(1e-12), tome.summon::<Epsilon>().unwrap());
+}
+
+#[test]
+fn builder_shares_identical_ingredients() {
+    let build = || {
+        TransmutationBuilder::new::<C>()
+            .require::<A>()
+            .require::<B>()
+            .build(|_| C)
+    };
+    assert!(std::ptr::eq(build().ingredients(), build().ingredients()));
+    let other = TransmutationBuilder::new::<C>().require::<B>().build(|_| C);
+    assert_eq!(&[TypeId::of::<B>()], other.ingredients());
+}
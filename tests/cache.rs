@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Input(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Expensive(u32);
+
+#[test]
+fn once_then_cache_runs_once() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+    let mut tome = Tome::new();
+    tome.ether(Input(21));
+    tome.inscribe_once_then_cache::<Expensive, _>(circle!(|input: &Input| -> Expensive {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+        Expensive(input.0 * 2)
+    }));
+    for _ in 0..3 {
+        assert_eq!(Expensive(42), tome.summon::<Expensive>().unwrap());
+    }
+    assert_eq!(1, RUNS.load(Ordering::SeqCst));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config(u32);
+
+#[test]
+fn lazy_computed_runs_once_until_reset() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+    let mut tome = Tome::new();
+    assert!(!tome.reset_lazy::<Config>());
+    tome.inscribe_lazy_computed(|| Config(RUNS.fetch_add(1, Ordering::SeqCst)));
+    assert_eq!(0, RUNS.load(Ordering::SeqCst));
+    assert_eq!(Config(0), tome.summon::<Config>().unwrap());
+    assert_eq!(Config(0), tome.summon::<Config>().unwrap());
+    assert_eq!(1, RUNS.load(Ordering::SeqCst));
+    assert!(tome.reset_lazy::<Config>());
+    assert_eq!(Config(1), tome.summon::<Config>().unwrap());
+    assert_eq!(2, RUNS.load(Ordering::SeqCst));
+}
+
+#[derive(Clone)]
+struct Settings(u32);
+struct Port(u32);
+struct Host(u32);
+
+#[test]
+fn ether_lazy_calls_factory_once_for_every_consumer() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+    let mut tome = Tome::new();
+    tome.ether_lazy(|| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        Settings(80)
+    });
+    tome.inscribe(circle!(Settings(s) => Port(*s)));
+    tome.inscribe(circle!(Settings(s) => Host(s + 1)));
+    assert_eq!(0, CALLS.load(Ordering::SeqCst));
+    assert_eq!(80, tome.summon::<Port>().unwrap().0);
+    assert_eq!(81, tome.summon::<Host>().unwrap().0);
+    assert_eq!(1, CALLS.load(Ordering::SeqCst));
+}
+
+#[test]
+fn clone_ether_bypasses_the_circle() {
+    static RUNS: AtomicU32 = AtomicU32::new(0);
+    let mut tome = Tome::new();
+    tome.ether(Input(5));
+    tome.inscribe(circle!(|input: &Input| -> Expensive {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+        Expensive(input.0 * 2)
+    }));
+    assert!(tome.clone_ether::<Config>().is_err());
+    tome.clone_ether::<Expensive>().unwrap();
+    assert_eq!(1, RUNS.load(Ordering::SeqCst));
+    for _ in 0..3 {
+        assert_eq!(Expensive(10), tome.summon::<Expensive>().unwrap());
+    }
+    assert_eq!(1, RUNS.load(Ordering::SeqCst));
+}
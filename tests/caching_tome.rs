@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+use summon::{circle, CachingTome, SharedTome, ThreadSafeCachingTome, Tome};
+
+#[derive(Clone)]
+struct Base(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Doubled(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Tripled(u32);
+#[derive(Clone)]
+struct Missing;
+
+static DOUBLED_RUNS: AtomicU32 = AtomicU32::new(0);
+static TRIPLED_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn caching_tome_summons_once() {
+    let mut tome = Tome::new();
+    tome.ether(Base(21));
+    tome.inscribe(circle!(|b: &Base| -> Doubled {
+        DOUBLED_RUNS.fetch_add(1, Ordering::SeqCst);
+        Doubled(b.0 * 2)
+    }));
+    let caching = CachingTome::new(&tome);
+    assert_eq!(Doubled(42), caching.summon::<Doubled>().unwrap());
+    assert_eq!(Doubled(42), caching.summon::<Doubled>().unwrap());
+    assert_eq!(1, DOUBLED_RUNS.load(Ordering::SeqCst));
+    assert!(caching.summon::<Missing>().is_err());
+
+    caching.invalidate();
+    assert_eq!(Doubled(42), caching.summon::<Doubled>().unwrap());
+    assert_eq!(2, DOUBLED_RUNS.load(Ordering::SeqCst));
+}
+
+#[test]
+fn thread_safe_caching_tome_is_shared_between_threads() {
+    let mut tome = SharedTome::new();
+    tome.ether(Base(2));
+    tome.inscribe(circle!(|b: &Base| -> Tripled {
+        TRIPLED_RUNS.fetch_add(1, Ordering::SeqCst);
+        Tripled(b.0 * 3)
+    }));
+    let caching = ThreadSafeCachingTome::new(&tome);
+    assert_eq!(Tripled(6), caching.summon::<Tripled>().unwrap());
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| assert_eq!(Tripled(6), caching.summon::<Tripled>().unwrap()));
+        }
+    });
+    assert_eq!(1, TRIPLED_RUNS.load(Ordering::SeqCst));
+}
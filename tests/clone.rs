@@ -0,0 +1,44 @@
+use summon::{circle, circle_clone, Tome};
+
+#[derive(Clone)]
+struct Seed;
+#[derive(Debug, PartialEq)]
+struct Plant(&'static str);
+
+#[test]
+fn clones_are_independent() {
+    let mut tome = Tome::new();
+    tome.ether(Seed);
+    let snapshot = tome.clone();
+    tome.inscribe(circle!(|_s: &Seed| -> Plant { Plant("grown") }));
+    assert_eq!(Plant("grown"), tome.summon::<Plant>().unwrap());
+    assert!(snapshot.summon::<Plant>().is_err());
+
+    let mut copy = tome.clone();
+    copy.remove::<Plant>();
+    copy.inscribe(circle!(|_s: &Seed| -> Plant { Plant("patched") }));
+    assert_eq!(Plant("patched"), copy.summon::<Plant>().unwrap());
+    assert_eq!(Plant("grown"), tome.summon::<Plant>().unwrap());
+}
+
+#[derive(Clone)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct Time(f64);
+#[derive(Debug, PartialEq)]
+struct Distance(f64);
+
+#[test]
+fn circle_clone_modifies_its_own_copy() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(Time(4.0));
+    tome.inscribe(circle_clone!(|mut a: ConstantAcceleration,
+                                 t: Time|
+     -> Distance {
+        a.0 *= 2.0;
+        Distance(a.0 * t.0)
+    }));
+    assert_eq!(Distance(24.0), tome.summon::<Distance>().unwrap());
+    assert_eq!(3.0, tome.summon::<ConstantAcceleration>().unwrap().0);
+}
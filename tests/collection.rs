@@ -0,0 +1,53 @@
+use summon::{circle, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Job(u32);
+#[derive(Debug, PartialEq)]
+struct Total(u32);
+
+type Jobs = Vec<Job>;
+
+#[test]
+fn vec_ether_keeps_insertion_order() {
+    let mut tome = Tome::new();
+    tome.inscribe_from_iter_as_vec(vec![Job(1), Job(2)]);
+    tome.inscribe_push_ether(Job(3));
+    assert_eq!(
+        vec![Job(1), Job(2), Job(3)],
+        tome.summon::<Vec<Job>>().unwrap()
+    );
+}
+
+#[test]
+fn vec_ether_push_pop() {
+    let mut tome = Tome::new();
+    assert_eq!(None, tome.inscribe_pop_ether::<Job>());
+    tome.inscribe_push_ether(Job(1));
+    tome.inscribe_push_ether(Job(2));
+    assert_eq!(Some(Job(2)), tome.inscribe_pop_ether::<Job>());
+    assert_eq!(vec![Job(1)], tome.summon::<Vec<Job>>().unwrap());
+}
+
+#[test]
+fn vec_ether_as_ingredient() {
+    let mut tome = Tome::new();
+    tome.inscribe_from_iter_as_vec((1..=4).map(Job));
+    tome.inscribe(circle!(|jobs: &Jobs| -> Total {
+        Total(jobs.iter().map(|job| job.0).sum())
+    }));
+    assert_eq!(Total(10), tome.summon::<Total>().unwrap());
+}
+
+#[test]
+fn ether_push_collects_every_ether() {
+    let mut tome = Tome::new();
+    tome.ether(Job(1));
+    tome.ether_push(Job(2));
+    tome.ether_push(Job(3));
+    tome.ether(Job(4));
+    assert_eq!(
+        vec![Job(1), Job(2), Job(3), Job(4)],
+        tome.summon::<Vec<Job>>().unwrap()
+    );
+    assert_eq!(Job(1), tome.summon::<Job>().unwrap());
+}
@@ -0,0 +1,42 @@
+use summon::{circle, SummonError, Tome};
+
+#[derive(Clone)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct InitialVelocity(f64);
+#[derive(Clone)]
+struct InitialPosition(f64);
+#[derive(Clone)]
+struct Time(f64);
+
+struct Distance(f64);
+struct Missing;
+struct Unreachable;
+
+#[test]
+fn compiled_physics() {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(3.0));
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.ether(Time(4.0));
+    tome.inscribe(circle!(|a: &ConstantAcceleration,
+                           v: &InitialVelocity,
+                           p: &InitialPosition,
+                           t: &Time|
+     -> Distance {
+        Distance(0.5 * a.0 * t.0.powi(2) + v.0 * t.0 + p.0)
+    }));
+    tome.inscribe(circle!(Missing => Unreachable));
+    let compiled = tome.compile();
+    assert!(compiled.contains::<Distance>());
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        compiled.summon::<Distance>().unwrap().0
+    );
+    assert_eq!(4.0, compiled.summon::<Time>().unwrap().0);
+    assert!(matches!(
+        compiled.summon::<Unreachable>(),
+        Err(SummonError::MissingIngredient { .. })
+    ));
+}
@@ -0,0 +1,66 @@
+use std::any::TypeId;
+use summon::{circle, compose, Tome};
+
+#[derive(Clone)]
+struct Mass(f64);
+#[derive(Clone)]
+struct Acceleration(f64);
+#[derive(Clone, Debug, PartialEq)]
+struct Force(f64);
+#[derive(Debug, PartialEq)]
+struct Weight(f64);
+
+fn force(m: &Mass, a: &Acceleration) -> Force {
+    Force(m.0 * a.0)
+}
+
+fn weight(f: &Force) -> Weight {
+    Weight(f.0 / 2.0)
+}
+
+#[test]
+fn inscribe_composed_produces_intermediate_and_final() {
+    let mut tome = Tome::new();
+    tome.ether(Mass(2.0));
+    tome.ether(Acceleration(3.0));
+    tome.inscribe_composed(force, weight);
+    assert_eq!(Force(6.0), tome.summon::<Force>().unwrap());
+    assert_eq!(Weight(3.0), tome.summon::<Weight>().unwrap());
+    assert_eq!(
+        Some(TypeId::of::<Force>()),
+        tome.composed_through::<Weight>()
+    );
+    assert_eq!(None, tome.composed_through::<Force>());
+}
+
+#[test]
+fn compose_macro() {
+    let mut tome = Tome::new();
+    tome.ether(Mass(4.0));
+    tome.ether(Acceleration(1.0));
+    compose!(tome, force: (Mass, Acceleration) -> Force, weight: Force -> Weight);
+    assert_eq!(Weight(2.0), tome.summon::<Weight>().unwrap());
+}
+
+#[test]
+fn composed_circle_matches_separate_circles() {
+    let force_circle = || circle!(|m: &Mass, a: &Acceleration| -> Force { force(m, a) });
+    let weight_circle = || circle!(|f: &Force| -> Weight { weight(f) });
+
+    let mut separate = Tome::new();
+    separate.ether(Mass(3.0));
+    separate.ether(Acceleration(4.0));
+    separate.inscribe(force_circle());
+    separate.inscribe(weight_circle());
+
+    let mut composed = Tome::new();
+    composed.ether(Mass(3.0));
+    composed.ether(Acceleration(4.0));
+    composed.inscribe(Tome::compose(force_circle(), weight_circle()));
+
+    assert_eq!(
+        separate.summon::<Weight>().unwrap(),
+        composed.summon::<Weight>().unwrap()
+    );
+    assert!(composed.summon::<Force>().is_err());
+}
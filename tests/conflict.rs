@@ -0,0 +1,127 @@
+use std::any::TypeId;
+use summon::{circle, SelectionReason, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct Extra;
+struct Route;
+
+#[test]
+fn no_conflict_with_one_recipe() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => Route));
+    tome.inscribe(circle!(B => Route));
+    assert_eq!(None, tome.explain_resolution_conflict::<Route>());
+    assert_eq!(None, tome.explain_resolution_conflict::<A>());
+}
+
+#[test]
+fn conflict_by_ingredient_count() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.ether(Extra);
+    tome.inscribe(circle!(B, Extra => Route));
+    tome.inscribe(circle!(A => Route));
+    let report = tome.explain_resolution_conflict::<Route>().unwrap();
+    assert_eq!(2, report.competing_circles.len());
+    assert_eq!(0, report.selected);
+    assert_eq!(
+        vec![TypeId::of::<A>()],
+        report.competing_circles[report.selected].ingredients
+    );
+    assert_eq!(SelectionReason::ByIngredientCount(1), report.reason);
+}
+
+#[test]
+fn conflict_by_registration_order() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.inscribe(circle!(B => Route));
+    tome.inscribe(circle!(A => Route));
+    let report = tome.explain_resolution_conflict::<Route>().unwrap();
+    assert_eq!(
+        vec![TypeId::of::<B>()],
+        report.competing_circles[report.selected].ingredients
+    );
+    assert_eq!(2, report.competing_circles[report.selected].recipe_steps);
+    assert_eq!(SelectionReason::ByRegistrationOrder, report.reason);
+}
+
+#[test]
+fn conflict_by_priority() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.ether(Extra);
+    tome.inscribe(circle!(A => Route));
+    tome.inscribe(circle!(B, Extra => Route));
+    tome.prefer::<Route, B, A>();
+    let report = tome.explain_resolution_conflict::<Route>().unwrap();
+    assert_eq!(
+        vec![TypeId::of::<B>(), TypeId::of::<Extra>()],
+        report.competing_circles[report.selected].ingredients
+    );
+    assert_eq!(SelectionReason::ByPreference(1), report.reason);
+}
+
+#[test]
+fn conflict_by_inscribed_priority() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.ether(Extra);
+    tome.inscribe(circle!(A => Route));
+    tome.inscribe_with_priority(circle!(B, Extra => Route), 10);
+    let report = tome.explain_resolution_conflict::<Route>().unwrap();
+    assert_eq!(
+        vec![TypeId::of::<B>(), TypeId::of::<Extra>()],
+        report.competing_circles[report.selected].ingredients
+    );
+    assert_eq!(SelectionReason::ByPriority(10), report.reason);
+}
+
+#[test]
+fn conflict_by_cost() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(B => Extra));
+    tome.inscribe(circle!(Extra => Route));
+    tome.inscribe(circle!(A => Route));
+    let report = tome.explain_resolution_conflict::<Route>().unwrap();
+    assert_eq!(1, report.selected);
+    assert_eq!(
+        vec![TypeId::of::<A>()],
+        report.competing_circles[report.selected].ingredients
+    );
+    assert_eq!(2, report.competing_circles[report.selected].recipe_steps);
+    assert_eq!(4, report.competing_circles[0].recipe_steps);
+    assert_eq!(SelectionReason::ByCost(2), report.reason);
+}
+
+#[derive(Clone)]
+struct Time;
+#[derive(Clone)]
+struct Speed;
+struct Distance;
+
+#[test]
+fn report_ambiguities_lists_competing_circles() {
+    let mut tome = Tome::new();
+    tome.ether(Time);
+    tome.ether(Speed);
+    tome.inscribe(circle!(name = "from time", Time => Distance));
+    tome.inscribe(circle!(Time, Speed => Distance));
+    tome.inscribe(circle!(A => Distance));
+    tome.inscribe(circle!(Speed => Route));
+    let reports = tome.report_ambiguities();
+    assert_eq!(1, reports.len());
+    assert_eq!(TypeId::of::<Distance>(), reports[0].product);
+    assert_eq!(vec!["from time", "#2"], reports[0].competing_circles);
+}
@@ -0,0 +1,103 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::rc::Rc;
+use summon::{circle, Context, LoggingContext, MetricsContext, Tome, TracingContext};
+
+#[derive(Clone)]
+struct Mass(f64);
+#[derive(Clone)]
+struct Acceleration(f64);
+struct Force(f64);
+
+fn logger() -> (LoggingContext, Rc<RefCell<Vec<String>>>) {
+    let lines = Rc::new(RefCell::new(vec![]));
+    let sink = lines.clone();
+    (
+        LoggingContext::new(move |line| sink.borrow_mut().push(line.to_owned())),
+        lines,
+    )
+}
+
+#[test]
+fn logging_context_logs_each_transmutation() {
+    let (logging, lines) = logger();
+    let mut tome = Tome::new();
+    tome.with_context(logging);
+    tome.ether(Mass(2.0));
+    tome.ether(Acceleration(3.0));
+    tome.inscribe(circle!(Mass(m), Acceleration(a) => Force(m * a)));
+    assert_eq!(6.0, tome.summon::<Force>().unwrap().0);
+    let lines = lines.borrow();
+    assert_eq!(3, lines.len());
+    assert_eq!(
+        format!("transmuting {:?} from 2 ingredients", TypeId::of::<Force>()),
+        lines[2]
+    );
+}
+
+#[test]
+fn context_only_applies_afterwards_unless_retroactive() {
+    let (logging, lines) = logger();
+    let mut tome = Tome::new();
+    tome.ether(Mass(2.0));
+    tome.with_context(logging);
+    tome.summon::<Mass>().unwrap();
+    assert!(lines.borrow().is_empty());
+
+    let (logging, lines) = logger();
+    tome.with_context_retroactive(logging);
+    tome.summon::<Mass>().unwrap();
+    assert_eq!(1, lines.borrow().len());
+}
+
+#[test]
+fn contexts_compose() {
+    let metrics = MetricsContext::new();
+    let tracing = TracingContext::new();
+    let mut tome = Tome::new();
+    tome.with_context(metrics.clone().and_then(tracing.clone()));
+    tome.ether(Mass(2.0));
+    tome.ether(Acceleration(3.0));
+    tome.inscribe(circle!(Mass(m), Acceleration(a) => Force(m * a)));
+    tome.summon::<Force>().unwrap();
+    tome.summon::<Force>().unwrap();
+    assert_eq!(Some(&2), metrics.counts().get(&TypeId::of::<Force>()));
+    let spans = tracing.spans();
+    assert_eq!(6, spans.len());
+    assert_eq!(TypeId::of::<Force>(), spans[2].product);
+}
+
+struct Weight(f64);
+struct Momentum(f64);
+#[derive(Default)]
+struct Energy(f64);
+struct Velocity;
+
+#[test]
+fn retroactive_context_instruments_every_kind_of_circle() {
+    let mut tome = Tome::new();
+    tome.ether(Mass(2.0));
+    tome.ether(Acceleration(3.0));
+    tome.inscribe_overrideable::<Force>(circle!(Mass(m), Acceleration(a) => Force(m * a)));
+    tome.inscribe_with_schema_version::<Weight, _>(1, circle!(Mass(m) => Weight(m * 9.8)));
+    tome.inscribe_fallback_chain::<Momentum, _, _>(
+        circle!(Velocity => Momentum { Momentum(0.0) }),
+        circle!(Mass(m) => Momentum(*m)),
+    );
+    tome.inscribe_derived_default::<Energy>(&[TypeId::of::<Velocity>()]);
+    let metrics = MetricsContext::new();
+    tome.with_context_retroactive(metrics.clone());
+    assert_eq!(6.0, tome.summon::<Force>().unwrap().0);
+    assert_eq!(19.6, tome.summon::<Weight>().unwrap().0);
+    assert_eq!(2.0, tome.summon::<Momentum>().unwrap().0);
+    assert_eq!(0.0, tome.summon::<Energy>().unwrap().0);
+    let counts = metrics.counts();
+    for product in [
+        TypeId::of::<Force>(),
+        TypeId::of::<Weight>(),
+        TypeId::of::<Momentum>(),
+        TypeId::of::<Energy>(),
+    ] {
+        assert_eq!(Some(&1), counts.get(&product));
+    }
+}
@@ -0,0 +1,31 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Area(u32);
+#[derive(Debug, PartialEq)]
+struct Price(u32);
+
+#[test]
+fn ether_beats_a_longer_recipe() {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    tome.inscribe(circle!(Width(w) => Area(w * w)));
+    tome.ether(Area(100));
+    assert_eq!(Area(100), tome.summon::<Area>().unwrap());
+}
+
+#[test]
+fn expensive_circles_are_avoided() {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    tome.inscribe(circle!(cost = 100, Width(w) => Price(w * 100)));
+    tome.inscribe(circle!(Width(w) => Area(w * w)));
+    tome.inscribe(circle!(Area(a) => Price(*a)));
+    assert_eq!(Price(9), tome.summon::<Price>().unwrap());
+    assert_eq!(3, tome.explain::<Price>().unwrap().steps.len());
+
+    tome.inscribe(circle!(name = "cheap", cost = 1, Width(w) => Price(w * 2)));
+    assert_eq!(Price(6), tome.summon::<Price>().unwrap());
+}
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use summon::{circle, Tome};
+
+struct Checksum(u64);
+
+#[test]
+fn cow_ether_is_never_copied() {
+    let data = vec![7u8; 1 << 20];
+    let address = data.as_ptr();
+    let mut tome = Tome::new();
+    tome.ether_cow(data);
+    tome.inscribe(circle!(|data: &Arc<Vec<u8>>| -> Checksum {
+        Checksum(data.iter().map(|&byte| u64::from(byte)).sum())
+    }));
+    for _ in 0..2 {
+        let summoned = tome.summon_cow::<Vec<u8>>().unwrap();
+        assert!(matches!(summoned, Cow::Borrowed(_)));
+        assert!(std::ptr::eq(address, summoned.as_ptr()));
+        assert!(std::ptr::eq(
+            address,
+            tome.summon::<Arc<Vec<u8>>>().unwrap().as_ptr()
+        ));
+    }
+    assert_eq!(7 << 20, tome.summon::<Checksum>().unwrap().0);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Count(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Double(u32);
+
+#[test]
+fn cow_borrows_ether_and_owns_products() {
+    let mut tome = Tome::new();
+    tome.ether(Count(3));
+    tome.inscribe(circle!(Count(n) => Double(n * 2)));
+    assert!(matches!(
+        tome.summon_cow::<Count>().unwrap(),
+        Cow::Borrowed(Count(3))
+    ));
+    assert_eq!(
+        Cow::<Double>::Owned(Double(6)),
+        tome.summon_cow::<Double>().unwrap()
+    );
+}
+
+#[derive(Clone)]
+struct Seed;
+
+#[test]
+fn cow_checks_prescriptions_and_tells_observers() {
+    let mut tome = Tome::new();
+    tome.ether(Count(3));
+    tome.ether(Seed);
+    tome.inscribe(circle!(Seed => Count { Count(9) }));
+    tome.prescribe(|count: &Count| count.0 > 5);
+    let observed = Rc::new(Cell::new(0));
+    let counter = observed.clone();
+    tome.subscribe(move |_: &Count| counter.set(counter.get() + 1));
+    assert_eq!(
+        Cow::<Count>::Owned(Count(9)),
+        tome.summon_cow::<Count>().unwrap()
+    );
+    assert_eq!(1, observed.get());
+
+    let mut tome = Tome::new();
+    tome.ether(Count(7));
+    tome.prescribe(|count: &Count| count.0 > 5);
+    let counter = observed.clone();
+    tome.subscribe(move |_: &Count| counter.set(counter.get() + 1));
+    assert!(matches!(
+        tome.summon_cow::<Count>().unwrap(),
+        Cow::Borrowed(Count(7))
+    ));
+    assert_eq!(2, observed.get());
+}
+
+#[test]
+fn cow_owns_cached_products() {
+    let mut tome = Tome::new();
+    tome.ether(Count(3));
+    tome.enable_cache();
+    assert_eq!(
+        Cow::<Count>::Owned(Count(3)),
+        tome.summon_cow::<Count>().unwrap()
+    );
+}
@@ -0,0 +1,41 @@
+use summon::{circle, debug_circle, Tome};
+
+#[derive(Clone)]
+struct Seed(u32);
+#[derive(Debug, PartialEq)]
+struct Fixture(u32);
+#[derive(Debug, PartialEq)]
+struct Sample(u32);
+
+#[debug_circle]
+fn sample(seed: &Seed) -> Sample {
+    Sample(seed.0 + 1)
+}
+
+const DEBUG: bool = cfg!(all(debug_assertions, not(feature = "simulate-release")));
+
+#[test]
+fn debug_only_circle_is_absent_in_release() {
+    let mut tome = Tome::new();
+    tome.ether(Seed(3));
+    tome.inscribe_debug_only::<Fixture, _>(circle!(Seed(n) => Fixture(n * 2)));
+    assert_eq!(DEBUG, tome.summon::<Fixture>().is_ok());
+}
+
+#[test]
+fn debug_circle_attribute_inscribes_function() {
+    let mut tome = Tome::new();
+    tome.ether(Seed(3));
+    inscribe_sample(&mut tome);
+    let expected = if DEBUG { Some(Sample(4)) } else { None };
+    assert_eq!(expected, tome.summon_opt::<Sample>());
+    assert_eq!(Sample(1), sample(&Seed(0)));
+}
+
+#[test]
+fn test_only_circle_requires_testing_feature() {
+    let mut tome = Tome::new();
+    tome.ether(Seed(3));
+    tome.inscribe_test_only::<Fixture, _>(circle!(Seed(n) => Fixture(n * 2)));
+    assert_eq!(cfg!(feature = "testing"), tome.summon::<Fixture>().is_ok());
+}
@@ -0,0 +1,48 @@
+use summon::{circle, circle_try, Tome};
+
+#[derive(Clone)]
+struct Flags {
+    precise: bool,
+}
+#[derive(Clone)]
+struct Input(f64);
+#[derive(Debug, PartialEq)]
+struct Output(f64);
+
+fn tome(precise: bool) -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Flags { precise });
+    tome.ether(Input(2.0));
+    tome.inscribe(circle_try!(|flags: &Flags,
+                               input: &Input|
+     -> Option<Output> {
+        if flags.precise {
+            Some(Output(input.0.sqrt()))
+        } else {
+            None
+        }
+    }));
+    tome.inscribe(circle!(|_flags: &Flags, _input: &Input| -> Output {
+        Output(1.4)
+    }));
+    tome
+}
+
+#[test]
+fn declined_circles_fall_back_to_the_next_alternative() {
+    assert_eq!(
+        Output(2.0f64.sqrt()),
+        tome(true).summon::<Output>().unwrap()
+    );
+    assert_eq!(Output(1.4), tome(false).summon::<Output>().unwrap());
+}
+
+#[test]
+fn declining_without_an_alternative_fails() {
+    let mut tome = Tome::new();
+    tome.ether(Flags { precise: false });
+    tome.inscribe(circle_try!(|flags: &Flags| -> Option<Output> {
+        Some(Output(1.0)).filter(|_| flags.precise)
+    }));
+    assert!(tome.summon::<Output>().is_err());
+}
@@ -0,0 +1,77 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Config(u32);
+#[derive(Clone)]
+struct Override;
+#[derive(Debug, Default, PartialEq)]
+struct Threads(u32);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.inscribe_derived_default::<Threads>(&[TypeId::of::<Config>(), TypeId::of::<Override>()]);
+    tome.inscribe(circle!(|c: &Config, _o: &Override| -> Threads {
+        Threads(c.0)
+    }));
+    tome
+}
+
+#[test]
+fn default_fires_without_dependencies() {
+    let mut tome = tome();
+    assert_eq!(Threads(0), tome.summon::<Threads>().unwrap());
+    tome.ether(Config(8));
+    assert_eq!(Threads(0), tome.summon::<Threads>().unwrap());
+}
+
+#[test]
+fn default_is_bypassed_with_dependencies() {
+    let mut tome = tome();
+    tome.ether(Config(8));
+    tome.ether(Override);
+    assert_eq!(Threads(8), tome.summon::<Threads>().unwrap());
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Retries(u32);
+
+impl Default for Retries {
+    fn default() -> Self {
+        DEFAULTS_MADE.fetch_add(1, Ordering::SeqCst);
+        Retries(3)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Expensive(u32);
+
+impl Default for Expensive {
+    fn default() -> Self {
+        LAZY_DEFAULTS_MADE.fetch_add(1, Ordering::SeqCst);
+        Expensive(7)
+    }
+}
+
+static DEFAULTS_MADE: AtomicU32 = AtomicU32::new(0);
+static LAZY_DEFAULTS_MADE: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn inscribe_default_waits_until_summoned() {
+    let mut tome = Tome::new();
+    tome.inscribe_default::<Retries>();
+    assert_eq!(0, DEFAULTS_MADE.load(Ordering::SeqCst));
+    assert_eq!(Retries(3), tome.summon::<Retries>().unwrap());
+    assert_eq!(1, DEFAULTS_MADE.load(Ordering::SeqCst));
+}
+
+#[test]
+fn inscribe_lazy_default_is_made_once() {
+    let mut tome = Tome::new();
+    tome.inscribe_lazy_default::<Expensive>();
+    assert_eq!(0, LAZY_DEFAULTS_MADE.load(Ordering::SeqCst));
+    assert_eq!(Expensive(7), tome.summon::<Expensive>().unwrap());
+    assert_eq!(Expensive(7), tome.summon::<Expensive>().unwrap());
+    assert_eq!(1, LAZY_DEFAULTS_MADE.load(Ordering::SeqCst));
+}
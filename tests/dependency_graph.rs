@@ -0,0 +1,38 @@
+#![cfg(feature = "serde")]
+
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+struct Area(u32);
+
+#[test]
+fn dependency_graph_serializes_to_json() {
+    let mut tome = Tome::new();
+    tome.register_type::<Width>("Width");
+    tome.register_type::<Height>("Height");
+    tome.register_type::<Area>("Area");
+    tome.ether(Width(2));
+    tome.ether(Height(3));
+    tome.inscribe(circle!(name = "area", Width(w), Height(h) => Area(w * h)));
+    let graph = tome.to_dependency_graph();
+    assert_eq!(3, graph.products.len());
+    assert_eq!(6, tome.summon::<Area>().unwrap().0);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+    let area = json["products"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|product| product["product"] == "Area")
+        .unwrap();
+    assert_eq!(1, area["circle_count"]);
+    assert_eq!("area", area["circles"][0]["name"]);
+    assert_eq!(
+        serde_json::json!(["Width", "Height"]),
+        area["circles"][0]["ingredients"]
+    );
+}
@@ -0,0 +1,83 @@
+use std::any::{Any, TypeId};
+use std::marker::PhantomData;
+use summon::{SummonError, Tome, Transmutation};
+
+#[derive(Clone)]
+struct Link<const N: usize>;
+
+/// Produces `Link<TO>` from `Link<FROM>`.
+struct Step<const FROM: usize, const TO: usize>(PhantomData<()>);
+
+impl<const FROM: usize, const TO: usize> Step<FROM, TO> {
+    const INGREDIENTS: &'static [TypeId] = &[TypeId::of::<Link<FROM>>()];
+}
+
+impl<const FROM: usize, const TO: usize> Transmutation for Step<FROM, TO> {
+    fn ingredients(&self) -> &'static [TypeId] {
+        Self::INGREDIENTS
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<Link<TO>>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(Link::<TO>)
+    }
+}
+
+macro_rules! each_digit {
+    (@call $mac:ident ($($prefix:tt)*) $digit:tt) => {
+        $mac!($($prefix)* $digit);
+    };
+    ($mac:ident $($prefix:tt)*) => {
+        each_digit!(@ $mac ($($prefix)*) 0 1 2 3 4 5 6 7 8 9);
+    };
+    (@ $mac:ident $prefix:tt $($digit:tt)*) => {
+        $(each_digit!(@call $mac $prefix $digit);)*
+    };
+}
+
+macro_rules! unit {
+    ($tome:ident $h:tt $t:tt $u:tt) => {
+        $tome.inscribe(Step::<
+            { $h * 100 + $t * 10 + $u },
+            { $h * 100 + $t * 10 + $u + 1 },
+        >(PhantomData));
+    };
+}
+
+macro_rules! ten {
+    ($tome:ident $h:tt $t:tt) => {
+        each_digit!(unit $tome $h $t);
+    };
+}
+
+/// A tome where `Link<300>` is made from `Link<0>` through 300 chained circles.
+fn chain() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Link::<0>);
+    each_digit!(ten tome 0);
+    each_digit!(ten tome 1);
+    each_digit!(ten tome 2);
+    tome
+}
+
+#[test]
+fn deep_recipes_exceed_the_max_depth() {
+    let mut tome = chain();
+    assert_eq!(Tome::DEFAULT_MAX_DEPTH, tome.max_depth());
+    assert!(tome.summon_opt::<Link<300>>().is_none());
+    assert!(tome.summon_opt::<Link<200>>().is_some());
+
+    tome.set_max_depth(200);
+    assert!(tome.summon_opt::<Link<300>>().is_none());
+    match tome.summon::<Link<300>>() {
+        Err(SummonError::DepthExceeded { target, limit }) => {
+            assert_eq!(TypeId::of::<Link<300>>(), target);
+            assert_eq!(200, limit);
+        }
+        _ => panic!("expected the depth to be exceeded"),
+    }
+
+    tome.set_max_depth(400);
+    assert!(tome.summon_opt::<Link<300>>().is_some());
+}
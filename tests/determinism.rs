@@ -0,0 +1,56 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+macro_rules! parts {
+    ($($seed:ident => $part:ident),*) => {
+        $(
+            #[derive(Clone)]
+            struct $seed;
+            #[derive(Clone)]
+            struct $part;
+        )*
+
+        fn tome() -> Tome {
+            let mut tome = Tome::new();
+            $(
+                tome.ether($seed);
+                tome.inscribe(circle!($seed => $part));
+            )*
+            tome.inscribe(circle!($($part),* => Whole));
+            tome
+        }
+
+        fn parts() -> Vec<TypeId> {
+            vec![$(TypeId::of::<$part>()),*]
+        }
+    };
+}
+
+struct Whole;
+
+parts!(
+    S0 => P0, S1 => P1, S2 => P2, S3 => P3, S4 => P4,
+    S5 => P5, S6 => P6, S7 => P7, S8 => P8, S9 => P9
+);
+
+#[test]
+fn steps_are_performed_in_the_same_order_every_time() {
+    let tome = tome();
+    let order = |tome: &Tome| -> Vec<TypeId> {
+        let (_, trace) = tome.summon_explain::<Whole>().unwrap();
+        trace.into_iter().map(|step| step.product).collect()
+    };
+    let expected = order(&tome);
+    let performed_parts: Vec<TypeId> = expected
+        .iter()
+        .copied()
+        .filter(|product| parts().contains(product))
+        .collect();
+    assert_eq!(parts(), performed_parts);
+    for _ in 0..1000 {
+        assert_eq!(expected, order(&tome));
+    }
+    for _ in 0..10 {
+        assert_eq!(expected, order(&self::tome()));
+    }
+}
@@ -0,0 +1,50 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct C;
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(|_a: &A| -> B { B }));
+    tome
+}
+
+#[test]
+fn extra_ether_is_added() {
+    let a = tome();
+    let mut b = tome();
+    b.ether(C);
+    let diff = Tome::diff(&a, &b);
+    assert_eq!(vec![TypeId::of::<C>()], diff.added);
+    assert!(diff.removed.is_empty());
+    assert!(diff.changed.is_empty());
+}
+
+#[test]
+fn extra_circle_is_changed() {
+    let a = tome();
+    let mut b = tome();
+    b.inscribe(circle!(|_c: &C| -> B { B }));
+    let diff = Tome::diff(&a, &b);
+    assert_eq!(vec![TypeId::of::<B>()], diff.changed);
+    assert_eq!(vec![TypeId::of::<B>()], Tome::diff(&b, &a).changed);
+}
+
+#[test]
+fn missing_circle_is_removed() {
+    let diff = Tome::diff(&tome(), &Tome::new());
+    let mut expected = vec![TypeId::of::<A>(), TypeId::of::<B>()];
+    expected.sort();
+    assert_eq!(expected, diff.removed);
+}
+
+#[test]
+fn identical_tomes_are_empty() {
+    assert!(Tome::diff(&tome(), &tome()).is_empty());
+}
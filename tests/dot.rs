@@ -0,0 +1,38 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+struct C;
+
+/// Find the identifier of the node with the given label.
+fn node(dot: &str, label: &str) -> String {
+    let suffix = format!(" [label=\"{}\"];", label);
+    dot.lines()
+        .find_map(|line| line.trim().strip_suffix(suffix.as_str()))
+        .unwrap_or_else(|| panic!("no node labelled {}", label))
+        .to_owned()
+}
+
+#[test]
+fn to_dot_describes_nodes_and_edges() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(name = "combine", A, B => C));
+    tome.inscribe(circle!(name = "again", B, A => C));
+    let dot = tome.to_dot();
+    assert!(dot.starts_with("digraph tome {\n"));
+    assert!(dot.ends_with("}\n"));
+
+    let a = node(&dot, &format!("{:?}", TypeId::of::<A>()));
+    let b = node(&dot, &format!("{:?}", TypeId::of::<B>()));
+    let c = node(&dot, "combine");
+    let edges: Vec<&str> = dot.lines().filter(|line| line.contains("->")).collect();
+    assert_eq!(3, edges.len());
+    for (from, to) in [(&a, &b), (&a, &c), (&b, &c)] {
+        assert!(edges.contains(&format!("    {} -> {};", from, to).as_str()));
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "env")]
+
+use std::env;
+use summon::{circle, InscribeEnvError, Tome};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Port(u16);
+
+impl std::str::FromStr for Port {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Port)
+    }
+}
+
+#[test]
+fn inscribe_from_env_parses_variable() {
+    env::set_var("SUMMON_TEST_PORT", "8080");
+    let mut tome = Tome::new();
+    tome.inscribe_from_env::<Port>("SUMMON_TEST_PORT").unwrap();
+    assert_eq!(Port(8080), tome.summon::<Port>().unwrap());
+}
+
+#[test]
+fn inscribe_from_env_errors() {
+    env::remove_var("SUMMON_TEST_MISSING");
+    env::set_var("SUMMON_TEST_BAD_PORT", "eighty");
+    let mut tome = Tome::new();
+    assert_eq!(
+        Err(InscribeEnvError::Missing {
+            key: "SUMMON_TEST_MISSING".to_owned()
+        }),
+        tome.inscribe_from_env::<Port>("SUMMON_TEST_MISSING")
+    );
+    assert_eq!(
+        Err(InscribeEnvError::ParseError {
+            key: "SUMMON_TEST_BAD_PORT".to_owned(),
+            value: "eighty".to_owned()
+        }),
+        tome.inscribe_from_env::<Port>("SUMMON_TEST_BAD_PORT")
+    );
+    assert!(tome.summon::<Port>().is_err());
+}
+
+#[test]
+fn inscribe_from_env_or_default_falls_back() {
+    env::remove_var("SUMMON_TEST_DEFAULT_PORT");
+    let mut tome = Tome::new();
+    tome.inscribe_from_env_or_default::<Port>("SUMMON_TEST_DEFAULT_PORT")
+        .unwrap();
+    assert_eq!(Port(0), tome.summon::<Port>().unwrap());
+}
+
+macro_rules! parsed_f64 {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Clone)]
+            struct $name(f64);
+
+            impl std::str::FromStr for $name {
+                type Err = std::num::ParseFloatError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map($name)
+                }
+            }
+        )*
+    };
+}
+
+parsed_f64!(ConstantAcceleration, InitialVelocity, Time);
+struct Distance(f64);
+
+#[test]
+fn from_env_feeds_the_physics_summon() {
+    env::set_var("ACCEL", "3.0");
+    let mut tome = Tome::new();
+    tome.from_env::<ConstantAcceleration>("ACCEL").unwrap();
+    tome.ether(Time(4.0));
+    tome.inscribe(circle!(ConstantAcceleration(a), Time(t) => Distance(0.5 * a * t.powi(2))));
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+}
+
+#[test]
+fn load_env_inscribes_every_variable() {
+    env::set_var("SUMMON_TEST_ACCEL", "3.0");
+    env::set_var("SUMMON_TEST_VELOCITY", "5.0");
+    env::set_var("SUMMON_TEST_TIME", "4.0");
+    let mut tome = Tome::new();
+    tome.load_env(&[
+        (
+            "SUMMON_TEST_ACCEL",
+            Tome::inscribe_from_env::<ConstantAcceleration>,
+        ),
+        (
+            "SUMMON_TEST_VELOCITY",
+            Tome::inscribe_from_env::<InitialVelocity>,
+        ),
+        ("SUMMON_TEST_TIME", Tome::inscribe_from_env::<Time>),
+    ])
+    .unwrap();
+    tome.inscribe(circle!(ConstantAcceleration(a), InitialVelocity(v), Time(t) => Distance(0.5 * a * t.powi(2) + v * t)));
+    assert_eq!(44.0, tome.summon::<Distance>().unwrap().0);
+
+    assert_eq!(
+        Err(InscribeEnvError::Missing {
+            key: "SUMMON_TEST_MISSING_ACCEL".to_owned()
+        }),
+        Tome::new().load_env(&[(
+            "SUMMON_TEST_MISSING_ACCEL",
+            Tome::inscribe_from_env::<ConstantAcceleration>
+        )])
+    );
+}
@@ -0,0 +1,94 @@
+use std::any::TypeId;
+use summon::{circle, SummonError, Tome, TransmutationBuilder};
+
+#[derive(Clone, Debug, PartialEq)]
+struct A;
+#[derive(Clone, Debug, PartialEq)]
+struct B;
+#[derive(Clone, Debug, PartialEq)]
+struct C;
+
+#[test]
+fn no_possible_recipe() {
+    let tome = Tome::new();
+    match tome.summon::<A>() {
+        Err(SummonError::NoPossibleRecipe { target }) => assert_eq!(TypeId::of::<A>(), target),
+        other => panic!("expected no recipe, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_ingredient() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(B => C));
+    match tome.summon::<C>() {
+        Err(SummonError::MissingIngredient {
+            required_by,
+            missing,
+        }) => {
+            assert_eq!(TypeId::of::<B>(), required_by);
+            assert_eq!(TypeId::of::<A>(), missing);
+        }
+        other => panic!("expected a missing ingredient, got {:?}", other),
+    }
+}
+
+#[test]
+fn self_cycle_detected() {
+    let mut tome = Tome::new();
+    tome.inscribe(TransmutationBuilder::new::<A>().require::<A>().build(|_| A));
+    match tome.summon::<A>() {
+        Err(SummonError::CycleDetected { participants }) => {
+            assert_eq!(vec![TypeId::of::<A>()], participants)
+        }
+        other => panic!("expected a cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn summon_opt_discards_the_error() {
+    let mut tome = Tome::new();
+    assert_eq!(None, tome.summon_opt::<A>());
+    tome.ether(A);
+    assert_eq!(Some(A), tome.summon_opt::<A>());
+}
+
+#[test]
+fn two_node_cycle_terminates() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(B => A));
+    match tome.summon::<A>() {
+        Err(SummonError::CycleDetected { participants }) => {
+            assert_eq!(vec![TypeId::of::<A>(), TypeId::of::<B>()], participants)
+        }
+        other => panic!("expected a cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn three_node_cycle_terminates() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(B => C));
+    tome.inscribe(circle!(C => A));
+    match tome.summon::<C>() {
+        Err(SummonError::CycleDetected { participants }) => assert_eq!(
+            vec![TypeId::of::<C>(), TypeId::of::<B>(), TypeId::of::<A>()],
+            participants
+        ),
+        other => panic!("expected a cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn cycle_with_an_escape_is_summonable() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(B => A));
+    tome.inscribe(circle!(C => A));
+    tome.ether(C);
+    assert_eq!(B, tome.summon::<B>().unwrap());
+    assert_eq!(A, tome.summon::<A>().unwrap());
+}
@@ -0,0 +1,50 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::rc::Rc;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+struct C;
+
+#[test]
+fn on_inscribe_sees_every_circle_in_order() {
+    let inscribed = Rc::new(RefCell::new(vec![]));
+    let mut tome = Tome::new();
+    let seen = inscribed.clone();
+    tome.on_inscribe(move |product, _| seen.borrow_mut().push(product));
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(A, B => C));
+    tome.ether(B);
+    assert_eq!(
+        vec![
+            TypeId::of::<A>(),
+            TypeId::of::<B>(),
+            TypeId::of::<C>(),
+            TypeId::of::<B>()
+        ],
+        *inscribed.borrow()
+    );
+}
+
+#[test]
+fn on_remove_sees_forgotten_circles() {
+    let removed = Rc::new(RefCell::new(vec![]));
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(A, B => C));
+    let seen = removed.clone();
+    tome.on_remove(move |product, circle| {
+        seen.borrow_mut()
+            .push((product, circle.ingredients().len()))
+    });
+    tome.forget::<B>();
+    assert_eq!(
+        vec![(TypeId::of::<B>(), 1), (TypeId::of::<C>(), 2)],
+        *removed.borrow()
+    );
+}
@@ -0,0 +1,36 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Request;
+#[derive(Debug, PartialEq)]
+struct Token(&'static str);
+
+#[test]
+fn ttl_circle_expires() {
+    let mut tome = Tome::new();
+    tome.ether(Request);
+    tome.inscribe_ttl(
+        circle!(|_r: &Request| -> Token { Token("cached") }),
+        Duration::from_millis(20),
+    );
+    assert_eq!(Token("cached"), tome.summon::<Token>().unwrap());
+    thread::sleep(Duration::from_millis(40));
+    assert!(tome.summon::<Token>().is_err());
+}
+
+#[test]
+fn expired_circle_falls_back() {
+    let mut tome = Tome::new();
+    tome.ether(Request);
+    tome.inscribe_time_limited(
+        circle!(|_r: &Request| -> Token { Token("expired") }),
+        Instant::now(),
+    );
+    tome.inscribe(circle!(|_r: &Request| -> Token { Token("fresh") }));
+    assert_eq!(Token("fresh"), tome.summon::<Token>().unwrap());
+    assert_eq!(1, tome.expire_stale());
+    assert_eq!(0, tome.expire_stale());
+    assert_eq!(Token("fresh"), tome.summon::<Token>().unwrap());
+}
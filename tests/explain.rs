@@ -0,0 +1,91 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, circle_try, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+struct C;
+struct Unknown;
+
+static PERFORMED: AtomicU32 = AtomicU32::new(0);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(|_a: &A| -> B {
+        PERFORMED.fetch_add(1, Ordering::SeqCst);
+        B
+    }));
+    tome.inscribe(circle!(name = "combine", A, B => C));
+    tome
+}
+
+#[test]
+fn explain_lists_steps_without_performing_them() {
+    let tome = tome();
+    let explanation = tome.explain::<C>().unwrap();
+    let products: Vec<TypeId> = explanation.steps.iter().map(|s| s.product).collect();
+    assert_eq!(
+        vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()],
+        products
+    );
+    assert_eq!(
+        vec![TypeId::of::<A>(), TypeId::of::<B>()],
+        explanation.steps[2].ingredients
+    );
+    assert_eq!(Some("combine"), explanation.steps[2].name);
+    assert_eq!(None, explanation.steps[1].name);
+    assert_eq!(0, PERFORMED.load(Ordering::SeqCst));
+    assert!(tome.explain::<Unknown>().is_none());
+}
+
+#[test]
+fn explanation_displays_a_tree() {
+    let explanation = tome().explain::<C>().unwrap();
+    let expected = format!(
+        "{:?} (combine)\n  {:?}\n  {:?}\n    {:?}\n",
+        TypeId::of::<C>(),
+        TypeId::of::<A>(),
+        TypeId::of::<B>(),
+        TypeId::of::<A>()
+    );
+    assert_eq!(expected, explanation.to_string());
+}
+
+#[test]
+fn summon_explain_traces_the_steps_performed() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(name = "combine", A, B => C));
+    let (_, trace) = tome.summon_explain::<C>().unwrap();
+    let products: Vec<TypeId> = trace.iter().map(|s| s.product).collect();
+    assert_eq!(
+        vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()],
+        products
+    );
+    assert_eq!(vec![TypeId::of::<A>()], trace[1].ingredients);
+    assert_eq!(Some("combine"), trace[2].name);
+    assert!(trace
+        .windows(2)
+        .all(|w| w[0].performed_at <= w[1].performed_at));
+    assert!(tome.summon_explain::<Unknown>().is_none());
+}
+
+#[test]
+fn summon_explain_retries_declining_circles() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle_try!(|_a: &A| -> Option<C> { None }));
+    tome.inscribe(circle!(name = "combine", A, B => C));
+    let (_, trace) = tome.summon_explain::<C>().unwrap();
+    let products: Vec<TypeId> = trace.iter().map(|s| s.product).collect();
+    assert_eq!(
+        vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()],
+        products
+    );
+    assert_eq!(Some("combine"), trace[2].name);
+}
@@ -0,0 +1,45 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Config;
+#[derive(Clone)]
+struct Database;
+#[derive(Debug, PartialEq)]
+struct Server(&'static str);
+
+fn storage() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Config);
+    tome.inscribe(circle!(Config => Database));
+    tome
+}
+
+fn web() -> Tome {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(|_c: &Config, _d: &Database| -> Server {
+        Server("full")
+    }));
+    tome.inscribe(circle!(|_c: &Config| -> Server { Server("bare") }));
+    tome
+}
+
+#[test]
+fn extend_merges_circles() {
+    let mut tome = web();
+    assert!(tome.summon::<Server>().is_err());
+    tome.extend(storage());
+    assert_eq!(Server("bare"), tome.summon::<Server>().unwrap());
+
+    let mut tome = storage();
+    tome.extend(web());
+    assert_eq!(Server("bare"), tome.summon::<Server>().unwrap());
+}
+
+#[test]
+fn extend_ref_shares_circles() {
+    let storage = storage();
+    let mut tome = web();
+    tome.extend_ref(&storage);
+    assert_eq!(Server("bare"), tome.summon::<Server>().unwrap());
+    assert!(storage.summon::<Database>().is_ok());
+}
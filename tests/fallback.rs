@@ -0,0 +1,81 @@
+use summon::{circle, Named, Tome};
+
+#[derive(Clone)]
+struct Cache;
+#[derive(Clone)]
+struct Database;
+#[derive(Clone)]
+struct Credentials;
+#[derive(Debug, PartialEq)]
+struct User(&'static str);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Database);
+    tome.inscribe(circle!(|_d: &Database| -> User { User("sorted") }));
+    tome.inscribe_fallback_chain::<User, _, _>(
+        circle!(|_d: &Database, _c: &Credentials| -> User { User("primary") }),
+        circle!(|_c: &Cache| -> User { User("fallback") }),
+    );
+    tome
+}
+
+#[test]
+fn primary_is_used_when_its_ingredients_are_present() {
+    let mut tome = tome();
+    tome.ether(Credentials);
+    tome.ether(Cache);
+    assert_eq!(User("primary"), tome.summon::<User>().unwrap());
+}
+
+#[test]
+fn fallback_is_used_when_primary_ingredients_are_missing() {
+    let mut tome = tome();
+    tome.ether(Cache);
+    assert_eq!(User("fallback"), tome.summon::<User>().unwrap());
+}
+
+#[test]
+fn other_circles_are_tried_after_the_chain() {
+    let tome = tome();
+    assert_eq!(User("sorted"), tome.summon::<User>().unwrap());
+}
+
+#[test]
+fn inscribe_with_fallback_explains_the_chosen_circle() {
+    let mut tome = Tome::new();
+    tome.ether(Cache);
+    tome.inscribe_with_fallback(
+        Named(
+            circle!(|_d: &Database, _c: &Credentials| -> User { User("primary") }),
+            "database",
+        ),
+        Named(circle!(|_c: &Cache| -> User { User("fallback") }), "cache"),
+    );
+    assert_eq!(User("fallback"), tome.summon::<User>().unwrap());
+    let explanation = tome.explain::<User>().unwrap();
+    let step = explanation.steps.last().unwrap();
+    assert_eq!(Some("cache"), step.name);
+    assert_eq!(Some("database or cache"), step.fallback_pair.as_deref());
+    assert_eq!(None, explanation.steps[0].fallback_pair);
+
+    tome.ether(Database);
+    tome.ether(Credentials);
+    assert_eq!(User("primary"), tome.summon::<User>().unwrap());
+    let explanation = tome.explain::<User>().unwrap();
+    assert_eq!(Some("database"), explanation.steps.last().unwrap().name);
+}
+
+#[test]
+fn inscribe_with_fallback_names_unnamed_circles() {
+    let mut tome = Tome::new();
+    tome.ether(Cache);
+    tome.inscribe_with_fallback(
+        circle!(|_d: &Database| -> User { User("primary") }),
+        circle!(|_c: &Cache| -> User { User("fallback") }),
+    );
+    let explanation = tome.explain::<User>().unwrap();
+    let step = explanation.steps.last().unwrap();
+    assert_eq!(Some("fallback"), step.name);
+    assert_eq!(Some("primary or fallback"), step.fallback_pair.as_deref());
+}
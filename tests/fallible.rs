@@ -0,0 +1,77 @@
+use std::any::{Any, TypeId};
+use std::error::Error;
+use std::fmt;
+use summon::{circle, FallibleTransmutation, SummonError, Tome};
+
+#[derive(Clone)]
+struct Text(&'static str);
+#[derive(Debug, PartialEq)]
+struct Number(u32);
+#[derive(Debug, PartialEq)]
+struct Doubled(u32);
+
+fn tome(text: &'static str) -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Text(text));
+    tome.inscribe(circle!(
+        |t: &Text| -> Result<Number, std::num::ParseIntError> { t.0.parse().map(Number) }
+    ));
+    tome.inscribe(circle!(Number(n) => Doubled(n * 2)));
+    tome
+}
+
+#[test]
+fn fallible_circle_succeeds() {
+    let tome = tome("21");
+    assert_eq!(Number(21), tome.summon::<Number>().unwrap());
+    assert_eq!(Doubled(42), tome.summon::<Doubled>().unwrap());
+}
+
+#[test]
+fn fallible_circle_failure_propagates() {
+    let tome = tome("twenty");
+    match tome.summon::<Doubled>() {
+        Err(SummonError::TransmutationFailed { at, source }) => {
+            assert_eq!(TypeId::of::<Number>(), at);
+            let parse_error = source
+                .downcast_ref::<std::num::ParseIntError>()
+                .expect("original error is accessible");
+            assert_eq!("invalid digit found in string", parse_error.to_string());
+        }
+        other => panic!("expected a failed transmutation, got {:?}", other),
+    }
+    assert!(tome.summon::<Doubled>().is_err());
+}
+
+#[derive(Debug)]
+struct Offline;
+
+impl fmt::Display for Offline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offline")
+    }
+}
+
+impl Error for Offline {}
+
+struct Fetch;
+
+impl FallibleTransmutation for Fetch {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<Number>()
+    }
+    fn try_transmute(&self, _: &[&dyn Any]) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        Err(Box::new(Offline))
+    }
+}
+
+#[test]
+fn manual_fallible_transmutation() {
+    let mut tome = Tome::new();
+    tome.inscribe_fallible(Fetch);
+    let error = tome.summon::<Number>().unwrap_err();
+    assert_eq!("offline", error.source().unwrap().to_string());
+}
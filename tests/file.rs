@@ -0,0 +1,69 @@
+#![cfg(feature = "file-ether")]
+
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+use summon::{FileEtherError, Tome};
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+struct Settings {
+    name: String,
+    retries: u32,
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("summon-{}-{}.json", name, std::process::id()))
+}
+
+#[test]
+fn inscribe_from_file_reads_json() {
+    let path = temp_path("read");
+    fs::write(&path, r#"{ "name": "summon", "retries": 3 }"#).unwrap();
+    let mut tome = Tome::new();
+    tome.inscribe_from_file::<Settings>(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        Settings {
+            name: "summon".to_owned(),
+            retries: 3
+        },
+        tome.summon::<Settings>().unwrap()
+    );
+}
+
+#[test]
+fn inscribe_from_file_errors() {
+    let mut tome = Tome::new();
+    let missing = temp_path("missing");
+    assert!(matches!(
+        tome.inscribe_from_file::<Settings>(&missing),
+        Err(FileEtherError::Io(_))
+    ));
+    let invalid = temp_path("invalid");
+    fs::write(&invalid, "{ name: ").unwrap();
+    let result = tome.inscribe_from_file::<Settings>(&invalid);
+    fs::remove_file(&invalid).unwrap();
+    assert!(matches!(result, Err(FileEtherError::Parse(_))));
+    assert!(tome.summon::<Settings>().is_err());
+}
+
+#[test]
+fn inscribe_from_file_watched_updates() {
+    let path = temp_path("watched");
+    fs::write(&path, r#"{ "name": "before", "retries": 1 }"#).unwrap();
+    let mut tome = Tome::new();
+    tome.inscribe_from_file_watched::<Settings>(&path).unwrap();
+    assert_eq!("before", tome.summon::<Settings>().unwrap().name);
+    fs::write(&path, r#"{ "name": "after", "retries": 2 }"#).unwrap();
+    let start = Instant::now();
+    while tome.summon::<Settings>().unwrap().name != "after" {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "file change was not seen"
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+    fs::remove_file(&path).unwrap();
+}
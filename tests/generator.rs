@@ -0,0 +1,23 @@
+use summon::Tome;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Id(u64);
+
+#[test]
+fn generator_sequence() {
+    let mut tome = Tome::new();
+    tome.inscribe_generator(Id(1), |Id(n)| Id(n * 2));
+    let ids: Vec<Id> = (0..3).map(|_| tome.summon::<Id>().unwrap()).collect();
+    assert_eq!(vec![Id(1), Id(2), Id(4)], ids);
+}
+
+#[test]
+fn generator_reset() {
+    let mut tome = Tome::new();
+    assert!(!tome.reset_generator::<Id>());
+    tome.inscribe_generator(Id(0), |Id(n)| Id(n + 1));
+    tome.summon::<Id>().unwrap();
+    tome.summon::<Id>().unwrap();
+    assert!(tome.reset_generator::<Id>());
+    assert_eq!(Id(0), tome.summon::<Id>().unwrap());
+}
@@ -0,0 +1,26 @@
+use std::sync::{LazyLock, Mutex};
+use summon::SharedTome;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Width(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Area(u32);
+
+static GLOBAL_TOME: LazyLock<Mutex<SharedTome>> = LazyLock::new(|| {
+    let mut tome = SharedTome::new();
+    tome.ether(Width(3));
+    Mutex::new(tome)
+});
+
+#[summon::inscribe(GLOBAL_TOME)]
+fn area(width: &Width) -> Area {
+    Area(width.0 * width.0)
+}
+
+#[test]
+fn inscribed_before_main() {
+    assert_eq!(
+        Area(9),
+        GLOBAL_TOME.lock().unwrap().summon::<Area>().unwrap()
+    );
+}
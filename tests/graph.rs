@@ -0,0 +1,43 @@
+#![cfg(feature = "petgraph")]
+
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct C;
+
+#[test]
+fn acyclic_graph() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(A, B => C));
+    let graph = tome.type_dependencies_as_petgraph();
+    assert_eq!(3, graph.node_count());
+    assert_eq!(3, graph.edge_count());
+    let a = graph
+        .node_indices()
+        .find(|&n| graph[n] == TypeId::of::<A>())
+        .unwrap();
+    assert_eq!(2, graph.neighbors(a).count());
+    let scc = tome.type_scc();
+    assert_eq!(3, scc.len());
+    assert!(scc.iter().all(|component| component.len() == 1));
+}
+
+#[test]
+fn cyclic_graph() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A, C => B));
+    tome.inscribe(circle!(B => C));
+    let scc = tome.type_scc();
+    assert_eq!(2, scc.len());
+    let cycle = scc.iter().find(|component| component.len() > 1).unwrap();
+    assert!(cycle.contains(&TypeId::of::<B>()));
+    assert!(cycle.contains(&TypeId::of::<C>()));
+}
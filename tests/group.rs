@@ -0,0 +1,79 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use summon::{circle, Tome, Transmutation, TransmutationGroup};
+
+#[derive(Clone)]
+struct Enabled;
+#[derive(Clone)]
+struct Config;
+#[derive(Debug, PartialEq)]
+struct Client;
+#[derive(Debug, PartialEq)]
+struct Server;
+
+struct Networking;
+
+impl TransmutationGroup for Networking {
+    fn circles() -> Vec<Box<dyn Transmutation>> {
+        vec![
+            Box::new(circle!(Config => Client)),
+            Box::new(circle!(Config => Server)),
+        ]
+    }
+}
+
+#[test]
+fn group_inscribes_every_circle() {
+    let mut tome = Tome::new();
+    tome.ether(Config);
+    tome.inscribe_group::<Networking>();
+    assert_eq!(Client, tome.summon::<Client>().unwrap());
+    assert_eq!(Server, tome.summon::<Server>().unwrap());
+}
+
+#[test]
+fn conditional_group_appears_together() {
+    let mut tome = Tome::new();
+    tome.ether(Config);
+    tome.inscribe_conditional_group::<Networking>(|tome| tome.summon::<Enabled>().is_ok());
+    assert!(tome.summon::<Client>().is_err());
+    assert!(tome.summon::<Server>().is_err());
+    tome.ether(Enabled);
+    assert_eq!(Client, tome.summon::<Client>().unwrap());
+    assert_eq!(Server, tome.summon::<Server>().unwrap());
+}
+
+#[test]
+fn conditional_group_disappears_together() {
+    let enabled = Rc::new(Cell::new(true));
+    let flag = enabled.clone();
+    let mut tome = Tome::new();
+    tome.ether(Config);
+    tome.inscribe_conditional_group::<Networking>(move |_| flag.get());
+    assert_eq!(Client, tome.summon::<Client>().unwrap());
+    enabled.set(false);
+    assert!(tome.summon::<Client>().is_err());
+    assert!(tome.summon::<Server>().is_err());
+}
+
+#[derive(Debug, PartialEq)]
+struct Proxy;
+
+#[test]
+fn named_group_is_removed_together() {
+    let mut tome = Tome::new();
+    tome.ether(Config);
+    tome.inscribe(circle!(Config => Proxy));
+    tome.inscribe_named_group("networking", Networking::circles());
+    tome.inscribe_named_group(
+        "networking",
+        vec![Box::new(circle!(Config => Proxy)) as Box<dyn Transmutation>],
+    );
+    assert_eq!(Some(Client), tome.summon_opt::<Client>());
+    assert_eq!(Some(Server), tome.summon_opt::<Server>());
+    assert_eq!(3, tome.remove_group("networking").len());
+    assert_eq!(None, tome.summon_opt::<Client>());
+    assert_eq!(None, tome.summon_opt::<Server>());
+    assert_eq!(Some(Proxy), tome.summon_opt::<Proxy>());
+    assert!(tome.remove_group("networking").is_empty());
+}
@@ -0,0 +1,19 @@
+use summon::Tome;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Value(u32);
+
+#[test]
+fn identity_passes_through_ether() {
+    let mut tome = Tome::new();
+    tome.inscribe_identity::<Value>();
+    tome.ether(Value(7));
+    assert_eq!(Value(7), tome.summon::<Value>().unwrap());
+}
+
+#[test]
+fn identity_alone_terminates() {
+    let mut tome = Tome::new();
+    tome.inscribe_identity::<Value>();
+    assert!(tome.summon::<Value>().is_err());
+}
@@ -0,0 +1,183 @@
+use std::any::TypeId;
+use std::sync::Arc;
+use summon::{circle, InscribeError, Tome, Transmutation, TransmutationBuilder};
+
+#[derive(Clone)]
+struct A;
+struct B;
+
+fn singleton() -> impl Transmutation {
+    circle!(A => B)
+}
+
+#[test]
+fn inscribe_once_per_process_across_tomes() {
+    let mut first = Tome::new();
+    let mut second = Tome::new();
+    first.ether(A);
+    second.ether(A);
+    let inscribed = [
+        first.inscribe_once_per_process(singleton()),
+        second.inscribe_once_per_process(singleton()),
+        first.inscribe_once_per_process(singleton()),
+    ];
+    assert_eq!([true, false, false], inscribed);
+    assert!(first.summon::<B>().is_ok());
+    assert!(second.summon::<B>().is_err());
+}
+
+#[derive(Clone)]
+struct Celsius(f64);
+#[derive(Clone)]
+struct Kelvin(f64);
+struct Fahrenheit(f64);
+
+impl From<Kelvin> for Celsius {
+    fn from(Kelvin(k): Kelvin) -> Self {
+        Celsius(k - 273.15)
+    }
+}
+
+#[test]
+fn forwarding_resolves_through_source() {
+    let mut tome = Tome::new();
+    tome.ether(Kelvin(300.0));
+    tome.inscribe_forwarding::<Celsius, Kelvin>();
+    tome.inscribe_forwarding_with::<Fahrenheit, Celsius>(|Celsius(c)| Fahrenheit(c * 1.8 + 32.0));
+    assert!((tome.summon::<Celsius>().unwrap().0 - 26.85).abs() < 1e-9);
+    assert!((tome.summon::<Fahrenheit>().unwrap().0 - 80.33).abs() < 1e-9);
+}
+
+#[derive(Clone)]
+struct Haystack(&'static str);
+#[derive(Clone)]
+struct Needle(char);
+#[derive(Debug, PartialEq)]
+struct Position(usize);
+
+type MaybePosition = Option<Position>;
+
+fn search() -> impl Transmutation {
+    circle!(|haystack: &Haystack, needle: &Needle| -> MaybePosition {
+        haystack.0.find(needle.0).map(Position)
+    })
+}
+
+#[test]
+fn optional_products() {
+    let mut tome = Tome::new();
+    assert!(tome.summon::<Option<Position>>().is_err());
+    tome.inscribe_optional::<Position>(search());
+    assert!(tome.summon::<Option<Position>>().is_err());
+    tome.ether(Haystack("summon"));
+    tome.ether(Needle('m'));
+    assert_eq!(
+        Some(Position(2)),
+        tome.summon::<Option<Position>>().unwrap()
+    );
+
+    let mut tome = Tome::new();
+    tome.inscribe_optional::<Position>(search());
+    tome.ether(Haystack("summon"));
+    tome.ether(Needle('z'));
+    assert_eq!(None, tome.summon::<Option<Position>>().unwrap());
+}
+
+#[test]
+fn try_inscribe_rejects_a_second_circle() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    assert_eq!(Ok(()), tome.try_inscribe(circle!(A => B)));
+    assert_eq!(
+        Err(InscribeError::AlreadyInscribed {
+            product: TypeId::of::<B>()
+        }),
+        tome.try_inscribe(circle!(A => B))
+    );
+    assert_eq!(
+        Ok(()),
+        tome.try_inscribe(TransmutationBuilder::new::<A>().require::<A>().build(|_| A))
+    );
+    assert!(tome.summon::<B>().is_ok());
+}
+
+#[test]
+fn inscribe_arc_shares_the_circle() {
+    let shared: Arc<dyn Transmutation> = Arc::new(circle!(A => B));
+    let mut first = Tome::new();
+    let mut second = Tome::new();
+    first.inscribe_arc(shared.clone());
+    second.inscribe_arc(shared.clone());
+    assert_eq!(3, Arc::strong_count(&shared));
+    first.ether(A);
+    assert!(first.summon::<B>().is_ok());
+    assert!(second.summon::<B>().is_err());
+    second.ether(A);
+    assert!(second.summon::<B>().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "already inscribed (named \"a to b\")")]
+fn inscribe_once_panics_on_second_circle() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe_once(circle!(name = "a to b", A => B));
+    tome.inscribe_once(singleton());
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Stage(u32);
+#[derive(Debug, PartialEq)]
+struct Finished(u32);
+
+#[test]
+fn inscribe_from_iter_inscribes_every_circle() {
+    let mut tome = Tome::new();
+    tome.ether(Stage(0));
+    let mut circles: Vec<Box<dyn Transmutation>> = vec![
+        Box::new(circle!(Stage(n) => Finished(n + 1))),
+        Box::new(circle!(Stage(n) => Finished(n + 2))),
+    ];
+    for _ in 0..8 {
+        circles.push(Box::new(circle!(A => Finished { Finished(100) })));
+    }
+    tome.ether(A);
+    tome.inscribe_from_iter(circles);
+    assert_eq!(10, tome.circles_for::<Finished>().len());
+    assert_eq!(
+        vec![Finished(1), Finished(2)],
+        tome.summon_all::<Finished>()
+            .into_iter()
+            .take(2)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn inscribe_dyn_inscribes_boxed_circles() {
+    let circles: Vec<Box<dyn Transmutation>> = vec![
+        Box::new(circle!(Stage(n) => Finished(n + 1))),
+        Box::new(circle!(A => Stage { Stage(4) })),
+    ];
+    let mut tome = Tome::new();
+    tome.ether(A);
+    for circle in circles {
+        tome.inscribe_dyn(circle);
+    }
+    assert_eq!(Finished(5), tome.summon::<Finished>().unwrap());
+}
+
+#[derive(Clone)]
+struct GpuDevice;
+struct Render;
+
+#[test]
+fn inscribe_if_checks_condition_once() {
+    let mut tome = Tome::new();
+    let gpu = || circle!(GpuDevice => Render);
+    assert!(!tome.inscribe_if(|t| t.can_summon::<GpuDevice>(), gpu()));
+    tome.ether(GpuDevice);
+    assert!(tome.summon::<Render>().is_err());
+    assert!(tome.inscribe_if(|t| t.can_summon::<GpuDevice>(), gpu()));
+    assert!(tome.summon::<Render>().is_ok());
+}
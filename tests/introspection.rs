@@ -0,0 +1,78 @@
+use std::any::TypeId;
+use std::collections::BTreeSet;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct C;
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(|_a: &A| -> B { B }));
+    tome.inscribe(circle!(|_a: &A, _b: &B| -> C { C }));
+    tome.inscribe(circle!(|_b: &B| -> C { C }));
+    tome
+}
+
+#[test]
+fn registered_products_lists_every_product() {
+    let mut expected = vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()];
+    expected.sort();
+    assert_eq!(expected, tome().registered_products());
+}
+
+#[test]
+fn circles_for_lists_circles_in_order() {
+    let tome = tome();
+    let circles = tome.circles_for::<C>();
+    assert_eq!(2, circles.len());
+    assert_eq!(&[TypeId::of::<B>()], circles[0].ingredients());
+    assert!(tome.circles_for::<u32>().is_empty());
+}
+
+#[test]
+fn dependency_edges_from_outside_the_crate() {
+    let tome = tome();
+    let edges: BTreeSet<(TypeId, TypeId)> = tome
+        .registered_products()
+        .into_iter()
+        .flat_map(|product| {
+            tome.circles_for_id(product)
+                .into_iter()
+                .flat_map(|circle| circle.ingredients().to_vec())
+                .map(move |ingredient| (ingredient, product))
+        })
+        .collect();
+    let expected: BTreeSet<(TypeId, TypeId)> = [
+        (TypeId::of::<A>(), TypeId::of::<B>()),
+        (TypeId::of::<A>(), TypeId::of::<C>()),
+        (TypeId::of::<B>(), TypeId::of::<C>()),
+    ]
+    .iter()
+    .copied()
+    .collect();
+    assert_eq!(expected, edges);
+}
+
+#[derive(Clone)]
+struct D;
+
+#[test]
+fn ether_extends_reachable_products() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(|_a: &A| -> B { B }));
+    tome.inscribe(circle!(|_b: &B, _d: &D| -> C { C }));
+    assert!(tome.all_reachable_from_ether().is_empty());
+    tome.ether(A);
+    let mut expected = vec![TypeId::of::<A>(), TypeId::of::<B>()];
+    expected.sort();
+    assert_eq!(expected, tome.all_reachable_from_ether());
+    assert!(!tome.can_summon::<C>());
+    tome.ether(D);
+    assert!(tome.can_summon::<C>());
+    assert_eq!(4, tome.all_reachable_from_ether().len());
+}
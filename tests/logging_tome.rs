@@ -0,0 +1,38 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Mass(f64);
+#[derive(Clone)]
+struct Acceleration(f64);
+#[derive(Debug, PartialEq)]
+struct Force(f64);
+
+#[test]
+fn with_logging_logs_steps_in_order() {
+    let mut tome = Tome::new();
+    tome.register_type::<Force>("Force");
+    tome.ether(Mass(2.0));
+    tome.ether(Acceleration(3.0));
+    tome.inscribe(circle!(Mass(m), Acceleration(a) => Force(m * a)));
+    let lines = RefCell::new(vec![]);
+    let logging = tome.with_logging(|line| lines.borrow_mut().push(line.to_owned()));
+    assert_eq!(Force(6.0), logging.summon::<Force>().unwrap());
+    assert_eq!(
+        vec![
+            format!(
+                "Applying circle producing {:?} with 0 ingredients",
+                TypeId::of::<Mass>()
+            ),
+            format!(
+                "Applying circle producing {:?} with 0 ingredients",
+                TypeId::of::<Acceleration>()
+            ),
+            "Applying circle producing Force with 2 ingredients".to_owned(),
+        ],
+        *lines.borrow()
+    );
+    assert_eq!(Force(6.0), tome.summon::<Force>().unwrap());
+    assert_eq!(3, lines.borrow().len());
+}
@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, SummonError, Tome};
+
+#[derive(Clone)]
+struct Input(u32);
+#[derive(Clone)]
+struct Expensive(u32);
+#[derive(Debug, PartialEq)]
+struct Left(u32);
+#[derive(Debug, PartialEq)]
+struct Right(u32);
+struct Missing;
+
+static COMPUTED: AtomicU32 = AtomicU32::new(0);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Input(3));
+    tome.inscribe(circle!(|i: &Input| -> Expensive {
+        COMPUTED.fetch_add(1, Ordering::SeqCst);
+        Expensive(i.0 * 100)
+    }));
+    tome.inscribe(circle!(Expensive(e) => Left(e + 1)));
+    tome.inscribe(circle!(Expensive(e) => Right(e + 2)));
+    tome
+}
+
+#[test]
+fn shared_steps_are_performed_once() {
+    let tome = tome();
+    let before = COMPUTED.load(Ordering::SeqCst);
+    let (left, right) = tome.summon_tuple::<(Left, Right)>().unwrap();
+    assert_eq!(Left(301), left);
+    assert_eq!(Right(302), right);
+    assert_eq!(before + 1, COMPUTED.load(Ordering::SeqCst));
+}
+
+#[test]
+fn missing_type_fails_the_whole_tuple() {
+    let tome = tome();
+    assert!(matches!(
+        tome.summon_tuple::<(Left, Missing)>(),
+        Err(SummonError::NoPossibleRecipe { .. })
+    ));
+}
+
+#[derive(Debug, PartialEq)]
+struct Unreachable;
+
+#[test]
+fn try_summon_all_fills_available_slots() {
+    static SHARED: AtomicU32 = AtomicU32::new(0);
+    let mut tome = Tome::new();
+    tome.ether(Input(3));
+    tome.inscribe(circle!(|i: &Input| -> Expensive {
+        SHARED.fetch_add(1, Ordering::SeqCst);
+        Expensive(i.0 * 10)
+    }));
+    tome.inscribe(circle!(Expensive(e) => Left(e + 1)));
+    tome.inscribe(circle!(Expensive(e) => Right(e + 2)));
+    tome.inscribe(circle!(|_m: &Missing| -> Unreachable { Unreachable }));
+    assert_eq!(
+        (Some(Left(31)), None, Some(Right(32))),
+        tome.try_summon_all::<(Left, Unreachable, Right)>()
+    );
+    assert_eq!(1, SHARED.load(Ordering::SeqCst));
+}
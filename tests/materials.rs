@@ -0,0 +1,52 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Width(u32);
+#[derive(Debug, PartialEq)]
+struct Area(u32);
+#[derive(Debug, PartialEq)]
+struct Volume(u32);
+struct Unused;
+
+#[test]
+fn preserve_keeps_intermediate_materials() {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    tome.inscribe(circle!(Width(w) => Area(w * w)));
+    tome.inscribe(circle!(Width(w), Area(a) => Volume(w * a)));
+    let mut materials = tome.preserve::<Volume>().unwrap();
+    assert_eq!(Some(&Width(3)), materials.get::<Width>());
+    assert_eq!(Some(&Area(9)), materials.get::<Area>());
+    assert_eq!(Some(&Volume(27)), materials.get::<Volume>());
+    assert!(materials.get::<Unused>().is_none());
+
+    materials.apply(&circle!(Area(a) => Volume(a + 1)));
+    assert_eq!(Some(&Volume(10)), materials.get::<Volume>());
+    assert!(tome.preserve::<Unused>().is_err());
+}
+
+#[test]
+fn materials_iterate_every_product() {
+    let mut tome = Tome::new();
+    tome.ether(Width(2));
+    tome.inscribe(circle!(Width(w) => Area(w * w)));
+    tome.inscribe(circle!(Area(a) => Volume(a * 2)));
+    let mut areas = 0;
+    let mut ids = vec![];
+    for (id, material) in tome.preserve::<Volume>().unwrap() {
+        if let Some(area) = material.downcast_ref::<Area>() {
+            areas += area.0;
+        }
+        ids.push(id);
+    }
+    ids.sort();
+    let mut expected = vec![
+        TypeId::of::<Width>(),
+        TypeId::of::<Area>(),
+        TypeId::of::<Volume>(),
+    ];
+    expected.sort();
+    assert_eq!(expected, ids);
+    assert_eq!(4, areas);
+}
@@ -0,0 +1,151 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+use std::time::Duration;
+use summon::{circle, circle_try, Tome};
+
+#[derive(Clone)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct Velocity(f64);
+struct Distance(f64);
+
+static VELOCITY_RUNS: AtomicU32 = AtomicU32::new(0);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(ConstantAcceleration(2.0));
+    tome.inscribe(circle!(|a: &ConstantAcceleration| -> Velocity {
+        VELOCITY_RUNS.fetch_add(1, Ordering::SeqCst);
+        Velocity(a.0 * 3.0)
+    }));
+    tome.inscribe(circle!(Velocity(v) => Distance(v * 4.0)));
+    tome
+}
+
+#[test]
+fn cache_reuses_materials_until_invalidated() {
+    let mut tome = tome();
+    tome.enable_cache();
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(1, VELOCITY_RUNS.load(Ordering::SeqCst));
+
+    tome.invalidate_cache();
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(2, VELOCITY_RUNS.load(Ordering::SeqCst));
+
+    tome.disable_cache();
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(24.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(4, VELOCITY_RUNS.load(Ordering::SeqCst));
+}
+
+#[derive(Clone)]
+struct Time(f64);
+#[derive(Clone)]
+struct Acceleration(f64);
+#[derive(Clone)]
+struct Fall(f64);
+
+static ACCELERATION_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn changing_ether_forgets_dependents() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.ether(9.0f64);
+    tome.inscribe(circle!(|g: &f64| -> Acceleration {
+        ACCELERATION_RUNS.fetch_add(1, Ordering::SeqCst);
+        Acceleration(*g)
+    }));
+    tome.inscribe(circle!(|a: &Acceleration, t: &Time| -> Fall {
+        Fall(0.5 * a.0 * t.0 * t.0)
+    }));
+    tome.inscribe(circle!(Fall(d) => Distance(*d)));
+    tome.enable_cache();
+    assert_eq!(18.0, tome.summon::<Distance>().unwrap().0);
+
+    tome.remove::<Time>();
+    tome.ether(Time(4.0));
+    assert_eq!(72.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(1, ACCELERATION_RUNS.load(Ordering::SeqCst));
+
+    tome.forget_cache::<f64>();
+    assert_eq!(72.0, tome.summon::<Distance>().unwrap().0);
+    assert_eq!(2, ACCELERATION_RUNS.load(Ordering::SeqCst));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Height(f64);
+
+static HEIGHT_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn summon_cached_keeps_the_product() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.inscribe(circle!(|t: &Time| -> Height {
+        HEIGHT_RUNS.fetch_add(1, Ordering::SeqCst);
+        Height(t.0 * 5.0)
+    }));
+    tome.enable_cache();
+    assert_eq!(Height(10.0), tome.summon_cached::<Height>().unwrap());
+    assert_eq!(Height(10.0), tome.summon_cached::<Height>().unwrap());
+    assert_eq!(1, HEIGHT_RUNS.load(Ordering::SeqCst));
+}
+
+#[test]
+fn cache_applies_prescriptions_and_observers() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.inscribe(circle!(Time(t) => Acceleration(t * 2.0)));
+    tome.prescribe::<Acceleration>(|a| a.0 < 0.0);
+    let observed = Rc::new(Cell::new(0));
+    let counter = observed.clone();
+    tome.subscribe::<Time>(move |_| counter.set(counter.get() + 1));
+    tome.enable_cache();
+    assert!(tome.summon::<Acceleration>().is_err());
+    assert_eq!(1, observed.get());
+}
+
+#[test]
+fn cache_retries_declining_circles() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.inscribe(circle_try!(|_t: &Time| -> Option<Fall> { None }));
+    tome.inscribe(circle!(Time(t) => Fall(t * 3.0)));
+    tome.enable_cache();
+    assert_eq!(6.0, tome.summon::<Fall>().unwrap().0);
+}
+
+#[test]
+fn overriding_forgets_dependents_in_every_store() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.inscribe_overrideable::<Height>(circle!(Time(t) => Height(t * 5.0)));
+    tome.inscribe_with_schema_version::<Fall, _>(1, circle!(Height(h) => Fall(h * 2.0)));
+    tome.enable_cache();
+    assert_eq!(20.0, tome.summon_cached::<Fall>().unwrap().0);
+    tome.override_circle::<Height>(circle!(Time => Height { Height(1.0) }));
+    assert_eq!(Height(1.0), tome.summon_cached::<Height>().unwrap());
+    assert_eq!(2.0, tome.summon_cached::<Fall>().unwrap().0);
+    tome.undo_override::<Height>();
+    assert_eq!(20.0, tome.summon_cached::<Fall>().unwrap().0);
+}
+
+#[test]
+fn cache_remakes_products_of_expired_circles() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.inscribe_ttl(
+        circle!(Time => Height { Height(1.0) }),
+        Duration::from_millis(20),
+    );
+    tome.inscribe(circle!(Time(t) => Height(t * 5.0)));
+    tome.enable_cache();
+    assert_eq!(Height(1.0), tome.summon_cached::<Height>().unwrap());
+    thread::sleep(Duration::from_millis(40));
+    assert_eq!(Height(10.0), tome.summon_cached::<Height>().unwrap());
+}
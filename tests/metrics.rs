@@ -0,0 +1,32 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+struct Area(u32);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    tome.inscribe(circle!(Width(w) => Area(w * w)));
+    tome
+}
+
+#[test]
+fn metrics_count_each_transmutation() {
+    let mut tome = tome();
+    tome.summon::<Area>().unwrap();
+    assert!(tome.metrics().is_empty());
+
+    tome.enable_metrics();
+    for _ in 0..5 {
+        assert_eq!(9, tome.summon::<Area>().unwrap().0);
+    }
+    assert_eq!(Some(&5), tome.metrics().get(&TypeId::of::<Area>()));
+    assert_eq!(Some(&5), tome.metrics().get(&TypeId::of::<Width>()));
+
+    tome.reset_metrics();
+    assert!(tome.metrics().is_empty());
+    tome.summon::<Area>().unwrap();
+    assert_eq!(Some(&1), tome.metrics().get(&TypeId::of::<Area>()));
+}
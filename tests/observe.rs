@@ -0,0 +1,30 @@
+use std::any::TypeId;
+use std::cell::Cell;
+use std::rc::Rc;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Speed(f64);
+#[derive(Clone)]
+struct Distance(f64);
+struct Report;
+
+#[test]
+fn subscribe_fires_for_every_distance_made() {
+    let mut tome = Tome::new();
+    tome.enable_metrics();
+    tome.ether(Speed(3.0));
+    tome.inscribe(circle!(Speed(s) => Distance(s * 2.0)));
+    tome.inscribe(circle!(Distance => Report));
+    let produced = Rc::new(Cell::new(0));
+    let counter = produced.clone();
+    tome.subscribe(move |d: &Distance| {
+        assert_eq!(6.0, d.0);
+        counter.set(counter.get() + 1);
+    });
+    tome.summon::<Distance>().unwrap();
+    tome.summon::<Report>().unwrap();
+    tome.summon::<Speed>().unwrap();
+    assert_eq!(2, produced.get());
+    assert_eq!(Some(&2), tome.metrics().get(&TypeId::of::<Distance>()));
+}
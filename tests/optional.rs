@@ -0,0 +1,26 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Request;
+#[derive(Clone, Debug, PartialEq)]
+struct Session(u32);
+#[derive(Debug, PartialEq)]
+struct Response(Option<u32>);
+
+type MaybeSession = Option<Session>;
+
+#[test]
+fn optional_ingredient_is_none_when_missing() {
+    let mut tome = Tome::new();
+    tome.ether(Request);
+    tome.optional_ingredient::<Session>();
+    tome.inscribe(circle!(|_r: &Request, s: &MaybeSession| -> Response {
+        Response(s.as_ref().map(|s| s.0))
+    }));
+    assert_eq!(Response(None), tome.summon::<Response>().unwrap());
+    assert_eq!(None, tome.summon::<MaybeSession>().unwrap());
+
+    tome.inscribe(circle!(|_r: &Request| -> Session { Session(7) }));
+    assert_eq!(Response(Some(7)), tome.summon::<Response>().unwrap());
+    assert_eq!(Some(Session(7)), tome.summon::<MaybeSession>().unwrap());
+}
@@ -0,0 +1,95 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, MaxIngredients, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+#[derive(Clone)]
+struct C;
+#[derive(Clone)]
+struct Extra;
+#[derive(Clone, Debug, PartialEq)]
+struct Route(&'static str);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.ether(C);
+    tome.ether(Extra);
+    tome.inscribe(circle!(|_a: &A| -> Route { Route("a") }));
+    tome.inscribe(circle!(|_b: &B, _e: &Extra| -> Route { Route("b") }));
+    tome.inscribe(circle!(|_c: &C, _e: &Extra| -> Route { Route("c") }));
+    tome
+}
+
+#[test]
+fn preferences_reorder_circles() {
+    let mut tome = tome();
+    assert_eq!(Route("a"), tome.summon::<Route>().unwrap());
+    tome.prefer::<Route, C, A>();
+    assert_eq!(Route("c"), tome.summon::<Route>().unwrap());
+    tome.prefer::<Route, B, C>();
+    assert_eq!(Route("b"), tome.summon::<Route>().unwrap());
+    assert!(tome.compute_ordering_conflicts().is_empty());
+}
+
+#[test]
+fn preference_cycle_is_a_conflict() {
+    let mut tome = tome();
+    tome.prefer::<Route, A, B>();
+    tome.prefer::<Route, B, C>();
+    tome.prefer::<Route, C, A>();
+    let conflicts = tome.compute_ordering_conflicts();
+    assert_eq!(1, conflicts.len());
+    assert_eq!(TypeId::of::<Route>(), conflicts[0].product);
+    let mut types = conflicts[0].types.clone();
+    types.sort();
+    let mut expected = vec![TypeId::of::<A>(), TypeId::of::<B>(), TypeId::of::<C>()];
+    expected.sort();
+    assert_eq!(expected, types);
+    assert!(conflicts[0].explanation.contains("cycle"));
+    // The contradicting preferences are ignored.
+    assert_eq!(Route("a"), tome.summon::<Route>().unwrap());
+}
+
+#[test]
+fn reorder_circles_overrides_heuristic() {
+    let mut tome = tome();
+    assert!(tome.reorder_circles::<Route>(vec![2, 0, 1]));
+    assert_eq!(Route("c"), tome.summon::<Route>().unwrap());
+    tome.inscribe(circle!(|_a: &A| -> Route { Route("late") }));
+    assert_eq!(Route("c"), tome.summon::<Route>().unwrap());
+    assert!(!tome.reorder_circles::<Route>(vec![0, 0, 1, 2]));
+    assert!(!tome.reorder_circles::<Route>(vec![0, 1]));
+    tome.reset_circle_order::<Route>();
+    assert_eq!(Route("a"), tome.summon::<Route>().unwrap());
+}
+
+#[test]
+fn priority_overrides_heuristic() {
+    let mut tome = tome();
+    tome.ether(Route("ether"));
+    assert_eq!(Route("ether"), tome.summon::<Route>().unwrap());
+    tome.inscribe_with_priority(circle!(|_b: &B, _c: &C| -> Route { Route("priority") }), 10);
+    assert_eq!(Route("priority"), tome.summon::<Route>().unwrap());
+}
+
+static GREEDY_CALLS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn max_ingredients_strategy_prefers_more_ingredients() {
+    let mut tome = Tome::new_with_strategy(MaxIngredients);
+    tome.ether(A);
+    tome.ether(B);
+    tome.ether(C);
+    tome.inscribe(circle!(|_a: &A| -> Route { Route("one") }));
+    tome.inscribe(circle!(|_a: &A, _b: &B, _c: &C| -> Route {
+        GREEDY_CALLS.fetch_add(1, Ordering::SeqCst);
+        Route("three")
+    }));
+    assert_eq!(Route("three"), tome.summon::<Route>().unwrap());
+    assert_eq!(1, GREEDY_CALLS.load(Ordering::SeqCst));
+}
@@ -0,0 +1,103 @@
+use summon::{circle, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Initial(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Rate(u32);
+#[derive(Debug, PartialEq)]
+struct Outcome(u32);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Initial(1));
+    tome.ether(Rate(2));
+    tome.inscribe(circle!(Initial(i), Rate(r) => Outcome(i * r)));
+    tome
+}
+
+#[test]
+fn injected_ether_takes_precedence() {
+    let tome = tome();
+    assert_eq!(
+        Outcome(10),
+        tome.summon_with::<Outcome, _>(|t| t.ether(Initial(5)))
+            .unwrap()
+    );
+    assert_eq!(
+        Outcome(15),
+        tome.summon_with::<Outcome, _>(|t| {
+            t.ether(Initial(5));
+            t.ether(Rate(3));
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn tome_is_unmodified() {
+    let tome = tome();
+    tome.summon_with::<Outcome, _>(|t| t.ether(Initial(5)))
+        .unwrap();
+    assert_eq!(Outcome(2), tome.summon::<Outcome>().unwrap());
+    assert_eq!(Initial(1), tome.summon::<Initial>().unwrap());
+}
+
+#[test]
+fn injected_ether_fills_missing_ingredients() {
+    let mut tome = Tome::new();
+    tome.inscribe(circle!(Initial(i), Rate(r) => Outcome(i * r)));
+    assert!(tome.summon::<Outcome>().is_err());
+    assert_eq!(
+        Outcome(12),
+        tome.summon_with::<Outcome, _>(|t| {
+            t.ether(Initial(3));
+            t.ether(Rate(4));
+        })
+        .unwrap()
+    );
+    assert!(tome.summon::<Outcome>().is_err());
+}
+
+#[derive(Clone)]
+struct A(u32);
+#[derive(Debug, PartialEq)]
+struct B(u32);
+
+#[test]
+fn scopes_resolve_independently() {
+    let mut parent = Tome::new();
+    parent.ether(A(3));
+    parent.inscribe(circle!(A(n) => B(n + 1)));
+    let mut child = parent.scope();
+    child.inscribe(circle!(A(n) => B(n * 10)));
+    assert_eq!(B(30), child.summon::<B>().unwrap());
+    assert_eq!(B(4), parent.summon::<B>().unwrap());
+
+    let mut other = parent.scope();
+    other.ether(A(5));
+    assert_eq!(Some(B(6)), other.summon_opt::<B>());
+    assert_eq!(B(30), child.summon::<B>().unwrap());
+}
+
+#[test]
+fn with_ether_replaces_for_one_summon() {
+    let mut tome = Tome::new();
+    tome.ether(A(3));
+    tome.inscribe(circle!(A(n) => B(n + 1)));
+    assert_eq!(B(8), tome.with_ether(A(7)).summon::<B>().unwrap());
+    assert_eq!(Some(B(10)), tome.with_ether(A(9)).summon_opt::<B>());
+    assert_eq!(B(4), tome.summon::<B>().unwrap());
+}
+
+#[test]
+fn fallback_tome_threads_ingredients_across_tomes() {
+    let mut primary = Tome::new();
+    primary.inscribe(circle!(A(n) => B(n + 1)));
+    let mut fallback = Tome::new();
+    fallback.ether(A(3));
+    fallback.inscribe(circle!(A(n) => B(n * 100)));
+    let combined = Tome::with_fallback(primary, fallback);
+    assert_eq!(B(4), combined.summon::<B>().unwrap());
+    assert!(combined.primary().summon::<B>().is_err());
+    assert_eq!(Some(B(300)), combined.fallback().summon_opt::<B>());
+}
@@ -0,0 +1,41 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Flag;
+#[derive(Debug, PartialEq)]
+struct Behavior(&'static str);
+
+#[test]
+fn override_and_undo() {
+    let mut tome = Tome::new();
+    tome.ether(Flag);
+    tome.inscribe_overrideable::<Behavior>(circle!(|_f: &Flag| -> Behavior {
+        Behavior("default")
+    }));
+    assert_eq!(Behavior("default"), tome.summon::<Behavior>().unwrap());
+
+    assert!(
+        tome.override_circle::<Behavior>(circle!(|_f: &Flag| -> Behavior { Behavior("first") }))
+    );
+    assert!(
+        tome.override_circle::<Behavior>(circle!(|_f: &Flag| -> Behavior { Behavior("second") }))
+    );
+    assert_eq!(Behavior("second"), tome.summon::<Behavior>().unwrap());
+    assert_eq!(3, tome.override_history::<Behavior>().len());
+
+    assert!(tome.undo_override::<Behavior>());
+    assert_eq!(Behavior("first"), tome.summon::<Behavior>().unwrap());
+    assert!(tome.undo_override::<Behavior>());
+    assert_eq!(Behavior("default"), tome.summon::<Behavior>().unwrap());
+    assert!(!tome.undo_override::<Behavior>());
+    assert_eq!(1, tome.override_history::<Behavior>().len());
+}
+
+#[test]
+fn override_requires_slot() {
+    let mut tome = Tome::new();
+    assert!(
+        !tome.override_circle::<Behavior>(circle!(|_f: &Flag| -> Behavior { Behavior("first") }))
+    );
+    assert!(tome.override_history::<Behavior>().is_empty());
+}
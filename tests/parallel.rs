@@ -0,0 +1,35 @@
+#![cfg(feature = "rayon")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, SharedTome};
+
+#[derive(Clone)]
+struct Base(u32);
+struct Left(u32);
+struct Right(u32);
+struct Top(u32);
+struct Unshared(u32);
+
+static BASES_USED: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn summon_parallel_performs_a_diamond() {
+    let mut tome = SharedTome::new();
+    tome.ether(Base(3));
+    tome.inscribe_parallel::<Left, _>(circle!(|b: &Base| -> Left {
+        BASES_USED.fetch_add(1, Ordering::SeqCst);
+        Left(b.0 + 1)
+    }));
+    tome.inscribe_parallel::<Right, _>(circle!(|b: &Base| -> Right {
+        BASES_USED.fetch_add(1, Ordering::SeqCst);
+        Right(b.0 * 2)
+    }));
+    tome.inscribe_parallel::<Top, _>(circle!(Left(l), Right(r) => Top(l * r)));
+    tome.inscribe(circle!(Base(b) => Unshared(*b)));
+
+    assert_eq!(24, tome.summon_parallel::<Top>().unwrap().0);
+    assert_eq!(2, BASES_USED.load(Ordering::SeqCst));
+    assert_eq!(24, tome.summon::<Top>().unwrap().0);
+    assert!(tome.summon_parallel::<Unshared>().is_none());
+    assert_eq!(3, tome.summon::<Unshared>().unwrap().0);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use std::any::{Any, TypeId};
+use std::time::Duration;
+use summon::{circle, LoadError, LoggingContext, Tome, Transmutation};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Time(f64);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct Unsaved;
+#[derive(Debug, PartialEq)]
+struct Distance(f64);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.register_serde_type::<Time>("Time");
+    tome.register_serde_type::<ConstantAcceleration>("ConstantAcceleration");
+    tome.inscribe(circle!(ConstantAcceleration(a), Time(t) => Distance(0.5 * a * t.powi(2))));
+    tome
+}
+
+#[test]
+fn ether_roundtrips_through_json() {
+    let mut original = tome();
+    original.ether(Time(4.0));
+    original.ether(ConstantAcceleration(3.0));
+    original.ether(Unsaved);
+    let mut saved = vec![];
+    original.save_ether(&mut saved).unwrap();
+
+    let mut loaded = tome();
+    loaded.load_ether(saved.as_slice()).unwrap();
+    assert_eq!(
+        original.summon::<Distance>().unwrap(),
+        loaded.summon::<Distance>().unwrap()
+    );
+    assert_eq!(Time(4.0), loaded.summon::<Time>().unwrap());
+    assert!(loaded.summon::<Unsaved>().is_err());
+}
+
+fn roundtrip(original: &Tome) -> Tome {
+    let mut saved = vec![];
+    original.save_ether(&mut saved).unwrap();
+    let mut loaded = tome();
+    loaded.load_ether(saved.as_slice()).unwrap();
+    loaded
+}
+
+#[test]
+fn ether_instrumented_by_a_context_roundtrips() {
+    let mut original = tome();
+    original.with_context(LoggingContext::new(|_| {}));
+    original.ether(Time(4.0));
+    assert_eq!(Time(4.0), roundtrip(&original).summon::<Time>().unwrap());
+}
+
+/// A hand-written ether, so it can be wrapped by `inscribe_ttl`.
+struct TimeEther(Time);
+
+impl Transmutation for TimeEther {
+    fn ingredients(&self) -> &'static [TypeId] {
+        &[]
+    }
+    fn product(&self) -> TypeId {
+        TypeId::of::<Time>()
+    }
+    fn transmute(&self, _: &[&dyn Any]) -> Box<dyn Any> {
+        Box::new(self.0.clone())
+    }
+    fn ether_value(&self) -> Option<&dyn Any> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn time_limited_ether_roundtrips() {
+    let mut original = tome();
+    original.inscribe_ttl(TimeEther(Time(4.0)), Duration::from_secs(3600));
+    assert_eq!(Time(4.0), roundtrip(&original).summon::<Time>().unwrap());
+}
+
+#[test]
+fn load_rejects_unknown_types() {
+    let mut tome = Tome::new();
+    match tome.load_ether(r#"{"Time": [4.0]}"#.as_bytes()) {
+        Err(LoadError::UnknownType { name }) => assert_eq!("Time", name),
+        other => panic!("expected an unknown type, got {:?}", other),
+    }
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+use summon::{circle, Tome};
+
+struct Fast;
+struct Careful;
+#[derive(Clone)]
+struct Input(u32);
+#[derive(Debug, PartialEq)]
+struct Output(u32);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Input(3));
+    tome.inscribe(circle!(|_: &PhantomData<Fast>, i: &Input| -> Output {
+        Output(i.0)
+    }));
+    tome.inscribe(circle!(|_: &PhantomData<Careful>, i: &Input| -> Output {
+        Output(i.0 * 10)
+    }));
+    tome
+}
+
+#[test]
+fn phantom_selects_mode() {
+    let mut tome = tome();
+    assert!(tome.summon::<Output>().is_err());
+    tome.inscribe_phantom::<Careful>();
+    assert_eq!(Output(30), tome.summon::<Output>().unwrap());
+
+    let mut tome = self::tome();
+    tome.inscribe_phantom::<Fast>();
+    assert_eq!(Output(3), tome.summon::<Output>().unwrap());
+}
@@ -0,0 +1,51 @@
+use summon::{circle, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config(u32);
+
+#[test]
+#[should_panic(expected = "cannot inscribe a circle producing pin::Config because it is pinned")]
+fn ether_panics_when_pinned() {
+    let mut tome = Tome::new();
+    tome.pin_ether(Config(1));
+    tome.ether(Config(2));
+}
+
+#[test]
+fn unpin_allows_ether_again() {
+    let mut tome = Tome::new();
+    tome.pin_ether(Config(1));
+    assert!(tome.unpin::<Config>());
+    assert!(!tome.unpin::<Config>());
+    tome.ether(Config(2));
+    assert_eq!(2, tome.summon_all::<Config>().len());
+}
+
+#[test]
+#[should_panic(
+    expected = "cannot pin pin::Config because a circle producing it is already inscribed"
+)]
+fn pin_panics_when_already_inscribed() {
+    let mut tome = Tome::new();
+    tome.ether(Config(1));
+    tome.pin_ether(Config(2));
+}
+
+#[test]
+#[should_panic(expected = "cannot inscribe a circle producing pin::Config because it is pinned")]
+fn fallback_chain_panics_when_pinned() {
+    let mut tome = Tome::new();
+    tome.pin_ether(Config(1));
+    tome.inscribe_with_fallback(
+        circle!(|n: &u8| -> Config { Config((*n).into()) }),
+        circle!(|n: &u16| -> Config { Config((*n).into()) }),
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot inscribe a circle producing pin::Config because it is pinned")]
+fn override_panics_when_pinned() {
+    let mut tome = Tome::new();
+    tome.pin_ether(Config(1));
+    tome.inscribe_overrideable::<Config>(circle!(|n: &u8| -> Config { Config((*n).into()) }));
+}
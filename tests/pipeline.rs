@@ -0,0 +1,41 @@
+use summon::{PipelineBuilder, Tome};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Celsius(i32);
+#[derive(Clone, Debug, PartialEq)]
+struct Kelvin(i32);
+#[derive(Clone, Debug, PartialEq)]
+struct Reading(String);
+
+impl From<Celsius> for Kelvin {
+    fn from(c: Celsius) -> Self {
+        Kelvin(c.0 + 273)
+    }
+}
+
+impl From<Kelvin> for Reading {
+    fn from(k: Kelvin) -> Self {
+        Reading(format!("{}K", k.0))
+    }
+}
+
+#[test]
+fn builder_inscribes_each_step() {
+    let mut tome = Tome::new();
+    tome.ether(Celsius(27));
+    PipelineBuilder::new::<Celsius>()
+        .then(|c: &Celsius| Kelvin(c.0 + 273))
+        .then(|k: &Kelvin| Reading(k.0.to_string()))
+        .build_into(&mut tome);
+    assert_eq!(Kelvin(300), tome.summon::<Kelvin>().unwrap());
+    assert_eq!(Reading("300".to_owned()), tome.summon::<Reading>().unwrap());
+}
+
+#[test]
+fn piped_types_convert_with_from() {
+    let mut tome = Tome::new();
+    tome.ether(Celsius(-273));
+    tome.inscribe_piped::<(Celsius, Kelvin, Reading)>();
+    assert_eq!(Kelvin(0), tome.summon::<Kelvin>().unwrap());
+    assert_eq!(Reading("0K".to_owned()), tome.summon::<Reading>().unwrap());
+}
@@ -0,0 +1,90 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, PlanMode, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Area(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Volume(u32);
+#[derive(Debug, PartialEq)]
+struct Cost(u32);
+struct Unknown;
+
+static COST_RUNS: AtomicU32 = AtomicU32::new(0);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Width(2));
+    tome.ether(Height(3));
+    tome.inscribe(circle!(Width(w), Height(h) => Area(w * h)));
+    tome.inscribe(circle!(Area(a), Height(h) => Volume(a * h)));
+    tome
+}
+
+#[test]
+fn plan_is_reusable() {
+    let tome = tome();
+    let plan = tome.build_summon_plan::<Volume>().unwrap();
+    let copy = plan.clone();
+    assert_eq!(Some(Volume(18)), plan.execute(&tome));
+    assert_eq!(Some(Volume(18)), plan.execute(&tome));
+    assert_eq!(Some(Volume(18)), copy.execute(&tome));
+    assert!(!plan.is_stale(&tome));
+}
+
+#[test]
+#[should_panic(expected = "stale")]
+fn stale_plan_panics_when_strict() {
+    let mut tome = tome();
+    let plan = tome.build_summon_plan::<Volume>().unwrap();
+    tome.ether(Area(100));
+    plan.execute(&tome);
+}
+
+#[test]
+fn stale_plan_replans_when_lenient() {
+    let mut tome = tome();
+    let plan = tome
+        .build_summon_plan::<Volume>()
+        .unwrap()
+        .with_mode(PlanMode::Lenient);
+    tome.ether(Area(100));
+    assert!(plan.is_stale(&tome));
+    assert_eq!(Some(Volume(300)), plan.execute(&tome));
+}
+
+#[test]
+fn dry_run_plans_without_transmuting() {
+    let mut tome = tome();
+    tome.inscribe(circle!(|v: &Volume| -> Cost {
+        COST_RUNS.fetch_add(1, Ordering::SeqCst);
+        Cost(v.0 * 10)
+    }));
+
+    let plan = tome.dry_run::<Cost>().unwrap();
+    assert_eq!(0, COST_RUNS.load(Ordering::SeqCst));
+    let products: Vec<TypeId> = plan.steps().iter().map(|step| step.product).collect();
+    assert_eq!(Some(&TypeId::of::<Cost>()), products.last());
+    assert!(products.contains(&TypeId::of::<Area>()));
+    assert!(tome.dry_run::<Unknown>().is_none());
+
+    assert_eq!(Some(Cost(180)), plan.execute(&tome));
+    assert_eq!(1, COST_RUNS.load(Ordering::SeqCst));
+}
+
+#[derive(Debug, PartialEq)]
+struct Parsed(u32);
+
+#[test]
+fn execute_returns_none_when_a_circle_fails() {
+    let mut tome = tome();
+    tome.inscribe(circle!(
+        |_w: &Width| -> Result<Parsed, std::num::ParseIntError> { "wide".parse().map(Parsed) }
+    ));
+    let plan = tome.build_summon_plan::<Parsed>().unwrap();
+    assert_eq!(None, plan.execute(&tome));
+}
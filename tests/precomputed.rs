@@ -0,0 +1,48 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Input(u32);
+#[derive(Clone)]
+struct Other(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Output(u32);
+
+#[test]
+fn precomputed_value_is_used_while_recipe_matches() {
+    let mut tome = Tome::new();
+    tome.ether(Input(2));
+    tome.inscribe(circle!(Input(n) => Output(n * 10)));
+    let plan = tome.build_summon_plan::<Output>().unwrap();
+    tome.inject_precomputed(&plan, Output(99));
+    assert_eq!(Output(99), tome.summon::<Output>().unwrap());
+    // Another circle which is not used leaves the recipe unchanged.
+    tome.inscribe(circle!(Other(n) => Output(*n)));
+    assert_eq!(Output(99), tome.summon::<Output>().unwrap());
+}
+
+#[test]
+fn precomputed_value_is_ignored_when_recipe_changes() {
+    let mut tome = Tome::new();
+    tome.ether(Input(2));
+    tome.inscribe(circle!(Input(n) => Output(n * 10)));
+    let plan = tome.build_summon_plan::<Output>().unwrap();
+    tome.inscribe_precomputed(&plan, || Output(99));
+    tome.ether(Output(5));
+    assert_eq!(Output(5), tome.summon::<Output>().unwrap());
+}
+
+#[test]
+fn fingerprints_identify_recipes() {
+    let mut tome = Tome::new();
+    tome.ether(Input(2));
+    tome.inscribe(circle!(Input(n) => Output(n * 10)));
+    let first = tome.build_summon_plan::<Output>().unwrap();
+    let mut other = Tome::new();
+    other.ether(Input(7));
+    other.inscribe(circle!(Input(n) => Output(*n)));
+    let second = other.build_summon_plan::<Output>().unwrap();
+    assert_eq!(first.fingerprint(), second.fingerprint());
+    other.ether(Output(1));
+    let third = other.build_summon_plan::<Output>().unwrap();
+    assert_ne!(first.fingerprint(), third.fingerprint());
+}
@@ -0,0 +1,34 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Speed(f64);
+#[derive(Clone)]
+struct Time(f64);
+#[derive(Debug, PartialEq)]
+struct Distance(f64);
+#[derive(Debug, PartialEq)]
+struct Report(f64);
+
+#[test]
+fn rejected_product_falls_back_to_next_circle() {
+    let mut tome = Tome::new();
+    tome.ether(Speed(-3.0));
+    tome.ether(Time(2.0));
+    tome.inscribe(circle!(Speed(s) => Distance(*s)));
+    tome.inscribe(circle!(Speed(s), Time(t) => Distance((s * t).abs())));
+    tome.inscribe(circle!(Distance(d) => Report(*d)));
+    assert_eq!(Distance(-3.0), tome.summon::<Distance>().unwrap());
+
+    tome.prescribe(|d: &Distance| d.0 >= 0.0);
+    assert_eq!(Distance(6.0), tome.summon::<Distance>().unwrap());
+    assert_eq!(Report(6.0), tome.summon::<Report>().unwrap());
+}
+
+#[test]
+fn nothing_is_summoned_when_every_product_is_rejected() {
+    let mut tome = Tome::new();
+    tome.ether(Speed(-3.0));
+    tome.inscribe(circle!(Speed(s) => Distance(*s)));
+    tome.prescribe(|d: &Distance| d.0 >= 0.0);
+    assert_eq!(None, tome.summon_opt::<Distance>());
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "profiling")]
+
+use std::time::{Duration, Instant};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+struct C;
+
+#[test]
+fn cold_start_cost_splits_phases() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.inscribe(circle!(A, B => C));
+
+    let start = Instant::now();
+    let profile = tome.cold_start_cost::<C>().unwrap();
+    let total = start.elapsed();
+
+    assert!(profile.planning_cost + profile.execution_cost <= total + Duration::from_millis(1));
+    assert_eq!(3, profile.total_steps);
+    assert_eq!(2, profile.ether_steps);
+    assert_eq!(1, profile.compute_steps);
+}
+
+#[test]
+fn cold_start_cost_of_unsummonable() {
+    let tome = Tome::new();
+    assert!(tome.cold_start_cost::<C>().is_none());
+}
+
+#[test]
+fn cold_start_cost_of_failing_circle() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(|_a: &A| -> Result<C, std::fmt::Error> {
+        Err(std::fmt::Error)
+    }));
+    assert!(tome.cold_start_cost::<C>().is_none());
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "rand")]
+
+use summon::{circle, Tome, Transmutation};
+
+#[derive(Clone)]
+struct Seed;
+#[derive(Debug, PartialEq)]
+struct Coin(bool);
+
+fn tome(seed: u64) -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Seed);
+    let heads: Box<dyn Transmutation> = Box::new(circle!(|_s: &Seed| -> Coin { Coin(true) }));
+    let tails: Box<dyn Transmutation> = Box::new(circle!(|_s: &Seed| -> Coin { Coin(false) }));
+    tome.inscribe_weighted_random::<Coin>(vec![(1, heads), (3, tails)]);
+    tome.seed_rng(seed);
+    tome
+}
+
+fn flips(tome: &Tome, count: usize) -> Vec<bool> {
+    (0..count)
+        .map(|_| tome.summon::<Coin>().unwrap().0)
+        .collect()
+}
+
+#[test]
+fn seeded_selection_is_deterministic() {
+    assert_eq!(flips(&tome(7), 100), flips(&tome(7), 100));
+}
+
+#[test]
+fn weights_affect_frequency() {
+    let heads = flips(&tome(42), 4000).into_iter().filter(|&h| h).count();
+    assert!((800..1200).contains(&heads), "{} heads", heads);
+}
+
+#[test]
+fn unsatisfiable_weighted_circles_fall_back() {
+    struct Missing;
+    let mut tome = Tome::new();
+    let never: Box<dyn Transmutation> = Box::new(circle!(|_m: &Missing| -> Coin { Coin(true) }));
+    tome.inscribe_weighted_random::<Coin>(vec![(1, never)]);
+    assert!(tome.summon::<Coin>().is_err());
+    tome.ether(Seed);
+    tome.inscribe(circle!(|_s: &Seed| -> Coin { Coin(false) }));
+    assert_eq!(Coin(false), tome.summon::<Coin>().unwrap());
+}
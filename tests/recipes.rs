@@ -0,0 +1,77 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct A;
+#[derive(Clone)]
+struct B;
+struct C;
+struct D;
+struct E;
+struct Missing;
+
+#[test]
+fn iter_recipes_covers_satisfiable_products() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.inscribe(circle!(A, B => C));
+    tome.inscribe(circle!(Missing => D));
+    let recipes: HashMap<TypeId, usize> = tome
+        .iter_recipes()
+        .map(|(id, recipe)| (id, recipe.steps().len()))
+        .collect();
+    assert_eq!(3, recipes.len());
+    assert_eq!(Some(&1), recipes.get(&TypeId::of::<A>()));
+    assert_eq!(Some(&1), recipes.get(&TypeId::of::<B>()));
+    assert_eq!(Some(&3), recipes.get(&TypeId::of::<C>()));
+    assert!(!recipes.contains_key(&TypeId::of::<D>()));
+}
+
+#[test]
+fn iter_recipes_products_point_at_steps() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.inscribe(circle!(A, B => C));
+    let (_, recipe) = tome
+        .iter_recipes()
+        .find(|&(id, _)| id == TypeId::of::<C>())
+        .unwrap();
+    for (&product, &step) in recipe.products() {
+        assert_eq!(product, recipe.steps()[step].product());
+    }
+}
+
+#[test]
+fn shared_ingredients_are_made_once() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.inscribe(circle!(A => B));
+    tome.inscribe(circle!(A => C));
+    tome.inscribe(circle!(B, C => D));
+    tome.inscribe(circle!(B, C, D => E));
+    tome.enable_metrics();
+
+    assert_eq!(5, tome.explain::<E>().unwrap().steps.len());
+    tome.summon::<E>().unwrap();
+    let metrics = tome.metrics();
+    assert_eq!(5, metrics.len());
+    assert!(metrics.values().all(|&count| count == 1));
+}
+
+#[test]
+fn research_then_execute() {
+    let mut tome = Tome::new();
+    tome.ether(A);
+    tome.ether(B);
+    tome.inscribe(circle!(A, B => C));
+    tome.inscribe(circle!(Missing => D));
+    assert!(tome.research::<D>().is_none());
+    let recipe = tome.research::<C>().unwrap();
+    let products: Vec<TypeId> = recipe.steps().iter().map(|step| step.product()).collect();
+    assert_eq!(TypeId::of::<C>(), products[2]);
+    assert_eq!(Some(&2), recipe.products().get(&TypeId::of::<C>()));
+    assert!(recipe.execute::<C>(&tome).is_ok());
+}
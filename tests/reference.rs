@@ -0,0 +1,31 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Size(usize);
+struct Table(Vec<u64>);
+
+static BUILDS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn summon_ref_borrows_the_kept_value() {
+    let mut tome = Tome::new();
+    tome.ether(Size(4));
+    tome.inscribe(circle!(|s: &Size| -> Table {
+        BUILDS.fetch_add(1, Ordering::SeqCst);
+        Table((0..s.0 as u64).map(|n| n * n).collect())
+    }));
+    assert!(tome.summon_ref::<Table>().is_none());
+
+    tome.reserve_ref::<Table>();
+    let first = tome.summon_ref::<Table>().unwrap();
+    let second = tome.summon_ref::<Table>().unwrap();
+    assert_eq!(vec![0, 1, 4, 9], first.0);
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(1, BUILDS.load(Ordering::SeqCst));
+
+    assert_eq!(4, tome.release_ref::<Table>().unwrap().0.len());
+    assert!(tome.release_ref::<Table>().is_none());
+    assert_eq!(4, tome.summon_ref::<Table>().unwrap().0.len());
+    assert_eq!(2, BUILDS.load(Ordering::SeqCst));
+}
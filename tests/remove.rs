@@ -0,0 +1,145 @@
+use std::any::TypeId;
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Seed;
+#[derive(Clone)]
+struct Water;
+#[derive(Debug, PartialEq)]
+struct Plant(&'static str);
+
+fn fixture() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Seed);
+    tome.inscribe(circle!(|_s: &Seed| -> Plant { Plant("fixture") }));
+    tome
+}
+
+#[test]
+fn remove_and_reinscribe() {
+    let mut tome = fixture();
+    assert_eq!(Plant("fixture"), tome.summon::<Plant>().unwrap());
+    assert_eq!(1, tome.remove::<Plant>().len());
+    assert!(tome.summon::<Plant>().is_err());
+    assert!(tome.remove::<Plant>().is_empty());
+
+    tome.inscribe(circle!(|_s: &Seed| -> Plant { Plant("patched") }));
+    assert_eq!(Plant("patched"), tome.summon::<Plant>().unwrap());
+}
+
+#[test]
+fn remove_matching_keeps_other_circles() {
+    let mut tome = fixture();
+    tome.ether(Water);
+    tome.inscribe(circle!(|_s: &Seed, _w: &Water| -> Plant {
+        Plant("watered")
+    }));
+    assert_eq!(Plant("fixture"), tome.summon::<Plant>().unwrap());
+
+    let removed = tome.remove_matching::<Plant, _>(|circle| circle.ingredients().len() == 1);
+    assert_eq!(1, removed.len());
+    assert_eq!(Plant("watered"), tome.summon::<Plant>().unwrap());
+}
+
+#[derive(Clone)]
+struct Flower;
+#[derive(Clone)]
+struct Fruit;
+#[derive(Clone)]
+struct Sunlight;
+
+#[test]
+fn forget_cascades_to_consumers() {
+    let mut tome = fixture();
+    tome.ether(Water);
+    tome.ether(Sunlight);
+    tome.inscribe(circle!(Plant, Water => Flower));
+    tome.inscribe(circle!(Flower => Fruit));
+    tome.inscribe(circle!(Sunlight => Fruit));
+    assert_eq!(
+        vec![
+            TypeId::of::<Seed>(),
+            TypeId::of::<Plant>(),
+            TypeId::of::<Flower>(),
+            TypeId::of::<Fruit>(),
+        ],
+        tome.forget::<Seed>()
+    );
+    assert!(tome.summon::<Plant>().is_err());
+    assert!(tome.summon::<Flower>().is_err());
+    assert!(tome.summon::<Fruit>().is_ok());
+    assert!(tome.summon::<Water>().is_ok());
+}
+
+struct Moonlight;
+struct Bouquet;
+
+#[test]
+fn gc_removes_dead_circles_recursively() {
+    let mut tome = fixture();
+    tome.inscribe(circle!(Plant => Fruit));
+    tome.inscribe(circle!(Moonlight => Flower));
+    tome.inscribe(circle!(Flower => Bouquet));
+    assert_eq!(
+        vec![TypeId::of::<Flower>(), TypeId::of::<Bouquet>()],
+        tome.gc()
+    );
+    assert!(tome.gc().is_empty());
+    let mut expected = vec![
+        TypeId::of::<Seed>(),
+        TypeId::of::<Plant>(),
+        TypeId::of::<Fruit>(),
+    ];
+    expected.sort();
+    assert_eq!(expected, tome.registered_products());
+    assert_eq!(
+        3,
+        expected
+            .iter()
+            .map(|&id| tome.circles_for_id(id).len())
+            .sum::<usize>()
+    );
+    assert!(tome.summon::<Fruit>().is_ok());
+}
+
+#[test]
+fn gc_sweeps_fallback_chains_and_versioned_circles() {
+    let mut tome = fixture();
+    tome.inscribe_fallback_chain::<Flower, _, _>(
+        circle!(Moonlight => Flower),
+        circle!(Sunlight => Flower),
+    );
+    tome.inscribe_fallback_chain::<Fruit, _, _>(
+        circle!(Moonlight => Fruit),
+        circle!(Seed => Fruit),
+    );
+    tome.inscribe_with_schema_version::<Bouquet, _>(1, circle!(Flower => Bouquet));
+    assert_eq!(
+        vec![
+            TypeId::of::<Flower>(),
+            TypeId::of::<Flower>(),
+            TypeId::of::<Bouquet>()
+        ],
+        tome.gc()
+    );
+    assert!(tome.gc().is_empty());
+    assert!(tome.circles_for::<Flower>().is_empty());
+    assert!(tome.circles_for::<Bouquet>().is_empty());
+    assert!(tome.summon::<Fruit>().is_ok());
+}
+
+#[test]
+fn forget_sweeps_overrides_and_versioned_circles() {
+    let mut tome = fixture();
+    tome.inscribe_overrideable::<Flower>(circle!(Seed => Flower));
+    tome.inscribe_with_schema_version::<Fruit, _>(1, circle!(Flower => Fruit));
+    assert!(tome.summon::<Fruit>().is_ok());
+    assert_eq!(
+        vec![TypeId::of::<Flower>(), TypeId::of::<Fruit>()],
+        tome.forget::<Flower>()
+    );
+    assert!(tome.summon::<Flower>().is_err());
+    assert!(tome.summon::<Fruit>().is_err());
+    assert!(tome.circles_for::<Fruit>().is_empty());
+    assert!(tome.summon::<Plant>().is_ok());
+}
@@ -0,0 +1,60 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Source;
+#[derive(Debug, PartialEq)]
+struct Record(&'static str);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Source);
+    tome.inscribe_with_schema_version::<Record, _>(
+        1,
+        circle!(|_s: &Source| -> Record { Record("v1") }),
+    );
+    tome.inscribe_with_schema_version::<Record, _>(
+        2,
+        circle!(|_s: &Source| -> Record { Record("v2") }),
+    );
+    tome
+}
+
+#[test]
+fn summon_versioned_selects_minimum_version() {
+    let tome = tome();
+    assert_eq!(Some(Record("v2")), tome.summon_versioned::<Record>(1));
+    assert_eq!(Some(Record("v2")), tome.summon_versioned::<Record>(2));
+    assert_eq!(None, tome.summon_versioned::<Record>(3));
+    assert_eq!(Record("v2"), tome.summon::<Record>().unwrap());
+}
+
+#[test]
+fn summon_at_exact_version() {
+    let tome = tome();
+    assert_eq!(Some(Record("v1")), tome.summon_at_version::<Record>(1));
+    assert_eq!(Some(Record("v2")), tome.summon_at_version::<Record>(2));
+    assert_eq!(None, tome.summon_at_version::<Record>(0));
+}
+
+#[test]
+fn latest_version() {
+    let mut tome = tome();
+    assert_eq!(Some(2), tome.latest_version::<Record>());
+    assert_eq!(None, tome.latest_version::<Source>());
+    tome.inscribe_with_schema_version::<Record, _>(
+        5,
+        circle!(|_s: &Source| -> Record { Record("v5") }),
+    );
+    assert_eq!(Some(5), tome.latest_version::<Record>());
+}
+
+#[test]
+fn failing_versioned_circle_summons_nothing() {
+    let mut tome = tome();
+    tome.inscribe_with_schema_version::<Record, _>(
+        3,
+        circle!(|_s: &Source| -> Result<Record, std::fmt::Error> { Err(std::fmt::Error) }),
+    );
+    assert_eq!(None, tome.summon_at_version::<Record>(3));
+    assert_eq!(Some(Record("v1")), tome.summon_at_version::<Record>(1));
+}
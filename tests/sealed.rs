@@ -0,0 +1,34 @@
+use std::thread;
+use summon::{circle, SharedTome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+#[derive(Debug, PartialEq)]
+struct Area(u32);
+struct Missing;
+struct Volume;
+
+#[test]
+fn sealed_clones_summon_concurrently() {
+    let mut tome = SharedTome::new();
+    tome.ether(Width(3));
+    tome.ether(Height(4));
+    tome.inscribe(circle!(Width(w), Height(h) => Area(w * h)));
+    tome.inscribe(circle!(Missing => Volume));
+    let sealed = tome.seal();
+    assert!(sealed.contains::<Area>());
+    assert!(!sealed.contains::<Volume>());
+    let clone = sealed.clone();
+    let handles = [sealed, clone].map(|sealed| {
+        thread::spawn(move || {
+            (0..100)
+                .map(|_| sealed.summon::<Area>().unwrap())
+                .all(|area| area == Area(12))
+        })
+    });
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "shared-mut")]
+
+use std::sync::{Arc, RwLock};
+use std::thread;
+use summon::{circle, Tome};
+
+#[derive(Debug, PartialEq)]
+struct Counter(u32);
+#[derive(Debug, PartialEq)]
+struct Doubled(u32);
+
+type SharedCounter = Arc<RwLock<Counter>>;
+
+#[test]
+fn mutations_are_visible_to_later_summons() {
+    let mut tome = Tome::new();
+    tome.inscribe_mutable(Counter(1));
+    tome.inscribe(circle!(|c: &SharedCounter| -> Doubled {
+        Doubled(c.read().unwrap().0 * 2)
+    }));
+    assert_eq!(Doubled(2), tome.summon::<Doubled>().unwrap());
+    assert!(tome.mutate_ether::<Counter>(|c| c.0 += 4));
+    assert_eq!(Doubled(10), tome.summon::<Doubled>().unwrap());
+    assert!(!tome.mutate_ether::<Doubled>(|_| {}));
+}
+
+#[test]
+fn concurrent_readers_do_not_block() {
+    let mut tome = Tome::new();
+    tome.inscribe_mutable(Counter(3));
+    let shared = tome.summon::<SharedCounter>().unwrap();
+    let guard = shared.read().unwrap();
+    let reader = tome.summon::<SharedCounter>().unwrap();
+    let read = thread::spawn(move || reader.read().unwrap().0)
+        .join()
+        .unwrap();
+    assert_eq!(3, read);
+    assert_eq!(3, guard.0);
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+use std::thread;
+use summon::{circle, SharedTome};
+
+#[derive(Clone)]
+struct Base(u32);
+#[derive(Clone)]
+struct Doubled(u32);
+struct Missing;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn shared_tome_summons_from_many_threads() {
+    assert_send_sync::<SharedTome>();
+    let mut tome = SharedTome::new();
+    tome.ether(Base(21));
+    tome.inscribe(circle!(Base(n) => Doubled(*n * 2)));
+    let tome = Arc::new(tome);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let tome = tome.clone();
+            thread::spawn(move || tome.summon::<Doubled>().unwrap().0)
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(42, handle.join().unwrap());
+    }
+    assert!(tome.summon::<Missing>().is_err());
+}
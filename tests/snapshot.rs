@@ -0,0 +1,27 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+struct Area(u32);
+
+#[test]
+fn restore_forgets_circles_inscribed_after_the_snapshot() {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    let snapshot = tome.snapshot();
+
+    tome.ether(Height(4));
+    tome.inscribe(circle!(Width(w), Height(h) => Area(w * h)));
+    assert_eq!(12, tome.summon::<Area>().unwrap().0);
+
+    tome.restore(snapshot.clone());
+    assert!(tome.summon::<Area>().is_err());
+    assert!(tome.summon::<Height>().is_err());
+    assert_eq!(3, tome.summon::<Width>().unwrap().0);
+
+    tome.ether(Height(5));
+    tome.restore(snapshot);
+    assert!(tome.summon::<Height>().is_err());
+}
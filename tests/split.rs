@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Input(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Quotient(u32);
+#[derive(Clone, Debug, PartialEq)]
+struct Remainder(u32);
+
+static DIVISIONS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn splitting_makes_each_element_summonable() {
+    let mut tome = Tome::new();
+    tome.ether(Input(17));
+    tome.inscribe_splitting::<(Quotient, Remainder), _>(circle!(
+        |i: &Input| -> (Quotient, Remainder) {
+            DIVISIONS.fetch_add(1, Ordering::SeqCst);
+            (Quotient(i.0 / 5), Remainder(i.0 % 5))
+        }
+    ));
+    assert_eq!(Quotient(3), tome.summon::<Quotient>().unwrap());
+    assert_eq!(Remainder(2), tome.summon::<Remainder>().unwrap());
+    assert_eq!(2, DIVISIONS.load(Ordering::SeqCst));
+    let (quotient, remainder) = tome.summon_tuple::<(Quotient, Remainder)>().unwrap();
+    assert_eq!((Quotient(3), Remainder(2)), (quotient, remainder));
+    assert_eq!(3, DIVISIONS.load(Ordering::SeqCst));
+}
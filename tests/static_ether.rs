@@ -0,0 +1,53 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, Tome};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU32 = AtomicU32::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Limits {
+    max: u32,
+}
+
+static LIMITS: Limits = Limits { max: 8 };
+const GREETING: &str = "hello";
+
+type Greeting = &'static str;
+struct Length(usize);
+
+#[test]
+fn static_ether_is_borrowed_without_allocating() {
+    let mut tome = Tome::new();
+    tome.ether_static::<str>(GREETING);
+    tome.ether_static(&LIMITS);
+    tome.inscribe(circle!(|greeting: &Greeting| -> Length {
+        Length(greeting.len())
+    }));
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let greeting = tome.summon_static::<str>().unwrap();
+    let limits = tome.summon_ref::<Limits>().unwrap();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+    assert_eq!(before, after);
+    assert!(std::ptr::eq(GREETING, greeting));
+    assert!(std::ptr::eq(&LIMITS, limits));
+    assert_eq!(8, limits.max);
+
+    assert_eq!(GREETING, tome.summon::<Greeting>().unwrap());
+    assert_eq!(5, tome.summon::<Length>().unwrap().0);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "subscriptions")]
+
+use std::sync::{Arc, Mutex};
+use summon::Tome;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Value(u32);
+
+#[test]
+fn subscriber_receives_product() {
+    let mut tome = Tome::new();
+    tome.ether(Value(3));
+    let received = Arc::new(Mutex::new(vec![]));
+    let sink = received.clone();
+    let _handle = tome.subscribe_to_product(move |v: Value| sink.lock().unwrap().push(v));
+    tome.summon::<Value>().unwrap();
+    tome.summon::<Value>().unwrap();
+    assert_eq!(vec![Value(3), Value(3)], *received.lock().unwrap());
+}
+
+#[test]
+fn all_subscribers_fire() {
+    let mut tome = Tome::new();
+    tome.ether(Value(3));
+    let count = Arc::new(Mutex::new(0));
+    let first = count.clone();
+    let second = count.clone();
+    let _a = tome.subscribe_to_product(move |_: Value| *first.lock().unwrap() += 1);
+    let _b = tome.subscribe_to_product(move |_: Value| *second.lock().unwrap() += 1);
+    tome.summon::<Value>().unwrap();
+    assert_eq!(2, *count.lock().unwrap());
+}
+
+#[test]
+fn cancelled_subscriber_stops_firing() {
+    let mut tome = Tome::new();
+    tome.ether(Value(3));
+    let count = Arc::new(Mutex::new(0));
+    let first = count.clone();
+    let second = count.clone();
+    let cancelled = tome.subscribe_to_product(move |_: Value| *first.lock().unwrap() += 1);
+    let dropped = tome.subscribe_to_product(move |_: Value| *second.lock().unwrap() += 1);
+    tome.summon::<Value>().unwrap();
+    cancelled.cancel();
+    drop(dropped);
+    tome.summon::<Value>().unwrap();
+    assert_eq!(2, *count.lock().unwrap());
+}
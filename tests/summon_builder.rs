@@ -0,0 +1,49 @@
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct InitialVelocity(f64);
+#[derive(Clone)]
+struct InitialPosition(f64);
+#[derive(Clone)]
+struct Time(f64);
+#[derive(Debug)]
+struct Distance(f64);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(InitialVelocity(5.0));
+    tome.ether(InitialPosition(6.0));
+    tome.inscribe(
+        circle!(ConstantAcceleration(a), InitialVelocity(v), InitialPosition(p), Time(t) => Distance(0.5 * a * t.powi(2) + v * t + p)),
+    );
+    tome
+}
+
+#[test]
+fn builder_summons_physics() {
+    let tome = tome();
+    let distance = tome
+        .builder::<Distance>()
+        .with(Time(4.0))
+        .with(ConstantAcceleration(3.0))
+        .summon()
+        .unwrap();
+    assert_eq!(0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0, distance.0);
+}
+
+#[test]
+fn builder_leaves_tome_unchanged() {
+    let mut tome = tome();
+    assert!(tome
+        .builder::<Distance>()
+        .with(Time(4.0))
+        .summon()
+        .is_none());
+    assert!(tome.summon::<Distance>().is_err());
+    tome.ether(Time(1.0));
+    tome.ether(ConstantAcceleration(2.0));
+    let distance = tome.builder::<Distance>().with(Time(2.0)).summon().unwrap();
+    assert_eq!(0.5 * 2.0 * 4.0 + 5.0 * 2.0 + 6.0, distance.0);
+}
@@ -0,0 +1,28 @@
+use summon::{Summonable, Tome};
+
+#[derive(Clone)]
+struct Width(u32);
+#[derive(Clone)]
+struct Height(u32);
+
+#[derive(Summonable)]
+struct Area {
+    width: Width,
+    height: Height,
+}
+
+#[derive(Summonable)]
+struct Dimensions(Width, Height);
+
+#[test]
+fn derived_circles_build_from_fields() {
+    let mut tome = Tome::new();
+    tome.ether(Width(3));
+    tome.ether(Height(4));
+    Area::inscribe(&mut tome);
+    Dimensions::inscribe(&mut tome);
+    let area = tome.summon::<Area>().unwrap();
+    assert_eq!(12, area.width.0 * area.height.0);
+    let Dimensions(width, height) = tome.summon::<Dimensions>().unwrap();
+    assert_eq!((3, 4), (width.0, height.0));
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "testing")]
+
+use serde::Serialize;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use summon::{circle, Tome};
+
+#[derive(Clone)]
+struct Radius(f64);
+#[derive(Debug, PartialEq, Serialize)]
+struct Diameter(f64);
+#[derive(Debug, PartialEq)]
+struct Unreachable;
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.ether(Radius(2.0));
+    tome.inscribe(circle!(Radius(r) => Diameter(r * 2.0)));
+    tome
+}
+
+#[test]
+fn produce_all_and_assert_passes() {
+    tome().produce_all_and_assert(Diameter(4.0));
+}
+
+#[test]
+#[should_panic(expected = "tome with 2 products")]
+fn produce_all_and_assert_describes_tome_on_mismatch() {
+    tome().produce_all_and_assert(Diameter(5.0));
+}
+
+#[test]
+#[should_panic(expected = "could not summon")]
+fn produce_all_and_assert_fails_when_unsummonable() {
+    tome().produce_all_and_assert(Unreachable);
+}
+
+#[test]
+fn produce_and_snapshot_writes_then_compares() {
+    let path = std::env::temp_dir().join(format!("summon-snapshot-{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+    tome().produce_and_snapshot::<Diameter>(&path);
+    assert!(path.exists());
+    tome().produce_and_snapshot::<Diameter>(&path);
+
+    let mut changed = Tome::new();
+    changed.ether(Radius(3.0));
+    changed.inscribe(circle!(Radius(r) => Diameter(r * 2.0)));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        changed.produce_and_snapshot::<Diameter>(&path)
+    }));
+    fs::remove_file(&path).unwrap();
+    assert!(result.is_err());
+}
@@ -0,0 +1,38 @@
+use summon::{circle, Tome, TomeBuilder};
+
+#[derive(Clone)]
+struct ConstantAcceleration(f64);
+#[derive(Clone)]
+struct InitialVelocity(f64);
+#[derive(Clone)]
+struct InitialPosition(f64);
+#[derive(Clone)]
+struct Time(f64);
+struct Distance(f64);
+
+#[test]
+fn physics_as_a_single_expression() {
+    assert_eq!(
+        0.5 * 3.0 * 4.0f64.powi(2) + 5.0 * 4.0 + 6.0,
+        TomeBuilder::new()
+            .ether(ConstantAcceleration(3.0))
+            .ether(InitialVelocity(5.0))
+            .ether(InitialPosition(6.0))
+            .ether(Time(4.0))
+            .inscribe(circle!(ConstantAcceleration(a), InitialVelocity(v), InitialPosition(p), Time(t) => Distance(0.5 * a * t.powi(2) + v * t + p)))
+            .build()
+            .summon::<Distance>()
+            .unwrap()
+            .0
+    )
+}
+
+#[test]
+fn builder_continues_an_existing_tome() {
+    let mut tome = Tome::new();
+    tome.ether(Time(4.0));
+    let tome = TomeBuilder::from(tome)
+        .inscribe(circle!(Time(t) => Distance(t * 2.0)))
+        .build();
+    assert_eq!(8.0, tome.summon::<Distance>().unwrap().0);
+}
@@ -0,0 +1,42 @@
+use std::any::TypeId;
+use summon::{circle, CircleOutcome, SkipReason, Tome};
+
+#[derive(Clone)]
+struct Speed(f64);
+#[derive(Clone)]
+struct Time(f64);
+#[derive(Clone)]
+struct Rate(f64);
+struct Distance(f64);
+
+#[test]
+fn trace_shows_skipped_and_selected_alternatives() {
+    let mut tome = Tome::new();
+    tome.ether(Time(2.0));
+    tome.ether(Rate(3.0));
+    tome.inscribe(circle!(name = "from speed", Speed(s) => Distance(*s)));
+    tome.inscribe(circle!(name = "from rate", Time(t), Rate(r) => Distance(t * r)));
+    assert_eq!(6.0, tome.summon::<Distance>().unwrap().0);
+
+    let trace = tome.trace_summon::<Distance>();
+    assert_eq!(TypeId::of::<Distance>(), trace.product);
+    assert_eq!(2, trace.alternatives.len());
+
+    let speed = &trace.alternatives[0];
+    assert_eq!(Some("from speed"), speed.name);
+    assert_eq!(
+        CircleOutcome::Skipped(SkipReason::MissingIngredient(TypeId::of::<Speed>())),
+        speed.outcome
+    );
+    assert!(speed.ingredient_traces[0].alternatives.is_empty());
+
+    let rate = &trace.alternatives[1];
+    assert_eq!(Some("from rate"), rate.name);
+    assert_eq!(CircleOutcome::Selected, rate.outcome);
+    assert_eq!(2, rate.ingredient_traces.len());
+    assert!(rate
+        .ingredient_traces
+        .iter()
+        .all(|ingredient| ingredient.selected().is_some()));
+    assert_eq!(Some("from rate"), trace.selected().unwrap().name);
+}
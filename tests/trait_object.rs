@@ -0,0 +1,38 @@
+use summon::{circle_trait, Tome};
+
+trait Animal {
+    fn sound(&self) -> String;
+}
+
+struct Dog {
+    volume: u32,
+}
+
+impl Animal for Dog {
+    fn sound(&self) -> String {
+        "woof".repeat(self.volume as usize)
+    }
+}
+
+#[derive(Clone)]
+struct Config {
+    volume: u32,
+}
+
+#[test]
+fn summon_trait_objects() {
+    let mut tome = Tome::new();
+    assert!(tome.summon_trait::<dyn Animal>().is_err());
+    tome.ether(Config { volume: 2 });
+    tome.inscribe(circle_trait!(|c: &Config| -> dyn Animal {
+        Box::new(Dog { volume: c.volume })
+    }));
+    assert_eq!(
+        "woofwoof",
+        tome.summon_trait::<dyn Animal>().unwrap().sound()
+    );
+    assert_eq!(
+        "woofwoof",
+        tome.summon::<Box<dyn Animal>>().unwrap().sound()
+    );
+}
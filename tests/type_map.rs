@@ -0,0 +1,59 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicU32, Ordering};
+use summon::{circle, SummonError, Tome};
+
+#[derive(Clone)]
+struct Seed(u32);
+#[derive(Clone)]
+struct Shared(u32);
+#[derive(Debug, PartialEq)]
+struct First(u32);
+#[derive(Debug, PartialEq)]
+struct Second(u32);
+#[derive(Debug, PartialEq)]
+struct Third(u32);
+#[derive(Debug, PartialEq)]
+struct Fourth(u32);
+#[derive(Debug, PartialEq)]
+struct Fifth(u32);
+struct Missing;
+
+static SHARED_RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn shared_ingredients_are_made_once_for_every_type() {
+    let mut tome = Tome::new();
+    tome.ether(Seed(2));
+    tome.inscribe(circle!(|s: &Seed| -> Shared {
+        SHARED_RUNS.fetch_add(1, Ordering::SeqCst);
+        Shared(s.0 * 10)
+    }));
+    tome.inscribe(circle!(Shared(n) => First(n + 1)));
+    tome.inscribe(circle!(Shared(n) => Second(n + 2)));
+    tome.inscribe(circle!(Shared(n) => Third(n + 3)));
+    tome.inscribe(circle!(Shared(n) => Fourth(n + 4)));
+    tome.inscribe(circle!(Shared(n) => Fifth(n + 5)));
+    let mut summoned = tome
+        .summon_all_typed(vec![
+            TypeId::of::<First>(),
+            TypeId::of::<Second>(),
+            TypeId::of::<Third>(),
+            TypeId::of::<Fourth>(),
+            TypeId::of::<Fifth>(),
+        ])
+        .unwrap();
+    assert_eq!(1, SHARED_RUNS.load(Ordering::SeqCst));
+    assert_eq!(5, summoned.len());
+    assert_eq!(Some(&First(21)), summoned.get::<First>());
+    assert_eq!(Some(&Third(23)), summoned.get::<Third>());
+    assert_eq!(Some(Fifth(25)), summoned.remove::<Fifth>());
+    assert!(summoned.get::<Shared>().is_none());
+
+    match tome.summon_all_typed(vec![TypeId::of::<First>(), TypeId::of::<Missing>()]) {
+        Err(SummonError::NoPossibleRecipe { target }) => {
+            assert_eq!(TypeId::of::<Missing>(), target)
+        }
+        Err(error) => panic!("unexpected error: {}", error),
+        Ok(_) => panic!("Missing cannot be summoned"),
+    }
+}
@@ -0,0 +1,54 @@
+use std::any::TypeId;
+use summon::{circle, Tome, TypeRegistry};
+
+#[derive(Clone)]
+struct Speed(f64);
+#[derive(Clone)]
+struct Time(f64);
+struct Distance(f64);
+
+fn tome() -> Tome {
+    let mut tome = Tome::new();
+    tome.register_type::<Speed>("Speed");
+    tome.register_type::<Time>("Time");
+    tome.register_type::<Distance>("Distance");
+    tome.ether(Speed(3.0));
+    tome.inscribe(circle!(Speed(s), Time(t) => Distance(s * t)));
+    tome
+}
+
+#[test]
+fn registry_is_usable_alone() {
+    let mut registry = TypeRegistry::new();
+    registry.register::<Distance>("my_crate::Distance");
+    assert_eq!(
+        Some("my_crate::Distance"),
+        registry.name_of(TypeId::of::<Distance>())
+    );
+    assert_eq!(None, registry.name_of(TypeId::of::<Time>()));
+    assert_eq!(
+        format!("{:?}", TypeId::of::<Time>()),
+        registry.describe(TypeId::of::<Time>())
+    );
+}
+
+#[test]
+fn names_are_used_in_dot_and_errors() {
+    let mut tome = tome();
+    let dot = tome.to_dot();
+    assert!(dot.contains("[label=\"Distance\"]"));
+    assert!(dot.contains("[label=\"Speed\"]"));
+    assert!(!dot.contains("TypeId"));
+
+    let error = tome.summon::<Distance>().err().unwrap();
+    assert_eq!(
+        "Distance requires Time, which nothing produces",
+        tome.type_registry().display_error(&error).to_string()
+    );
+
+    tome.ether(Time(2.0));
+    let explanation = tome.explain::<Distance>().unwrap().to_string();
+    assert!(explanation.starts_with("Distance\n"));
+    assert!(explanation.contains("  Time\n"));
+    assert_eq!(6.0, tome.summon::<Distance>().unwrap().0);
+}